@@ -0,0 +1,202 @@
+#![feature(rustc_private)]
+#![feature(let_chains)]
+#![warn(unused_extern_crates)]
+
+extern crate rustc_hir;
+extern crate rustc_span;
+
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use lints_helpers::is_leptos_unique_ids_variant;
+use rustc_hir::{HirId, Item, ItemKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_span::{Span, Symbol, def_id::DefId, sym};
+use serde::Deserialize;
+
+const HELP: &str = concat!(
+    "for further information visit ",
+    "https://github.com/mondeja/leptos-unique-ids/tree/main/lints/cross_enum_duplicate_id#readme"
+);
+
+dylint_linting::impl_late_lint! {
+    /// ### What it does
+    ///
+    /// Check for identifiers declared in more than one enum generated by
+    /// `leptos_unique_ids` in the same crate.
+    ///
+    /// The identifiers emitted by the enums are compared, after applying their
+    /// `prefix` and `id_case` arguments and the names of prefixed groups, so
+    /// different string literals that end up as the same identifier, like
+    /// `"name"` with `prefix = "f-"` and `"f-name"`, are reported too.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Each enum guarantees that its identifiers are unique, but splitting them
+    /// in multiple enums like `FormIds` and `NavIds` allows to declare the same
+    /// identifier twice, so elements of the DOM could share it.
+    ///
+    /// ### Configuration
+    ///
+    /// Enums declared with different `prefix` arguments, like `"a-"` and `"b-"`,
+    /// emit different identifiers for the same string literal, like `"a-root"` and
+    /// `"b-root"`, which may be intended or a mistake. Set `local_names` in the
+    /// `dylint.toml` file of the workspace to also check the identifiers without
+    /// their prefixes:
+    ///
+    /// ```toml
+    /// [cross_enum_duplicate_id]
+    /// local_names = true
+    /// ```
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// #[leptos_unique_ids("email", "submit")]
+    /// pub enum FormIds {}
+    ///
+    /// #[leptos_unique_ids("home", "submit")]
+    /// pub enum NavIds {}
+    /// ```
+    ///
+    /// Use instead:
+    ///
+    /// ```rust,ignore
+    /// #[leptos_unique_ids("email", "form-submit")]
+    /// pub enum FormIds {}
+    ///
+    /// #[leptos_unique_ids("home", "nav-submit")]
+    /// pub enum NavIds {}
+    /// ```
+    pub CROSS_ENUM_DUPLICATE_ID,
+    Warn,
+    "Check for identifiers declared in more than one enum generated by `leptos_unique_ids` in the same crate.",
+    CrossEnumDuplicateId::new()
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    local_names: bool,
+}
+
+pub struct CrossEnumDuplicateId {
+    config: Config,
+    /// Identifiers of the enums generated by `leptos_unique_ids`, in declaration
+    /// order, with their local names, their enum and its span.
+    ids: Vec<(Symbol, Symbol, DefId, HirId, Span)>,
+}
+
+impl CrossEnumDuplicateId {
+    pub fn new() -> Self {
+        Self {
+            config: dylint_linting::config_or_default(env!("CARGO_PKG_NAME")),
+            ids: Vec::new(),
+        }
+    }
+}
+
+impl Default for CrossEnumDuplicateId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for CrossEnumDuplicateId {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        let ItemKind::Enum(..) = item.kind else {
+            return;
+        };
+        let def_id = item.owner_id.to_def_id();
+        let span = cx.tcx.def_span(def_id);
+        for variant in cx.tcx.adt_def(def_id).variants() {
+            if !is_leptos_unique_ids_variant(cx, variant.def_id) {
+                return;
+            }
+            // The first documentation line of each variant is its identifier,
+            // and the identifier without the prefix is its alias, if any.
+            let mut attrs = cx.tcx.get_attrs(variant.def_id, sym::doc);
+            if let Some(id) = attrs.next().and_then(|attr| attr.value_str()) {
+                let local_name = attrs
+                    .filter_map(|attr| attr.meta_item_list())
+                    .flatten()
+                    .find(|item| item.has_name(sym::alias))
+                    .and_then(|item| item.value_str())
+                    // quoted like the identifiers in the documentation
+                    .map_or(id, |alias| Symbol::intern(&format!("{:?}", alias.as_str())));
+                self.ids.push((id, local_name, def_id, item.hir_id(), span));
+            }
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for (i, (id, local_name, def_id, hir_id, span)) in self.ids.iter().enumerate() {
+            if self.config.local_names
+                && let Some((other_id, _, _, _, first_span)) =
+                    self.ids[..i]
+                        .iter()
+                        .find(|(other_id, other_local_name, other_def_id, ..)| {
+                            other_local_name == local_name
+                                && other_id != id
+                                && other_def_id != def_id
+                        })
+            {
+                span_lint_hir_and_then(
+                    cx,
+                    CROSS_ENUM_DUPLICATE_ID,
+                    *hir_id,
+                    *span,
+                    format!(
+                        "identifier {id} is declared with a different prefix in another `leptos_unique_ids` enum"
+                    ),
+                    |diag| {
+                        diag.span_note(*first_span, format!("also declared here as {other_id}"));
+                        diag.help(HELP);
+                    },
+                );
+            }
+            let Some((_, first_local_name, _, _, first_span)) = self.ids[..i]
+                .iter()
+                .find(|(other_id, _, other_def_id, ..)| other_id == id && other_def_id != def_id)
+            else {
+                continue;
+            };
+            span_lint_hir_and_then(
+                cx,
+                CROSS_ENUM_DUPLICATE_ID,
+                *hir_id,
+                *span,
+                format!("identifier {id} is declared in more than one `leptos_unique_ids` enum"),
+                |diag| {
+                    // the emitted identifiers are compared, so literals that only
+                    // collide after prefixing them are reported too
+                    if first_local_name == id {
+                        diag.span_note(*first_span, "also declared here");
+                    } else {
+                        diag.span_note(
+                            *first_span,
+                            format!("also declared here as {first_local_name} with a prefix"),
+                        );
+                    }
+                    if local_name != id {
+                        diag.note(format!("declared as {local_name} with a prefix"));
+                    }
+                    diag.help(HELP);
+                },
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn ui() {
+        dylint_testing::ui_test(env!("CARGO_PKG_NAME"), "ui");
+    }
+
+    #[test]
+    fn ui_local_names() {
+        dylint_testing::ui::Test::src_base(env!("CARGO_PKG_NAME"), "ui_local_names")
+            .dylint_toml("[cross_enum_duplicate_id]\nlocal_names = true")
+            .run();
+    }
+}
@@ -0,0 +1,20 @@
+//! Catch identifiers declared in more than one `leptos_unique_ids` enum
+
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("email", "submit")]
+pub enum FormIds {}
+
+#[leptos_unique_ids("home", "submit")]
+pub enum NavIds {}
+
+// Disjoint identifiers are fine
+#[leptos_unique_ids("footer")]
+pub enum FooterIds {}
+
+fn main() {
+    assert_eq!(FormIds::Email.as_str(), "email");
+    assert_eq!(FormIds::Submit.as_str(), NavIds::Submit.as_str());
+    assert_eq!(NavIds::Home.as_str(), "home");
+    assert_eq!(FooterIds::Footer.as_str(), "footer");
+}
@@ -0,0 +1,25 @@
+//! Catch string literals that collide after prefixing them in more than one
+//! `leptos_unique_ids` enum
+
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("name", "email", prefix = "f-")]
+pub enum FormIds {}
+
+#[leptos_unique_ids("f-name", "home")]
+pub enum NavIds {}
+
+#[leptos_unique_ids("user", group "f" prefixed { "email" })]
+pub enum ProfileIds {}
+
+// The same literals with different prefixes are fine
+#[leptos_unique_ids("name", prefix = "s-")]
+pub enum SearchIds {}
+
+fn main() {
+    assert_eq!(FormIds::Name.as_str(), NavIds::FName.as_str());
+    assert_eq!(FormIds::Email.as_str(), ProfileIds::FEmail.as_str());
+    assert_eq!(NavIds::Home.as_str(), "home");
+    assert_eq!(ProfileIds::User.as_str(), "user");
+    assert_eq!(SearchIds::Name.as_str(), "s-name");
+}
@@ -0,0 +1,21 @@
+//! Catch identifiers declared with different prefixes in more than one
+//! `leptos_unique_ids` enum
+
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("root", "email", prefix = "a-")]
+pub enum FormIds {}
+
+#[leptos_unique_ids("root", prefix = "b-")]
+pub enum NavIds {}
+
+// Local names of unprefixed identifiers are the identifiers themselves
+#[leptos_unique_ids("email")]
+pub enum FooterIds {}
+
+fn main() {
+    assert_eq!(FormIds::Root.as_str(), "a-root");
+    assert_eq!(FormIds::Email.as_str(), "a-email");
+    assert_eq!(NavIds::Root.as_str(), "b-root");
+    assert_eq!(FooterIds::Email.as_str(), "email");
+}
@@ -4,69 +4,1317 @@
 extern crate rustc_ast;
 #[allow(unused_extern_crates)]
 extern crate rustc_driver;
+extern crate rustc_lint;
+extern crate rustc_span;
 
 use rustc_ast::{
     MacCall,
-    token::TokenKind,
+    token::{Delimiter, Token, TokenKind},
     tokenstream::{TokenStreamIter, TokenTree},
 };
+use rustc_lint::LateContext;
+use rustc_span::{
+    def_id::DefId,
+    hygiene::{ExpnKind, MacroKind},
+};
+
+/// Names of the macros scanned as Leptos views by default.
+pub const DEFAULT_VIEW_MACRO_NAMES: [&str; 1] = ["view"];
 
-/// Given a macro call, return if is a `view!` macro
-pub fn is_leptos_view_macro_call(macro_call: &MacCall) -> bool {
+/// Given a macro call, return if is a Leptos view macro
+///
+/// The macro is matched by the last segment of its path against the given names,
+/// like `view` for `view!` or `leptos::view!`, or `template` for `template!`.
+///
+/// # Example
+///
+/// Call it from the `check_mac` method of an early lint pass before scanning
+/// the macro call, passing the names configured for the lint:
+///
+/// ```ignore
+/// fn check_mac(&mut self, cx: &EarlyContext, macro_call: &MacCall) {
+///     if !is_leptos_view_macro_call(macro_call, &self.config.macros) {
+///         return;
+///     }
+///     // ...
+/// }
+/// ```
+pub fn is_leptos_view_macro_call(macro_call: &MacCall, macro_names: &[String]) -> bool {
     macro_call
         .path
         .segments
         .iter()
         .last()
-        .map_or(false, |segment| segment.ident.name.as_str() == "view")
+        .map_or(false, |segment| {
+            macro_names
+                .iter()
+                .any(|name| segment.ident.name.as_str() == name)
+        })
+}
+
+/// Given a variant, return if it has been generated by the `leptos_unique_ids` macro
+///
+/// The variants are generated with the span of the macro call, so they come from
+/// the expansion of the attribute. The name of the enum is not checked, so enums
+/// renamed like `FormIds` are found and hand-written enums named `Ids` are not.
+///
+/// # Example
+///
+/// Call it from a late lint pass with the variants of an enum:
+///
+/// ```ignore
+/// for variant in cx.tcx.adt_def(def_id).variants() {
+///     if !is_leptos_unique_ids_variant(cx, variant.def_id) {
+///         return;
+///     }
+///     // ...
+/// }
+/// ```
+pub fn is_leptos_unique_ids_variant(cx: &LateContext<'_>, variant_def_id: DefId) -> bool {
+    let expn_data = cx.tcx.def_span(variant_def_id).ctxt().outer_expn_data();
+    matches!(
+        expn_data.kind,
+        ExpnKind::Macro(MacroKind::Attr, name) if name.as_str() == "leptos_unique_ids"
+    )
 }
 
-/// Iterator for id attribute values in macro calls
+/// Iterator for the tag names and id attribute values in macro calls
+///
+/// Id attributes are found at every nesting level of the view, including
+/// self-closing elements, fragments and nested view macro calls, like
+/// `{move || view! { <p id=Ids::Foo/> }}`. Rust code inside blocks is only scanned
+/// for nested view macro calls, so `let id = ...` is never misread as an id
+/// attribute, and only tags are scanned for attributes, so text content like
+/// `<p>The id = 5</p>` is neither.
+///
+/// The `id` props of components are found like the attributes of elements, so
+/// `<MyButton id="foo"/>` yields `("MyButton", "foo")`. The generic arguments
+/// of components, like `<MyList<T> id=...>`, are skipped.
+///
+/// Each value is yielded along with the name of the tag that contains it, like
+/// `div` or `web-component-x`. The tokens of each tag are run through the
+/// [`ParserState`] state machine, and the token tree that follows a
+/// [`ParserState::InIdValue`] state is the value of an id attribute.
+///
+/// # Example
+///
+/// Given the macro call of `view! { <div id=Ids::Foo><p id="bar"/></div> }`,
+/// the iterator yields `("div", Ids)`, as the path is made of several token
+/// trees, and `("p", "bar")`:
+///
+/// ```ignore
+/// for (tag_name, tt) in ViewMacroCallIdAttributeValueIter::new(macro_call, &self.config.macros) {
+///     if let TokenTree::Token(token, _) = tt
+///         && let TokenKind::Literal(lit) = token.kind
+///     {
+///         // `lit` is passed as the id attribute of `tag_name`
+///     }
+/// }
+/// ```
 pub struct ViewMacroCallIdAttributeValueIter<'a> {
+    walker: ViewWalker<'a>,
+    parser_state: ParserState,
+    // if the last token is a hyphen, like in `data-id`
+    after_hyphen: bool,
+}
+
+/// State of the parser of [`ViewMacroCallIdAttributeValueIter`]
+///
+/// Only the tokens inside tags, between `<` and `>`, are run through
+/// [`ParserState::next`]. Outside of tags and when entering or leaving a
+/// delimited group the parser goes back to [`ParserState::Initial`].
+///
+/// ```text
+///            `id`               `=`
+/// Initial ----------> InIdAttr ------> InIdValue
+///    ^  ^                |                 |
+///    |  +----------------+                 |
+///    |   other tokens                      |
+///    +-------------------------------------+
+///       next token tree, yielded as value
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ParserState {
+    /// Looking for an `id` attribute name.
+    #[default]
+    Initial,
+    /// After an `id` attribute name, like in `<div id`.
+    InIdAttr,
+    /// After the `=` of an `id` attribute, so the next token tree is its value.
+    InIdValue,
+}
+
+impl ParserState {
+    /// Return the state after a token found inside a tag
+    ///
+    /// `after_hyphen` is whether the previous token of the tag is a `-`, so the
+    /// `id` of attributes like `data-id` is not an id attribute name. From any
+    /// state, an `id` starts another id attribute, `=` after `id` moves to
+    /// [`ParserState::InIdValue`] and other tokens go back to
+    /// [`ParserState::Initial`].
+    ///
+    /// The token after [`ParserState::InIdValue`] is the value of the attribute,
+    /// which is yielded by the iterator instead of being passed here.
+    pub fn next(self, kind: &TokenKind, after_hyphen: bool) -> Self {
+        match kind {
+            TokenKind::Eq if self == Self::InIdAttr => Self::InIdValue,
+            // a token that is not `=` after `id` can start another id attribute
+            TokenKind::Ident(symbol, _) if symbol.as_str() == "id" && !after_hyphen => {
+                Self::InIdAttr
+            }
+            _ => Self::Initial,
+        }
+    }
+}
+
+/// State of the detection of view macro calls nested in the scanned tokens
+///
+/// Every token of the scanned streams is run through [`MacroCallState::next`],
+/// so the delimited group that follows a [`MacroCallState::AfterBang`] state is
+/// the content of a nested view macro call, like `{ <p/> }` in
+/// `{move || view! { <p/> }}`, and other groups are Rust code.
+///
+/// ```text
+///            name of a view macro            `!`
+/// Initial ----------------------> AfterName -----> AfterBang
+///    ^                                |                |
+///    +--------------------------------+----------------+
+///                       other tokens
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MacroCallState {
+    /// Looking for the name of a view macro.
+    #[default]
+    Initial,
+    /// After the name of a view macro, like `view` in `view!`.
+    AfterName,
+    /// After the `!` of a view macro call, so the next group is a view.
+    AfterBang,
+}
+
+impl MacroCallState {
+    /// Return the state after a token
+    ///
+    /// `macro_names` are the names of the view macros, like the ones passed to
+    /// [`is_leptos_view_macro_call`]. Any of them starts a macro call from any
+    /// state, `!` after the name moves to [`MacroCallState::AfterBang`] and
+    /// other tokens go back to [`MacroCallState::Initial`].
+    pub fn next(self, kind: &TokenKind, macro_names: &[String]) -> Self {
+        match kind {
+            TokenKind::Ident(symbol, _)
+                if macro_names.iter().any(|name| symbol.as_str() == name) =>
+            {
+                Self::AfterName
+            }
+            TokenKind::Bang if self == Self::AfterName => Self::AfterBang,
+            _ => Self::Initial,
+        }
+    }
+}
+
+// token stream parsed by `ViewWalker`
+struct ViewTokenStream<'a> {
     iter: TokenStreamIter<'a>,
-    // 1: Initial
-    // 2: Inside id attribute
-    // 4: Inside id attribute value
-    parser_state: u8,
+    // if it's the content of a view macro call instead of Rust code
+    is_view: bool,
+    // if the last token parsed is inside a tag, between `<` and `>`
+    in_tag: bool,
+    // name of the last tag, formed by words joined with hyphens
+    tag_name: String,
+    // if the name of the last tag is complete
+    tag_name_done: bool,
+    // number of unclosed `<` in the generic arguments of the last component
+    generics_depth: usize,
+}
+
+impl<'a> ViewTokenStream<'a> {
+    fn new(iter: TokenStreamIter<'a>, is_view: bool) -> Self {
+        Self {
+            iter,
+            is_view,
+            in_tag: false,
+            tag_name: String::new(),
+            tag_name_done: false,
+            generics_depth: 0,
+        }
+    }
+}
+
+// walker of the token streams of a view macro call and of the view macro calls
+// nested in it, shared by the iterators over the tags of the views
+struct ViewWalker<'a> {
+    // token streams being parsed, the innermost one is the last
+    stack: Vec<ViewTokenStream<'a>>,
+    // names of the view macros to scan when nested
+    macro_names: &'a [String],
+    macro_call_state: MacroCallState,
+}
+
+// token tree returned by `ViewWalker::next_tree`
+enum ViewTree<'a> {
+    // the innermost token stream has been consumed and left
+    End,
+    // next token tree of the innermost token stream, not walked yet
+    Tree(&'a TokenTree),
+}
+
+impl<'a> ViewWalker<'a> {
+    fn new(macro_call: &'a MacCall, macro_names: &'a [String]) -> Self {
+        Self {
+            stack: vec![ViewTokenStream::new(macro_call.args.tokens.iter(), true)],
+            macro_names,
+            macro_call_state: MacroCallState::Initial,
+        }
+    }
+
+    // `None` when all the token streams have been consumed
+    fn next_tree(&mut self) -> Option<ViewTree<'a>> {
+        let stream = self.stack.last_mut()?;
+        if let Some(tt) = stream.iter.next() {
+            return Some(ViewTree::Tree(tt));
+        }
+        self.stack.pop();
+        self.macro_call_state = MacroCallState::Initial;
+        Some(ViewTree::End)
+    }
+
+    // if the next token tree is inside a tag of a view, out of the generic
+    // arguments of components
+    fn in_tag(&self) -> bool {
+        self.stack
+            .last()
+            .is_some_and(|stream| stream.is_view && stream.in_tag && stream.generics_depth == 0)
+    }
+
+    fn tag_name(&self) -> String {
+        self.stack
+            .last()
+            .map_or_else(String::new, |stream| stream.tag_name.clone())
+    }
+
+    // Walk a token tree returned by `next_tree`, entering delimited groups and
+    // parsing the names of the tags of views. Return the token if it's inside a
+    // tag and it's not part of its name, like the tokens of attributes.
+    fn walk(&mut self, tt: &'a TokenTree) -> Option<&'a Token> {
+        let token = match tt {
+            TokenTree::Delimited(_, _, _, inner) => {
+                // a group inside a tag, like an attribute spread, ends its name
+                if let Some(stream) = self.stack.last_mut()
+                    && stream.in_tag
+                {
+                    stream.tag_name_done = true;
+                }
+                // the content of a nested view macro call is a view and
+                // other groups are Rust code that can contain view macro calls
+                let nested_is_view = self.macro_call_state == MacroCallState::AfterBang;
+                self.macro_call_state = MacroCallState::Initial;
+                self.stack
+                    .push(ViewTokenStream::new(inner.iter(), nested_is_view));
+                return None;
+            }
+            TokenTree::Token(token, _) => token,
+        };
+
+        self.macro_call_state = self.macro_call_state.next(&token.kind, self.macro_names);
+
+        let stream = self.stack.last_mut()?;
+        if !stream.is_view {
+            return None;
+        }
+        // the `<` and `>` of generic arguments of components, like in
+        // `<MyList<T> id=...>`, don't open nor close tags
+        if stream.generics_depth > 0 {
+            stream.generics_depth = match token.kind {
+                TokenKind::Lt => stream.generics_depth + 1,
+                TokenKind::Gt => stream.generics_depth - 1,
+                // `>>` closes the generic arguments and the tag, like in
+                // `<MyList<T>>`
+                TokenKind::Shr if stream.generics_depth == 1 => {
+                    stream.in_tag = false;
+                    0
+                }
+                TokenKind::Shr => stream.generics_depth - 2,
+                _ => stream.generics_depth,
+            };
+            return None;
+        }
+        if token.kind == TokenKind::Lt
+            && stream.in_tag
+            && !stream.tag_name_done
+            && is_component_name(&stream.tag_name)
+        {
+            stream.tag_name_done = true;
+            stream.generics_depth = 1;
+            return None;
+        }
+        match token.kind {
+            TokenKind::Lt => {
+                stream.in_tag = true;
+                stream.tag_name.clear();
+                stream.tag_name_done = false;
+            }
+            TokenKind::Gt => stream.in_tag = false,
+            _ => {}
+        }
+        if !stream.in_tag {
+            return None;
+        }
+        if !stream.tag_name_done && token.kind != TokenKind::Lt {
+            let name = &mut stream.tag_name;
+            match token.kind {
+                TokenKind::Ident(symbol, _) if name.is_empty() || name.ends_with('-') => {
+                    name.push_str(symbol.as_str());
+                    return None;
+                }
+                TokenKind::Minus if !name.is_empty() && !name.ends_with('-') => {
+                    name.push('-');
+                    return None;
+                }
+                // closing tags, like `</div>`
+                TokenKind::Slash if name.is_empty() => return None,
+                _ => stream.tag_name_done = true,
+            }
+        }
+        Some(token)
+    }
 }
 
 impl<'a> ViewMacroCallIdAttributeValueIter<'a> {
-    pub fn new(macro_call: &'a MacCall) -> Self {
+    /// Create an iterator over the arguments of a view macro call
+    ///
+    /// `macro_names` are the names of the view macros whose calls nested in
+    /// the arguments are also scanned, usually the same names that were passed
+    /// to [`is_leptos_view_macro_call`] to check the macro call.
+    pub fn new(macro_call: &'a MacCall, macro_names: &'a [String]) -> Self {
         Self {
-            iter: macro_call.args.tokens.iter(),
-            parser_state: 1,
+            walker: ViewWalker::new(macro_call, macro_names),
+            parser_state: ParserState::Initial,
+            after_hyphen: false,
         }
     }
+
+    /// Return an iterator over the token trees that follow the last value yielded
+    ///
+    /// Values are yielded as their first token tree, so this reads the rest of
+    /// values made of several token trees, like `::MyIdentifier` after `Ids` in
+    /// `<div id=Ids::MyIdentifier>`. It's `None` when all the token streams have
+    /// been consumed.
+    ///
+    /// ```ignore
+    /// let mut iter = ViewMacroCallIdAttributeValueIter::new(macro_call, &self.config.macros);
+    /// while let Some((tag_name, tt)) = iter.next() {
+    ///     let following = iter.following();
+    ///     // ...
+    /// }
+    /// ```
+    pub fn following(&self) -> Option<TokenStreamIter<'a>> {
+        self.walker.stack.last().map(|stream| stream.iter.clone())
+    }
+
+    fn reset(&mut self) {
+        self.parser_state = ParserState::Initial;
+        self.after_hyphen = false;
+    }
 }
 
 impl<'a> Iterator for ViewMacroCallIdAttributeValueIter<'a> {
-    type Item = &'a TokenTree;
+    type Item = (String, &'a TokenTree);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Tokens are consumed in a loop instead of recursively, so big views
+        // don't overflow the stack and every id attribute value is found.
+        loop {
+            let ViewTree::Tree(tt) = self.walker.next_tree()? else {
+                self.reset();
+                continue;
+            };
+            if self.walker.in_tag() && self.parser_state == ParserState::InIdValue {
+                let tag_name = self.walker.tag_name();
+                self.reset();
+                return Some((tag_name, tt));
+            }
+            let Some(token) = self.walker.walk(tt) else {
+                self.reset();
+                continue;
+            };
+            self.parser_state = self.parser_state.next(&token.kind, self.after_hyphen);
+            self.after_hyphen = token.kind == TokenKind::Minus;
+        }
+    }
+}
+
+/// Return if a tag name is the name of a component, which are capitalized, like
+/// `MyButton`
+fn is_component_name(tag_name: &str) -> bool {
+    tag_name.starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+/// Return if a token tree is an attribute spread, like `{..attrs}`
+///
+/// The bare `{..}` marker of components, which separates their props from the
+/// attributes passed to their root element, doesn't spread any value, so it's
+/// not an attribute spread.
+pub fn is_attribute_spread(tt: &TokenTree) -> bool {
+    let TokenTree::Delimited(_, _, Delimiter::Brace, inner) = tt else {
+        return false;
+    };
+    let mut iter = inner.iter();
+    matches!(iter.next(), Some(TokenTree::Token(token, _)) if token.kind == TokenKind::DotDot)
+        && iter.next().is_some()
+}
+
+/// Iterator for the tag names and attribute spreads in macro calls
+///
+/// Attribute spreads, like `<div {..attrs}>`, are found at every nesting level
+/// of the view like id attributes in [`ViewMacroCallIdAttributeValueIter`]. Each
+/// one is yielded as the braced group along with the name of the tag that
+/// contains it.
+pub struct ViewMacroCallAttributeSpreadIter<'a> {
+    walker: ViewWalker<'a>,
+}
+
+impl<'a> ViewMacroCallAttributeSpreadIter<'a> {
+    pub fn new(macro_call: &'a MacCall, macro_names: &'a [String]) -> Self {
+        Self {
+            walker: ViewWalker::new(macro_call, macro_names),
+        }
+    }
+}
+
+impl<'a> Iterator for ViewMacroCallAttributeSpreadIter<'a> {
+    type Item = (String, &'a TokenTree);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let ViewTree::Tree(tt) = self.walker.next_tree()? else {
+                continue;
+            };
+            let tag_name =
+                (self.walker.in_tag() && is_attribute_spread(tt)).then(|| self.walker.tag_name());
+            self.walker.walk(tt);
+            if let Some(tag_name) = tag_name {
+                return Some((tag_name, tt));
+            }
+        }
+    }
+}
+
+/// Iterator for the names and values of all the attributes in macro calls
+///
+/// Attributes are found at every nesting level of the view like id attributes in
+/// [`ViewMacroCallIdAttributeValueIter`], so comparisons in blocks, like
+/// `{a < b}`, don't open tags and the attributes of nested view macro calls are
+/// found. Unlike id attributes, attribute names can be formed by words joined
+/// with hyphens, like `aria-labelledby`.
+///
+/// Each value is yielded as its first token tree along with the name of the
+/// attribute.
+pub struct ViewMacroCallAttributeIter<'a> {
+    walker: ViewWalker<'a>,
+    // name of the attribute being parsed
+    attribute_name: String,
+    // if the last token is a hyphen inside an attribute name
+    after_hyphen: bool,
+    // if the next token tree is the value of `attribute_name`
+    in_value: bool,
+}
+
+impl<'a> ViewMacroCallAttributeIter<'a> {
+    /// Create an iterator over the arguments of a view macro call
+    ///
+    /// `macro_names` are the names of the view macros whose calls nested in
+    /// the arguments are also scanned, like in
+    /// [`ViewMacroCallIdAttributeValueIter::new`].
+    pub fn new(macro_call: &'a MacCall, macro_names: &'a [String]) -> Self {
+        Self {
+            walker: ViewWalker::new(macro_call, macro_names),
+            attribute_name: String::new(),
+            after_hyphen: false,
+            in_value: false,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.attribute_name.clear();
+        self.after_hyphen = false;
+        self.in_value = false;
+    }
+}
+
+impl<'a> Iterator for ViewMacroCallAttributeIter<'a> {
+    type Item = (String, &'a TokenTree);
 
     fn next(&mut self) -> Option<Self::Item> {
-        let token = self.iter.next()?;
-        if self.parser_state == 1 {
-            if let TokenTree::Token(token, _) = token {
-                if let TokenKind::Ident(symbol, _) = token.kind {
-                    if symbol.as_str() == "id" {
-                        self.parser_state <<= 1;
-                        return self.next();
+        loop {
+            let ViewTree::Tree(tt) = self.walker.next_tree()? else {
+                self.reset();
+                continue;
+            };
+            if self.walker.in_tag() && self.in_value {
+                let attribute_name = std::mem::take(&mut self.attribute_name);
+                self.reset();
+                // values are walked too, so views nested in them are scanned
+                self.walker.walk(tt);
+                return Some((attribute_name, tt));
+            }
+            let Some(token) = self.walker.walk(tt) else {
+                self.reset();
+                continue;
+            };
+            match token.kind {
+                TokenKind::Ident(symbol, _) => {
+                    if self.after_hyphen {
+                        self.attribute_name.push('-');
+                    } else {
+                        self.attribute_name.clear();
                     }
+                    self.attribute_name.push_str(symbol.as_str());
+                    self.after_hyphen = false;
                 }
-            }
-            self.next()
-        } else if self.parser_state == 2 {
-            if let TokenTree::Token(token, _) = token {
-                if token.kind == TokenKind::Eq {
-                    self.parser_state <<= 1;
-                    return self.next();
+                TokenKind::Minus if !self.attribute_name.is_empty() && !self.after_hyphen => {
+                    self.after_hyphen = true;
+                }
+                TokenKind::Eq if !self.after_hyphen && !self.attribute_name.is_empty() => {
+                    self.in_value = true;
                 }
+                _ => self.reset(),
+            }
+        }
+    }
+}
+
+/// Iterator for the values of the given attributes in macro calls
+///
+/// The attributes are found like in [`ViewMacroCallAttributeIter`].
+pub struct ViewMacroCallAttributeValueIter<'a> {
+    attributes: ViewMacroCallAttributeIter<'a>,
+    attribute_names: &'a [String],
+}
+
+impl<'a> ViewMacroCallAttributeValueIter<'a> {
+    pub fn new(
+        macro_call: &'a MacCall,
+        macro_names: &'a [String],
+        attribute_names: &'a [String],
+    ) -> Self {
+        Self {
+            attributes: ViewMacroCallAttributeIter::new(macro_call, macro_names),
+            attribute_names,
+        }
+    }
+}
+
+impl<'a> Iterator for ViewMacroCallAttributeValueIter<'a> {
+    type Item = &'a TokenTree;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (attribute_name, value) = self.attributes.next()?;
+            if self.attribute_names.contains(&attribute_name) {
+                return Some(value);
             }
-            self.parser_state >>= 1;
-            self.next()
-        } else {
-            // Here always the parser state is 4
-            self.parser_state >>= 2;
-            return Some(token);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    extern crate rustc_span;
+
+    use super::{
+        DEFAULT_VIEW_MACRO_NAMES, MacroCallState, ParserState, ViewMacroCallAttributeIter,
+        ViewMacroCallAttributeSpreadIter, ViewMacroCallIdAttributeValueIter, is_attribute_spread,
+        is_leptos_view_macro_call,
+    };
+    use rustc_ast::{
+        DelimArgs, MacCall, Path,
+        ptr::P,
+        token::{Delimiter, IdentIsRaw, Lit, LitKind, TokenKind},
+        tokenstream::{DelimSpacing, DelimSpan, Spacing, TokenStream, TokenTree},
+    };
+    use rustc_span::{DUMMY_SP, Ident, Symbol, create_default_session_globals_then};
+
+    fn view_macro_call(tokens: Vec<TokenKind>) -> MacCall {
+        macro_call("view", tokens)
+    }
+
+    fn macro_call(name: &str, tokens: Vec<TokenKind>) -> MacCall {
+        macro_call_with_trees(name, tokens.into_iter().map(token).collect())
+    }
+
+    fn macro_call_with_trees(name: &str, trees: Vec<TokenTree>) -> MacCall {
+        MacCall {
+            path: Path::from_ident(Ident::from_str(name)),
+            args: P(DelimArgs {
+                dspan: DelimSpan::dummy(),
+                delim: Delimiter::Brace,
+                tokens: TokenStream::new(trees),
+            }),
+        }
+    }
+
+    fn token(kind: TokenKind) -> TokenTree {
+        TokenTree::token_alone(kind, DUMMY_SP)
+    }
+
+    fn brace(trees: Vec<TokenTree>) -> TokenTree {
+        TokenTree::Delimited(
+            DelimSpan::dummy(),
+            DelimSpacing::new(Spacing::Alone, Spacing::Alone),
+            Delimiter::Brace,
+            TokenStream::new(trees),
+        )
+    }
+
+    fn id_attribute_values(macro_call: &MacCall) -> Vec<String> {
+        ViewMacroCallIdAttributeValueIter::new(macro_call, &default_view_macro_names())
+            .map(|(_, tt)| match tt {
+                TokenTree::Token(token, _) => match token.kind {
+                    TokenKind::Literal(lit) => lit.symbol.to_string(),
+                    _ => panic!("Expected a literal as id attribute value"),
+                },
+                TokenTree::Delimited(..) => panic!("Expected a token as id attribute value"),
+            })
+            .collect()
+    }
+
+    fn attributes(macro_call: &MacCall) -> Vec<(String, String)> {
+        ViewMacroCallAttributeIter::new(macro_call, &default_view_macro_names())
+            .map(|(name, tt)| match tt {
+                TokenTree::Token(token, _) => match token.kind {
+                    TokenKind::Literal(lit) => (name, lit.symbol.to_string()),
+                    _ => panic!("Expected a literal as attribute value"),
+                },
+                TokenTree::Delimited(..) => (name, "{..}".to_string()),
+            })
+            .collect()
+    }
+
+    fn ident(name: &str) -> TokenKind {
+        TokenKind::Ident(Symbol::intern(name), IdentIsRaw::No)
+    }
+
+    fn string(value: &str) -> TokenKind {
+        TokenKind::Literal(Lit::new(LitKind::Str, Symbol::intern(value), None))
+    }
+
+    fn default_view_macro_names() -> Vec<String> {
+        DEFAULT_VIEW_MACRO_NAMES.map(String::from).to_vec()
+    }
+
+    #[test]
+    fn view_macro_matched_by_default() {
+        create_default_session_globals_then(|| {
+            let macro_call = view_macro_call(vec![]);
+            assert!(is_leptos_view_macro_call(
+                &macro_call,
+                &default_view_macro_names()
+            ));
+        });
+    }
+
+    #[test]
+    fn template_macro_not_matched_by_default() {
+        create_default_session_globals_then(|| {
+            let macro_call = macro_call("template", vec![]);
+            assert!(!is_leptos_view_macro_call(
+                &macro_call,
+                &default_view_macro_names()
+            ));
+        });
+    }
+
+    #[test]
+    fn template_macro_matched_when_configured() {
+        create_default_session_globals_then(|| {
+            let macro_names = ["view".to_string(), "template".to_string()];
+            assert!(is_leptos_view_macro_call(
+                &macro_call("template", vec![]),
+                &macro_names
+            ));
+            assert!(is_leptos_view_macro_call(
+                &view_macro_call(vec![]),
+                &macro_names
+            ));
+            assert!(!is_leptos_view_macro_call(
+                &macro_call("html", vec![]),
+                &macro_names
+            ));
+        });
+    }
+
+    #[test]
+    fn parser_state_id_starts_id_attribute() {
+        create_default_session_globals_then(|| {
+            for state in [
+                ParserState::Initial,
+                ParserState::InIdAttr,
+                ParserState::InIdValue,
+            ] {
+                assert_eq!(state.next(&ident("id"), false), ParserState::InIdAttr);
+            }
+        });
+    }
+
+    #[test]
+    fn parser_state_eq_after_id_starts_id_value() {
+        assert_eq!(
+            ParserState::InIdAttr.next(&TokenKind::Eq, false),
+            ParserState::InIdValue
+        );
+        assert_eq!(
+            ParserState::Initial.next(&TokenKind::Eq, false),
+            ParserState::Initial
+        );
+    }
+
+    #[test]
+    fn parser_state_id_after_hyphen_is_not_id_attribute() {
+        create_default_session_globals_then(|| {
+            // the `id` of `data-id`
+            assert_eq!(
+                ParserState::Initial.next(&ident("id"), true),
+                ParserState::Initial
+            );
+        });
+    }
+
+    #[test]
+    fn parser_state_other_tokens_reset() {
+        create_default_session_globals_then(|| {
+            for token in [ident("class"), TokenKind::Minus, TokenKind::Slash] {
+                assert_eq!(
+                    ParserState::InIdAttr.next(&token, false),
+                    ParserState::Initial
+                );
+                assert_eq!(
+                    ParserState::InIdValue.next(&token, false),
+                    ParserState::Initial
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn macro_call_state_view_macro_name_starts_macro_call() {
+        create_default_session_globals_then(|| {
+            let macro_names = default_view_macro_names();
+            for state in [
+                MacroCallState::Initial,
+                MacroCallState::AfterName,
+                MacroCallState::AfterBang,
+            ] {
+                assert_eq!(
+                    state.next(&ident("view"), &macro_names),
+                    MacroCallState::AfterName
+                );
+            }
+            assert_eq!(
+                MacroCallState::Initial.next(&ident("template"), &macro_names),
+                MacroCallState::Initial
+            );
+        });
+    }
+
+    #[test]
+    fn macro_call_state_bang_after_name() {
+        let macro_names = default_view_macro_names();
+        assert_eq!(
+            MacroCallState::AfterName.next(&TokenKind::Bang, &macro_names),
+            MacroCallState::AfterBang
+        );
+        assert_eq!(
+            MacroCallState::Initial.next(&TokenKind::Bang, &macro_names),
+            MacroCallState::Initial
+        );
+        assert_eq!(
+            MacroCallState::AfterBang.next(&TokenKind::Bang, &macro_names),
+            MacroCallState::Initial
+        );
+    }
+
+    #[test]
+    fn tokens_following_id_attribute_value() {
+        create_default_session_globals_then(|| {
+            // <div id=Ids::Foo class="bar">
+            let macro_call = view_macro_call(vec![
+                TokenKind::Lt,
+                ident("div"),
+                ident("id"),
+                TokenKind::Eq,
+                ident("Ids"),
+                TokenKind::PathSep,
+                ident("Foo"),
+                ident("class"),
+                TokenKind::Eq,
+                string("bar"),
+                TokenKind::Gt,
+            ]);
+
+            let macro_names = default_view_macro_names();
+            let mut iter = ViewMacroCallIdAttributeValueIter::new(&macro_call, &macro_names);
+            let (tag_name, _) = iter.next().unwrap();
+            assert_eq!(tag_name, "div");
+            let following = iter
+                .following()
+                .unwrap()
+                .take(2)
+                .map(|tt| match tt {
+                    TokenTree::Token(token, _) => token.kind,
+                    TokenTree::Delimited(..) => panic!("Expected tokens after the value"),
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(following, [TokenKind::PathSep, ident("Foo")]);
+            assert!(iter.next().is_none());
+        });
+    }
+
+    #[test]
+    fn all_id_attribute_values() {
+        create_default_session_globals_then(|| {
+            // <div id="foo"> <span class="bar" id="baz"> <p id id="qux">
+            let macro_call = view_macro_call(vec![
+                TokenKind::Lt,
+                ident("div"),
+                ident("id"),
+                TokenKind::Eq,
+                string("foo"),
+                TokenKind::Gt,
+                TokenKind::Lt,
+                ident("span"),
+                ident("class"),
+                TokenKind::Eq,
+                string("bar"),
+                ident("id"),
+                TokenKind::Eq,
+                string("baz"),
+                TokenKind::Gt,
+                TokenKind::Lt,
+                ident("p"),
+                ident("id"),
+                ident("id"),
+                TokenKind::Eq,
+                string("qux"),
+                TokenKind::Gt,
+            ]);
+
+            let values = id_attribute_values(&macro_call);
+            assert_eq!(values, ["foo", "baz", "qux"]);
+        });
+    }
+
+    #[test]
+    fn id_attribute_values_in_self_closing_elements_and_fragments() {
+        create_default_session_globals_then(|| {
+            // <> <input id="foo"/> <br/> <p id="bar"></p> </>
+            let macro_call = view_macro_call(vec![
+                TokenKind::Lt,
+                TokenKind::Gt,
+                TokenKind::Lt,
+                ident("input"),
+                ident("id"),
+                TokenKind::Eq,
+                string("foo"),
+                TokenKind::Slash,
+                TokenKind::Gt,
+                TokenKind::Lt,
+                ident("br"),
+                TokenKind::Slash,
+                TokenKind::Gt,
+                TokenKind::Lt,
+                ident("p"),
+                ident("id"),
+                TokenKind::Eq,
+                string("bar"),
+                TokenKind::Gt,
+                TokenKind::Lt,
+                TokenKind::Slash,
+                ident("p"),
+                TokenKind::Gt,
+                TokenKind::Lt,
+                TokenKind::Slash,
+                TokenKind::Gt,
+            ]);
+
+            assert_eq!(id_attribute_values(&macro_call), ["foo", "bar"]);
+        });
+    }
+
+    #[test]
+    fn id_attribute_values_in_nested_views() {
+        create_default_session_globals_then(|| {
+            // <div id="foo" data-id="bar">
+            //     {move || view! { <span id="baz"/> }}
+            //     { let id = "qux"; }
+            //     <Show fallback=|| view! { <p id="quux"/> }/>
+            // </div>
+            let macro_call = macro_call_with_trees(
+                "view",
+                vec![
+                    token(TokenKind::Lt),
+                    token(ident("div")),
+                    token(ident("id")),
+                    token(TokenKind::Eq),
+                    token(string("foo")),
+                    token(ident("data")),
+                    token(TokenKind::Minus),
+                    token(ident("id")),
+                    token(TokenKind::Eq),
+                    token(string("bar")),
+                    token(TokenKind::Gt),
+                    brace(vec![
+                        token(ident("move")),
+                        token(TokenKind::OrOr),
+                        token(ident("view")),
+                        token(TokenKind::Bang),
+                        brace(vec![
+                            token(TokenKind::Lt),
+                            token(ident("span")),
+                            token(ident("id")),
+                            token(TokenKind::Eq),
+                            token(string("baz")),
+                            token(TokenKind::Slash),
+                            token(TokenKind::Gt),
+                        ]),
+                    ]),
+                    brace(vec![
+                        token(ident("let")),
+                        token(ident("id")),
+                        token(TokenKind::Eq),
+                        token(string("qux")),
+                        token(TokenKind::Semi),
+                    ]),
+                    token(TokenKind::Lt),
+                    token(ident("Show")),
+                    token(ident("fallback")),
+                    token(TokenKind::Eq),
+                    token(TokenKind::OrOr),
+                    token(ident("view")),
+                    token(TokenKind::Bang),
+                    brace(vec![
+                        token(TokenKind::Lt),
+                        token(ident("p")),
+                        token(ident("id")),
+                        token(TokenKind::Eq),
+                        token(string("quux")),
+                        token(TokenKind::Slash),
+                        token(TokenKind::Gt),
+                    ]),
+                    token(TokenKind::Slash),
+                    token(TokenKind::Gt),
+                    token(TokenKind::Lt),
+                    token(TokenKind::Slash),
+                    token(ident("div")),
+                    token(TokenKind::Gt),
+                ],
+            );
+
+            assert_eq!(id_attribute_values(&macro_call), ["foo", "baz", "quux"]);
+        });
+    }
+
+    #[test]
+    fn id_attribute_values_not_in_text_content() {
+        create_default_session_globals_then(|| {
+            // <label>Your id here</label> <p>The id = "foo"</p> <p id="bar"></p>
+            let macro_call = view_macro_call(vec![
+                TokenKind::Lt,
+                ident("label"),
+                TokenKind::Gt,
+                ident("Your"),
+                ident("id"),
+                ident("here"),
+                TokenKind::Lt,
+                TokenKind::Slash,
+                ident("label"),
+                TokenKind::Gt,
+                TokenKind::Lt,
+                ident("p"),
+                TokenKind::Gt,
+                ident("The"),
+                ident("id"),
+                TokenKind::Eq,
+                string("foo"),
+                TokenKind::Lt,
+                TokenKind::Slash,
+                ident("p"),
+                TokenKind::Gt,
+                TokenKind::Lt,
+                ident("p"),
+                ident("id"),
+                TokenKind::Eq,
+                string("bar"),
+                TokenKind::Gt,
+                TokenKind::Lt,
+                TokenKind::Slash,
+                ident("p"),
+                TokenKind::Gt,
+            ]);
+
+            assert_eq!(id_attribute_values(&macro_call), ["bar"]);
+            assert_eq!(
+                attributes(&macro_call),
+                [("id".to_string(), "bar".to_string())]
+            );
+        });
+    }
+
+    #[test]
+    fn tag_names_of_id_attribute_values() {
+        create_default_session_globals_then(|| {
+            // <web-component-x id="foo"></web-component-x> <div class="bar" id="baz"/>
+            let macro_call = view_macro_call(vec![
+                TokenKind::Lt,
+                ident("web"),
+                TokenKind::Minus,
+                ident("component"),
+                TokenKind::Minus,
+                ident("x"),
+                ident("id"),
+                TokenKind::Eq,
+                string("foo"),
+                TokenKind::Gt,
+                TokenKind::Lt,
+                TokenKind::Slash,
+                ident("web"),
+                TokenKind::Minus,
+                ident("component"),
+                TokenKind::Minus,
+                ident("x"),
+                TokenKind::Gt,
+                TokenKind::Lt,
+                ident("div"),
+                ident("class"),
+                TokenKind::Eq,
+                string("bar"),
+                ident("id"),
+                TokenKind::Eq,
+                string("baz"),
+                TokenKind::Slash,
+                TokenKind::Gt,
+            ]);
+
+            let tag_names =
+                ViewMacroCallIdAttributeValueIter::new(&macro_call, &default_view_macro_names())
+                    .map(|(tag_name, _)| tag_name)
+                    .collect::<Vec<_>>();
+            assert_eq!(tag_names, ["web-component-x", "div"]);
+        });
+    }
+
+    #[test]
+    fn id_attribute_values_of_components() {
+        create_default_session_globals_then(|| {
+            // <MyButton id="foo"/> <MyList<Vec<T>> id="bar"/> <MyList<T>>
+            // <p id="baz"/> </MyList>
+            let macro_call = view_macro_call(vec![
+                TokenKind::Lt,
+                ident("MyButton"),
+                ident("id"),
+                TokenKind::Eq,
+                string("foo"),
+                TokenKind::Slash,
+                TokenKind::Gt,
+                TokenKind::Lt,
+                ident("MyList"),
+                TokenKind::Lt,
+                ident("Vec"),
+                TokenKind::Lt,
+                ident("T"),
+                TokenKind::Shr,
+                ident("id"),
+                TokenKind::Eq,
+                string("bar"),
+                TokenKind::Slash,
+                TokenKind::Gt,
+                TokenKind::Lt,
+                ident("MyList"),
+                TokenKind::Lt,
+                ident("T"),
+                TokenKind::Shr,
+                TokenKind::Lt,
+                ident("p"),
+                ident("id"),
+                TokenKind::Eq,
+                string("baz"),
+                TokenKind::Slash,
+                TokenKind::Gt,
+                TokenKind::Lt,
+                TokenKind::Slash,
+                ident("MyList"),
+                TokenKind::Gt,
+            ]);
+
+            assert_eq!(id_attribute_values(&macro_call), ["foo", "bar", "baz"]);
+            let tag_names =
+                ViewMacroCallIdAttributeValueIter::new(&macro_call, &default_view_macro_names())
+                    .map(|(tag_name, _)| tag_name)
+                    .collect::<Vec<_>>();
+            assert_eq!(tag_names, ["MyButton", "MyList", "p"]);
+        });
+    }
+
+    #[test]
+    fn all_attribute_names_and_values() {
+        create_default_session_globals_then(|| {
+            // <label class="foo" for="bar"> <div aria-labelledby="baz">
+            let macro_call = view_macro_call(vec![
+                TokenKind::Lt,
+                ident("label"),
+                ident("class"),
+                TokenKind::Eq,
+                string("foo"),
+                ident("for"),
+                TokenKind::Eq,
+                string("bar"),
+                TokenKind::Gt,
+                TokenKind::Lt,
+                ident("div"),
+                ident("aria"),
+                TokenKind::Minus,
+                ident("labelledby"),
+                TokenKind::Eq,
+                string("baz"),
+                TokenKind::Gt,
+            ]);
+
+            assert_eq!(
+                attributes(&macro_call),
+                [
+                    ("class".to_string(), "foo".to_string()),
+                    ("for".to_string(), "bar".to_string()),
+                    ("aria-labelledby".to_string(), "baz".to_string()),
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn attribute_names_and_values_in_nested_views() {
+        create_default_session_globals_then(|| {
+            // <div hidden={a < b} class="foo">
+            //     {move || view! { <span class="bar"/> }}
+            // </div>
+            let macro_call = macro_call_with_trees(
+                "view",
+                vec![
+                    token(TokenKind::Lt),
+                    token(ident("div")),
+                    token(ident("hidden")),
+                    token(TokenKind::Eq),
+                    brace(vec![
+                        token(ident("a")),
+                        token(TokenKind::Lt),
+                        token(ident("b")),
+                    ]),
+                    token(ident("class")),
+                    token(TokenKind::Eq),
+                    token(string("foo")),
+                    token(TokenKind::Gt),
+                    brace(vec![
+                        token(ident("move")),
+                        token(TokenKind::OrOr),
+                        token(ident("view")),
+                        token(TokenKind::Bang),
+                        brace(vec![
+                            token(TokenKind::Lt),
+                            token(ident("span")),
+                            token(ident("class")),
+                            token(TokenKind::Eq),
+                            token(string("bar")),
+                            token(TokenKind::Slash),
+                            token(TokenKind::Gt),
+                        ]),
+                    ]),
+                    token(TokenKind::Lt),
+                    token(TokenKind::Slash),
+                    token(ident("div")),
+                    token(TokenKind::Gt),
+                ],
+            );
+
+            assert_eq!(
+                attributes(&macro_call),
+                [
+                    ("hidden".to_string(), "{..}".to_string()),
+                    ("class".to_string(), "foo".to_string()),
+                    ("class".to_string(), "bar".to_string()),
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn attribute_spreads() {
+        create_default_session_globals_then(|| {
+            // <div {..attrs}></div> <my-input type="text" {..input_attrs}/>
+            // <Greeting {..} class="foo"/>
+            let macro_call = macro_call_with_trees(
+                "view",
+                vec![
+                    token(TokenKind::Lt),
+                    token(ident("div")),
+                    brace(vec![token(TokenKind::DotDot), token(ident("attrs"))]),
+                    token(TokenKind::Gt),
+                    token(TokenKind::Lt),
+                    token(TokenKind::Slash),
+                    token(ident("div")),
+                    token(TokenKind::Gt),
+                    token(TokenKind::Lt),
+                    token(ident("my")),
+                    token(TokenKind::Minus),
+                    token(ident("input")),
+                    token(ident("type")),
+                    token(TokenKind::Eq),
+                    token(string("text")),
+                    brace(vec![token(TokenKind::DotDot), token(ident("input_attrs"))]),
+                    token(TokenKind::Slash),
+                    token(TokenKind::Gt),
+                    token(TokenKind::Lt),
+                    token(ident("Greeting")),
+                    brace(vec![token(TokenKind::DotDot)]),
+                    token(ident("class")),
+                    token(TokenKind::Eq),
+                    token(string("foo")),
+                    token(TokenKind::Slash),
+                    token(TokenKind::Gt),
+                ],
+            );
+
+            let spreads =
+                ViewMacroCallAttributeSpreadIter::new(&macro_call, &default_view_macro_names())
+                    .map(|(tag_name, tt)| {
+                        assert!(is_attribute_spread(tt));
+                        tag_name
+                    })
+                    .collect::<Vec<_>>();
+            assert_eq!(spreads, ["div", "my-input"]);
+        });
+    }
+
+    #[test]
+    fn attribute_spreads_of_components_with_generics() {
+        create_default_session_globals_then(|| {
+            // <MyList<T> {..attrs}/>
+            let macro_call = macro_call_with_trees(
+                "view",
+                vec![
+                    token(TokenKind::Lt),
+                    token(ident("MyList")),
+                    token(TokenKind::Lt),
+                    token(ident("T")),
+                    token(TokenKind::Gt),
+                    brace(vec![token(TokenKind::DotDot), token(ident("attrs"))]),
+                    token(TokenKind::Slash),
+                    token(TokenKind::Gt),
+                ],
+            );
+
+            let spreads =
+                ViewMacroCallAttributeSpreadIter::new(&macro_call, &default_view_macro_names())
+                    .map(|(tag_name, _)| tag_name)
+                    .collect::<Vec<_>>();
+            assert_eq!(spreads, ["MyList"]);
+        });
+    }
+}
@@ -0,0 +1,205 @@
+#![feature(rustc_private)]
+#![feature(let_chains)]
+#![warn(unused_extern_crates)]
+
+extern crate rustc_ast;
+extern crate rustc_span;
+
+use std::ops::Range;
+
+use clippy_utils::diagnostics::span_lint_and_help;
+use lints_helpers::{
+    DEFAULT_VIEW_MACRO_NAMES, ViewMacroCallAttributeValueIter, is_leptos_view_macro_call,
+};
+use rustc_ast::{
+    token::{LitKind, TokenKind},
+    tokenstream::TokenTree,
+};
+use rustc_lint::{EarlyContext, EarlyLintPass};
+use rustc_span::InnerSpan;
+use serde::Deserialize;
+
+const HELP: &str = concat!(
+    "for further information visit ",
+    "https://github.com/mondeja/leptos-unique-ids/tree/main/lints/id_reference_literal#readme"
+);
+const MESSAGE: &str = "literal string passed as value of an attribute that references ids";
+const LIST_MESSAGE: &str =
+    "literal id of a space-separated list passed as value of an attribute that references ids";
+
+// Attributes scanned by default.
+const DEFAULT_ATTRIBUTES: [&str; 5] = [
+    "for",
+    "aria-labelledby",
+    "aria-describedby",
+    "aria-controls",
+    "headers",
+];
+
+dylint_linting::impl_pre_expansion_lint! {
+    /// ### What it does
+    ///
+    /// Check for literals passed to attributes that reference ids, like `for` or
+    /// `aria-labelledby`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Attributes that reference elements by their ids break silently when the
+    /// referenced ids change. Passing `Ids` enum variants ensures that they
+    /// reference existing ids.
+    ///
+    /// Attributes like `aria-controls` or `headers` can reference several ids
+    /// separated by whitespace. Each id of these literals is reported, and values
+    /// built from `Ids` enum variants, like
+    /// `format!("{} {}", Ids::Menu.as_str(), Ids::Panel.as_str())`, are allowed.
+    ///
+    /// ### Configuration
+    ///
+    /// The attributes scanned by default are `for`, `aria-labelledby`,
+    /// `aria-describedby`, `aria-controls` and `headers`. They can be configured
+    /// in the `dylint.toml` file of the workspace:
+    ///
+    /// ```toml
+    /// [id_reference_literal]
+    /// attributes = ["for", "aria-labelledby", "aria-describedby", "aria-owns"]
+    /// ```
+    ///
+    /// The macros scanned as Leptos views can be configured with `macros`, which
+    /// defaults to `["view"]`, to also cover `template!` or wrappers around `view!`:
+    ///
+    /// ```toml
+    /// [id_reference_literal]
+    /// macros = ["view", "template"]
+    /// ```
+    ///
+    /// ### Known problems
+    ///
+    /// Only checks for literals in the attribute values of the `view!` macro,
+    /// including the view macro calls nested in it, like `{move || view! { .. }}`.
+    /// Currently, it does not check it in Leptos builder syntax.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// view! {
+    ///     <label for="language-selector">Language</label>
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    ///
+    /// ```rust,ignore
+    /// use ids::Ids;
+    ///
+    /// view! {
+    ///     <label for=Ids::LanguageSelector>Language</label>
+    /// }
+    /// ```
+    pub ID_REFERENCE_LITERAL,
+    Warn,
+    "Check for literals passed to attributes that reference ids, like `for` or `aria-labelledby`.",
+    IdReferenceLiteral::new()
+}
+
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    attributes: Vec<String>,
+    macros: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            attributes: DEFAULT_ATTRIBUTES.map(String::from).to_vec(),
+            macros: DEFAULT_VIEW_MACRO_NAMES.map(String::from).to_vec(),
+        }
+    }
+}
+
+pub struct IdReferenceLiteral {
+    config: Config,
+}
+
+impl IdReferenceLiteral {
+    pub fn new() -> Self {
+        Self {
+            config: dylint_linting::config_or_default(env!("CARGO_PKG_NAME")),
+        }
+    }
+}
+
+impl Default for IdReferenceLiteral {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EarlyLintPass for IdReferenceLiteral {
+    fn check_mac(&mut self, cx: &EarlyContext, macro_call: &rustc_ast::MacCall) {
+        if !is_leptos_view_macro_call(macro_call, &self.config.macros) {
+            return;
+        }
+        for tt in ViewMacroCallAttributeValueIter::new(
+            macro_call,
+            &self.config.macros,
+            &self.config.attributes,
+        ) {
+            if let TokenTree::Token(token, _) = tt
+                && let TokenKind::Literal(lit) = token.kind
+                && lit.kind == LitKind::Str
+            {
+                let value = lit.symbol.as_str();
+                let ranges = id_ranges(value);
+                // offsets inside literals with escapes don't match their source
+                if ranges.len() < 2 || value.contains('\\') {
+                    span_lint_and_help(cx, ID_REFERENCE_LITERAL, token.span, MESSAGE, None, HELP);
+                    continue;
+                }
+                for range in ranges {
+                    // skip the opening quote
+                    let span = token
+                        .span
+                        .from_inner(InnerSpan::new(range.start + 1, range.end + 1));
+                    span_lint_and_help(cx, ID_REFERENCE_LITERAL, span, LIST_MESSAGE, None, HELP);
+                }
+            }
+        }
+    }
+}
+
+/// Return the byte ranges of the ids separated by whitespace in an attribute value.
+fn id_ranges(value: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = None;
+    for (i, c) in value.char_indices().chain([(value.len(), ' ')]) {
+        match (c.is_whitespace(), start) {
+            (true, Some(s)) => {
+                ranges.push(s..i);
+                start = None;
+            }
+            (false, None) => start = Some(i),
+            _ => {}
+        }
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::id_ranges;
+
+    #[test]
+    fn id_ranges_of_space_separated_ids() {
+        assert_eq!(id_ranges("menu"), [0..4]);
+        assert_eq!(id_ranges("menu panel"), [0..4, 5..10]);
+        assert_eq!(id_ranges("  menu\tpanel  "), [2..6, 7..12]);
+        assert!(id_ranges("").is_empty());
+        assert!(id_ranges("   ").is_empty());
+    }
+
+    #[test]
+    fn ui() {
+        dylint_testing::ui_test(env!("CARGO_PKG_NAME"), "ui");
+    }
+}
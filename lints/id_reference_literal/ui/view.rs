@@ -0,0 +1,54 @@
+//! Catch literal strings in attributes that reference ids in view! macros
+
+#[macro_export]
+macro_rules! view {
+    ($($arg:tt)*) => {
+        println!("View macro called with: {}", stringify!($($arg)*));
+    };
+}
+
+fn main() {
+    view! {
+        <label for="language-selector">Language</label>
+    }
+
+    view! {
+        <div aria-labelledby="language-label" aria-describedby="language-help"></div>
+    }
+
+    view! {
+        <td headers="name-header">Name</td>
+    }
+
+    view! {
+        <button aria-controls="menu-list menu-panel">Menu</button>
+    }
+
+    // attributes of nested views are checked and comparisons don't open tags
+    view! {
+        <div hidden={count < 5}>
+            {move || view! { <label for="nested-input">Nested</label> }}
+        </div>
+    }
+
+    // Use the Ids enum instead
+
+    view! {
+        <label for=Ids::LanguageSelector>Language</label>
+    }
+
+    view! {
+        <div aria-labelledby=Ids::LanguageLabel></div>
+    }
+
+    view! {
+        <button aria-controls=format!("{} {}", Ids::MenuList.as_str(), Ids::MenuPanel.as_str())>
+            Menu
+        </button>
+    }
+
+    // other attributes are not checked
+    view! {
+        <div class="language" data-for="language-selector"></div>
+    }
+}
@@ -0,0 +1,128 @@
+#![feature(rustc_private)]
+#![warn(unused_extern_crates)]
+
+extern crate rustc_ast;
+
+use clippy_utils::diagnostics::span_lint_and_then;
+use lints_helpers::{
+    DEFAULT_VIEW_MACRO_NAMES, ViewMacroCallAttributeSpreadIter, is_leptos_view_macro_call,
+};
+use rustc_lint::{EarlyContext, EarlyLintPass};
+use serde::Deserialize;
+
+const HELP: &str = concat!(
+    "for further information visit ",
+    "https://github.com/mondeja/leptos-unique-ids/tree/main/lints/id_via_spread#readme"
+);
+const MESSAGE: &str = "attribute spread passed to an element";
+const NOTE: &str = "ids set inside attribute spreads can't be checked";
+const EXPLICIT_ID_HELP: &str = "set the id explicitly with an `Ids` enum variant, like `id=Ids::X`";
+
+dylint_linting::impl_pre_expansion_lint! {
+    /// ### What it does
+    ///
+    /// Check for attribute spreads in elements, like `<div {..attrs}>`, which can
+    /// set ids that can't be checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Attribute spreads are built outside of the view, so an id set inside them
+    /// bypasses the rest of the lints. Setting the id explicitly with an `Ids`
+    /// enum variant ensures that it's unique.
+    ///
+    /// ### Configuration
+    ///
+    /// The macros scanned as Leptos views can be configured in the `dylint.toml`
+    /// file of the workspace with `macros`, which defaults to `["view"]`, to also
+    /// cover `template!` or wrappers around `view!`:
+    ///
+    /// ```toml
+    /// [id_via_spread]
+    /// macros = ["view", "template"]
+    /// ```
+    ///
+    /// ### Known problems
+    ///
+    /// Spreads are reported even when they don't set an id, because their content
+    /// is only known at runtime. The bare `{..}` marker of components is not
+    /// reported, as it doesn't spread any value.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// let attrs = view! { <{..} id="greeting" class="greeting"/> };
+    ///
+    /// view! {
+    ///     <div {..attrs}>"Hello"</div>
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    ///
+    /// ```rust,ignore
+    /// use ids::Ids;
+    ///
+    /// view! {
+    ///     <div id=Ids::Greeting class="greeting">"Hello"</div>
+    /// }
+    /// ```
+    pub ID_VIA_SPREAD,
+    Warn,
+    "Check for attribute spreads in elements, which can set ids that can't be checked.",
+    IdViaSpread::new()
+}
+
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    macros: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            macros: DEFAULT_VIEW_MACRO_NAMES.map(String::from).to_vec(),
+        }
+    }
+}
+
+pub struct IdViaSpread {
+    config: Config,
+}
+
+impl IdViaSpread {
+    pub fn new() -> Self {
+        Self {
+            config: dylint_linting::config_or_default(env!("CARGO_PKG_NAME")),
+        }
+    }
+}
+
+impl Default for IdViaSpread {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EarlyLintPass for IdViaSpread {
+    fn check_mac(&mut self, cx: &EarlyContext, macro_call: &rustc_ast::MacCall) {
+        if !is_leptos_view_macro_call(macro_call, &self.config.macros) {
+            return;
+        }
+        for (_, tt) in ViewMacroCallAttributeSpreadIter::new(macro_call, &self.config.macros) {
+            span_lint_and_then(cx, ID_VIA_SPREAD, tt.span(), MESSAGE, |diag| {
+                diag.note(NOTE);
+                diag.help(EXPLICIT_ID_HELP);
+                diag.help(HELP);
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn ui() {
+        dylint_testing::ui_test(env!("CARGO_PKG_NAME"), "ui");
+    }
+}
@@ -0,0 +1,29 @@
+//! Catch attribute spreads in view! macros
+
+#[macro_export]
+macro_rules! view {
+    ($($arg:tt)*) => {
+        println!("View macro called with: {}", stringify!($($arg)*));
+    };
+}
+
+fn main() {
+    view! {
+        <div {..attrs}>"Hello"</div>
+    }
+
+    view! {
+        <input type="text" {..input_attrs}/>
+    }
+
+    // Set the id explicitly instead
+
+    view! {
+        <div id=Ids::Greeting>"Hello"</div>
+    }
+
+    // the `{..}` marker of components doesn't spread any value
+    view! {
+        <Greeting {..} class="greeting"/>
+    }
+}
@@ -0,0 +1,155 @@
+#![feature(rustc_private)]
+#![feature(let_chains)]
+#![warn(unused_extern_crates)]
+
+extern crate rustc_ast;
+
+use clippy_utils::diagnostics::span_lint_and_help;
+use lints_helpers::{
+    DEFAULT_VIEW_MACRO_NAMES, ViewMacroCallAttributeIter, is_leptos_view_macro_call,
+};
+use rustc_ast::{token::TokenKind, tokenstream::TokenTree};
+use rustc_lint::{EarlyContext, EarlyLintPass};
+use serde::Deserialize;
+
+const HELP: &str = concat!(
+    "for further information visit ",
+    "https://github.com/mondeja/leptos-unique-ids/tree/main/lints/ids_used_as_non_id#readme"
+);
+const MESSAGE: &str = "`Ids` enum variant passed as value of an attribute that is not id-like";
+
+// Attributes allowed by default.
+const DEFAULT_ATTRIBUTES: [&str; 10] = [
+    "id",
+    "for",
+    "form",
+    "list",
+    "headers",
+    "aria-activedescendant",
+    "aria-controls",
+    "aria-describedby",
+    "aria-labelledby",
+    "aria-owns",
+];
+
+dylint_linting::impl_pre_expansion_lint! {
+    /// ### What it does
+    ///
+    /// Check for `Ids` enum variants passed as values of attributes that are not
+    /// id-like, like `class` or `name`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Identifiers must be unique in the DOM. Using them as values of other
+    /// attributes, like class names, bypasses the purpose of the `Ids` enum and
+    /// couples unrelated attributes to the identifiers.
+    ///
+    /// ### Configuration
+    ///
+    /// The id-like attributes allowed by default are `id`, `for`, `form`, `list`,
+    /// `headers`, `aria-activedescendant`, `aria-controls`, `aria-describedby`,
+    /// `aria-labelledby` and `aria-owns`. They can be configured in the
+    /// `dylint.toml` file of the workspace:
+    ///
+    /// ```toml
+    /// [ids_used_as_non_id]
+    /// attributes = ["id", "for", "aria-labelledby", "popovertarget"]
+    /// ```
+    ///
+    /// The macros scanned as Leptos views can be configured with `macros`, which
+    /// defaults to `["view"]`, to also cover `template!` or wrappers around `view!`:
+    ///
+    /// ```toml
+    /// [ids_used_as_non_id]
+    /// macros = ["view", "template"]
+    /// ```
+    ///
+    /// ### Known problems
+    ///
+    /// Only checks for `Ids` enum variants in the attribute values of the `view!`
+    /// macro. Currently, it does not check it in Leptos builder syntax.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// use ids::Ids;
+    ///
+    /// view! {
+    ///     <div class=Ids::LanguageSelector></div>
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    ///
+    /// ```rust,ignore
+    /// use ids::Ids;
+    ///
+    /// view! {
+    ///     <div id=Ids::LanguageSelector class="language-selector"></div>
+    /// }
+    /// ```
+    pub IDS_USED_AS_NON_ID,
+    Warn,
+    "Check for `Ids` enum variants passed as values of attributes that are not id-like, like `class` or `name`.",
+    IdsUsedAsNonId::new()
+}
+
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    attributes: Vec<String>,
+    macros: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            attributes: DEFAULT_ATTRIBUTES.map(String::from).to_vec(),
+            macros: DEFAULT_VIEW_MACRO_NAMES.map(String::from).to_vec(),
+        }
+    }
+}
+
+pub struct IdsUsedAsNonId {
+    config: Config,
+}
+
+impl IdsUsedAsNonId {
+    pub fn new() -> Self {
+        Self {
+            config: dylint_linting::config_or_default(env!("CARGO_PKG_NAME")),
+        }
+    }
+}
+
+impl Default for IdsUsedAsNonId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EarlyLintPass for IdsUsedAsNonId {
+    fn check_mac(&mut self, cx: &EarlyContext, macro_call: &rustc_ast::MacCall) {
+        if !is_leptos_view_macro_call(macro_call, &self.config.macros) {
+            return;
+        }
+        for (attribute_name, tt) in ViewMacroCallAttributeIter::new(macro_call, &self.config.macros)
+        {
+            if let TokenTree::Token(token, _) = tt
+                && let TokenKind::Ident(symbol, _) = token.kind
+                && symbol.as_str() == "Ids"
+                && !self.config.attributes.contains(&attribute_name)
+            {
+                span_lint_and_help(cx, IDS_USED_AS_NON_ID, token.span, MESSAGE, None, HELP);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn ui() {
+        dylint_testing::ui_test(env!("CARGO_PKG_NAME"), "ui");
+    }
+}
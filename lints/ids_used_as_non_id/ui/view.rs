@@ -0,0 +1,40 @@
+//! Catch `Ids` enum variants passed to attributes that are not id-like in view! macros
+
+#[macro_export]
+macro_rules! view {
+    ($($arg:tt)*) => {
+        println!("View macro called with: {}", stringify!($($arg)*));
+    };
+}
+
+fn main() {
+    view! {
+        <div class=Ids::Foo></div>
+    }
+
+    view! {
+        <input name=Ids::Foo data-section=Ids::Bar />
+    }
+
+    // attributes of nested views are checked and comparisons don't open tags
+    view! {
+        <div hidden={a < b} data-section=Ids::Foo>
+            {move || view! { <span class=Ids::Bar id=Ids::Baz></span> }}
+        </div>
+    }
+
+    // id-like attributes are allowed
+
+    view! {
+        <div id=Ids::Foo></div>
+    }
+
+    view! {
+        <label for=Ids::Foo aria-describedby=Ids::Bar>Foo</label>
+    }
+
+    // other values are not checked
+    view! {
+        <div class="foo" name=foo></div>
+    }
+}
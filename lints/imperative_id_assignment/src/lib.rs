@@ -0,0 +1,139 @@
+#![feature(rustc_private)]
+#![feature(let_chains)]
+#![warn(unused_extern_crates)]
+
+extern crate rustc_ast;
+extern crate rustc_hir;
+
+use clippy_utils::diagnostics::span_lint_and_help;
+use lints_helpers::is_leptos_unique_ids_variant;
+use rustc_ast::LitKind;
+use rustc_hir::{
+    Expr, ExprKind,
+    def::{CtorOf, DefKind, Res},
+};
+use rustc_lint::{LateContext, LateLintPass};
+
+const HELP: &str = concat!(
+    "for further information visit ",
+    "https://github.com/mondeja/leptos-unique-ids/tree/main/lints/imperative_id_assignment#readme"
+);
+const MESSAGE: &str = "id assigned imperatively with a value that is not `Ids` enum";
+
+dylint_linting::declare_late_lint! {
+    /// ### What it does
+    ///
+    /// Check for ids assigned imperatively to `web_sys::Element`s with values that
+    /// are not `Ids` enum variants.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Setting ids with `Element::set_id` or `Element::set_attribute("id", ...)`
+    /// skips the `view!` macro, so the other lints can't ensure that the ids are
+    /// unique in the DOM.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// element.set_id("my-identifier");
+    /// element.set_attribute("id", "my-identifier");
+    /// ```
+    ///
+    /// Use instead:
+    ///
+    /// ```rust,ignore
+    /// use ids::Ids;
+    ///
+    /// element.set_id(Ids::MyIdentifier.as_str());
+    /// element.set_attribute("id", Ids::MyIdentifier.as_str());
+    /// ```
+    pub IMPERATIVE_ID_ASSIGNMENT,
+    Warn,
+    "Check for ids assigned imperatively to `web_sys::Element`s with values that are not `Ids` enum variants."
+}
+
+impl<'tcx> LateLintPass<'tcx> for ImperativeIdAssignment {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let ExprKind::MethodCall(segment, _, args, _) = expr.kind else {
+            return;
+        };
+        let value = match (segment.ident.name.as_str(), args) {
+            ("set_id", [value]) => value,
+            ("set_attribute", [name, value]) if is_id_literal(name) => value,
+            _ => return,
+        };
+        if !is_web_sys_element_method_call(cx, expr) || is_ids_expr(cx, value) {
+            return;
+        }
+        span_lint_and_help(
+            cx,
+            IMPERATIVE_ID_ASSIGNMENT,
+            value.span,
+            MESSAGE,
+            None,
+            HELP,
+        );
+    }
+}
+
+/// Given an expression, return if it's the `"id"` string literal.
+fn is_id_literal(expr: &Expr<'_>) -> bool {
+    if let ExprKind::Lit(lit) = expr.kind
+        && let LitKind::Str(symbol, _) = lit.node
+    {
+        return symbol.as_str().eq_ignore_ascii_case("id");
+    }
+    false
+}
+
+/// Given a method call, return if the method is defined for `web_sys::Element`.
+///
+/// Other elements like `web_sys::HtmlElement` dereference to `web_sys::Element`,
+/// so calls on them are included.
+fn is_web_sys_element_method_call(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let Some(method_def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id) else {
+        return false;
+    };
+    let Some(impl_def_id) = cx.tcx.impl_of_method(method_def_id) else {
+        return false;
+    };
+    let Some(adt_def) = cx
+        .tcx
+        .type_of(impl_def_id)
+        .instantiate_identity()
+        .ty_adt_def()
+    else {
+        return false;
+    };
+    cx.tcx.crate_name(adt_def.did().krate).as_str() == "web_sys"
+        && cx.tcx.item_name(adt_def.did()).as_str() == "Element"
+}
+
+/// Given an expression, return if it's a variant of an enum generated by
+/// `leptos_unique_ids`, possibly referenced or converted like `&Ids::Foo.as_str()`
+/// or `Ids::Foo.into()`.
+fn is_ids_expr(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    match expr.kind {
+        ExprKind::AddrOf(_, _, inner) | ExprKind::MethodCall(_, inner, [], _) => {
+            is_ids_expr(cx, inner)
+        }
+        ExprKind::Path(ref qpath) => {
+            if let Res::Def(DefKind::Ctor(CtorOf::Variant, _), ctor_def_id) =
+                cx.qpath_res(qpath, expr.hir_id)
+            {
+                is_leptos_unique_ids_variant(cx, cx.tcx.parent(ctor_def_id))
+            } else {
+                false
+            }
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn ui() {
+        dylint_testing::ui_test(env!("CARGO_PKG_NAME"), "ui");
+    }
+}
@@ -0,0 +1,48 @@
+//! Catch ids assigned imperatively to `web_sys::Element`s
+
+#![allow(dead_code)]
+
+use leptos_unique_ids::leptos_unique_ids;
+use web_sys::Element;
+
+#[leptos_unique_ids("my-identifier")]
+pub enum Ids {}
+
+#[leptos_unique_ids("submit")]
+pub enum FormIds {}
+
+// Shadowing `Ids` enum not generated by the macro
+mod fake {
+    pub enum Ids {
+        MyIdentifier,
+    }
+
+    impl Ids {
+        pub fn as_str(&self) -> &'static str {
+            "my-identifier"
+        }
+    }
+}
+
+fn set_ids(element: &Element) {
+    let foo = "my-identifier";
+
+    element.set_id("my-identifier");
+    element.set_id(foo);
+    let _ = element.set_attribute("id", "my-identifier");
+    let _ = element.set_attribute("id", foo);
+
+    // Use the Ids enum instead
+    element.set_id(Ids::MyIdentifier.as_str());
+    let _ = element.set_attribute("id", Ids::MyIdentifier.as_str());
+    let _ = element.set_attribute("id", &Ids::MyIdentifier.as_str());
+    element.set_id(FormIds::Submit.as_str());
+
+    // shadowing fake `Ids`
+    element.set_id(fake::Ids::MyIdentifier.as_str());
+
+    // other attributes are not checked
+    let _ = element.set_attribute("class", "my-class");
+}
+
+fn main() {}
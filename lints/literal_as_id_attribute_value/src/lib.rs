@@ -3,26 +3,70 @@
 #![warn(unused_extern_crates)]
 
 extern crate rustc_ast;
+extern crate rustc_lint;
+extern crate rustc_session;
 
 use clippy_utils::diagnostics::span_lint_and_help;
-use lints_helpers::{ViewMacroCallIdAttributeValueIter, is_leptos_view_macro_call};
+use lints_helpers::{
+    DEFAULT_VIEW_MACRO_NAMES, ViewMacroCallIdAttributeValueIter, is_leptos_view_macro_call,
+};
 use rustc_ast::{
     token::{LitKind, TokenKind},
     tokenstream::TokenTree,
 };
-use rustc_lint::{EarlyContext, EarlyLintPass};
+use rustc_lint::{EarlyContext, EarlyLintPass, LintStore};
+use rustc_session::{
+    Session, declare_lint, impl_lint_pass,
+    lint::{Level, Lint},
+};
+use serde::Deserialize;
+
+const DESCRIPTION: &str = "Check for literals passed to id attribute values.";
 
-dylint_linting::declare_pre_expansion_lint! {
+#[cfg(not(feature = "rlib"))]
+dylint_linting::dylint_library!();
+
+declare_lint! {
     /// ### What it does
     ///
     /// Check for literals passed to id attribute values.
     ///
+    /// The `id` props of components, like `<MyButton id="foo"/>`, are checked as well.
+    ///
     /// ### Why is this bad?
     ///
     /// Passing a literal to an id attribute value can lead to issues with duplicate ids
     /// in the DOM, which can cause unexpected behavior in the application. It is
     /// recommended to use leptos-uniques-ids crate to generate unique ids instead.
     ///
+    /// ### Configuration
+    ///
+    /// The macros scanned as Leptos views can be configured in the `dylint.toml`
+    /// file of the workspace with `macros`, which defaults to `["view"]`, to also
+    /// cover `template!` or wrappers around `view!`:
+    ///
+    /// ```toml
+    /// [literal_as_id_attribute_value]
+    /// macros = ["view", "template"]
+    /// ```
+    ///
+    /// Id attributes of elements that legitimately take ids from third parties, like
+    /// custom elements, can be skipped listing their tags in `ignore_tags`:
+    ///
+    /// ```toml
+    /// [literal_as_id_attribute_value]
+    /// ignore_tags = ["web-component-x"]
+    /// ```
+    ///
+    /// The lint warns by default. Set `level` to `"deny"` to make it an error
+    /// without adding `#![deny(literal_as_id_attribute_value)]` to each crate.
+    /// Explicit `#[allow]` and `#[warn]` attributes still take precedence:
+    ///
+    /// ```toml
+    /// [literal_as_id_attribute_value]
+    /// level = "deny"
+    /// ```
+    ///
     /// ### Known problems
     ///
     /// Only checks for literals in the id attribute values of the `view!` macro.
@@ -47,22 +91,95 @@ dylint_linting::declare_pre_expansion_lint! {
     /// ```
     pub LITERAL_AS_ID_ATTRIBUTE_VALUE,
     Warn,
-    "Check for literals passed to id attribute values."
+    DESCRIPTION
+}
+
+/// The same lint denied by default, registered instead of
+/// [`LITERAL_AS_ID_ATTRIBUTE_VALUE`] with `level = "deny"`.
+static LITERAL_AS_ID_ATTRIBUTE_VALUE_DENY: Lint = Lint {
+    name: "LITERAL_AS_ID_ATTRIBUTE_VALUE",
+    default_level: Level::Deny,
+    desc: DESCRIPTION,
+    ..Lint::default_fields_for_macro()
+};
+
+impl_lint_pass!(LiteralAsIdAttributeValue => [LITERAL_AS_ID_ATTRIBUTE_VALUE]);
+
+#[allow(clippy::no_mangle_with_rust_abi)]
+#[cfg_attr(not(feature = "rlib"), unsafe(no_mangle))]
+pub fn register_lints(sess: &Session, lint_store: &mut LintStore) {
+    dylint_linting::init_config(sess);
+    lint_store.register_lints(&[LiteralAsIdAttributeValue::new().lint]);
+    lint_store.register_pre_expansion_pass(|| Box::new(LiteralAsIdAttributeValue::new()));
+}
+
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    macros: Vec<String>,
+    ignore_tags: Vec<String>,
+    level: ConfiguredLevel,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            macros: DEFAULT_VIEW_MACRO_NAMES.map(String::from).to_vec(),
+            ignore_tags: Vec::new(),
+            level: ConfiguredLevel::Warn,
+        }
+    }
+}
+
+/// Default level of the lint, overridden by the attributes of the crate.
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum ConfiguredLevel {
+    Warn,
+    Deny,
+}
+
+pub struct LiteralAsIdAttributeValue {
+    config: Config,
+    // lint registered with the configured default level
+    lint: &'static Lint,
+}
+
+impl LiteralAsIdAttributeValue {
+    pub fn new() -> Self {
+        let config: Config = dylint_linting::config_or_default(env!("CARGO_PKG_NAME"));
+        let lint = match config.level {
+            ConfiguredLevel::Warn => LITERAL_AS_ID_ATTRIBUTE_VALUE,
+            ConfiguredLevel::Deny => &LITERAL_AS_ID_ATTRIBUTE_VALUE_DENY,
+        };
+        Self { config, lint }
+    }
+}
+
+impl Default for LiteralAsIdAttributeValue {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl EarlyLintPass for LiteralAsIdAttributeValue {
     fn check_mac(&mut self, cx: &EarlyContext, macro_call: &rustc_ast::MacCall) {
-        if !is_leptos_view_macro_call(macro_call) {
+        if !is_leptos_view_macro_call(macro_call, &self.config.macros) {
             return;
         }
-        for tt in ViewMacroCallIdAttributeValueIter::new(macro_call) {
+        for (tag_name, tt) in
+            ViewMacroCallIdAttributeValueIter::new(macro_call, &self.config.macros)
+        {
+            if self.config.ignore_tags.contains(&tag_name) {
+                continue;
+            }
             if let TokenTree::Token(token, _) = tt
                 && let TokenKind::Literal(lit) = token.kind
                 && lit.kind == LitKind::Str
             {
                 span_lint_and_help(
                     cx,
-                    LITERAL_AS_ID_ATTRIBUTE_VALUE,
+                    self.lint,
                     token.span,
                     "literal string passed as id attribute value",
                     None,
@@ -79,4 +196,18 @@ mod tests {
     fn ui() {
         dylint_testing::ui_test(env!("CARGO_PKG_NAME"), "ui");
     }
+
+    #[test]
+    fn ui_ignore_tags() {
+        dylint_testing::ui::Test::src_base(env!("CARGO_PKG_NAME"), "ui_ignore_tags")
+            .dylint_toml("[literal_as_id_attribute_value]\nignore_tags = [\"web-component-x\"]")
+            .run();
+    }
+
+    #[test]
+    fn ui_level() {
+        dylint_testing::ui::Test::src_base(env!("CARGO_PKG_NAME"), "ui_level")
+            .dylint_toml("[literal_as_id_attribute_value]\nlevel = \"deny\"")
+            .run();
+    }
 }
@@ -36,4 +36,35 @@ fn main() {
     leptos::view! {
         <div id="my-identifier">Hello</div>
     }
+
+    // self-closing elements, fragments and nested views
+    view! {
+        <>
+            <input id="self-closing"/>
+            <div data-id="not-an-id">
+                {move || view! { <span id="nested"/> }}
+                {
+                    let id = "not-an-id";
+                    id
+                }
+            </div>
+        </>
+    }
+
+    // text content is not an attribute
+    view! {
+        <label>Your id here</label>
+        <p>The id = "not-an-id"</p>
+    }
+
+    // id props of components
+    view! {
+        <MyButton id="my-button"/>
+    }
+    view! {
+        <MyButton id=Ids::MyButton/>
+    }
+    view! {
+        <MyList<String> id="my-list"/>
+    }
 }
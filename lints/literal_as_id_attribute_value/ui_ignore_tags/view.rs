@@ -0,0 +1,18 @@
+//! Skip id attributes of the tags listed in `ignore_tags`
+
+#[macro_export]
+macro_rules! view {
+    ($($arg:tt)*) => {
+        println!("foo");
+    };
+}
+
+fn main() {
+    view! {
+        <web-component-x id="third-party-id">Hello</web-component-x>
+    }
+
+    view! {
+        <div id="third-party-id">Hello</div>
+    }
+}
@@ -0,0 +1,23 @@
+//! Deny literals with `level = "deny"`, unless allowed explicitly
+
+#[macro_export]
+macro_rules! view {
+    ($($arg:tt)*) => {
+        println!("foo");
+    };
+}
+
+fn main() {
+    view! {
+        <div id="denied-id">Hello</div>
+    }
+
+    allowed();
+}
+
+#[allow(literal_as_id_attribute_value)]
+fn allowed() {
+    view! {
+        <div id="allowed-id">Hello</div>
+    }
+}
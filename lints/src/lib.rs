@@ -9,6 +9,14 @@ extern crate rustc_session;
 #[expect(clippy::no_mangle_with_rust_abi)]
 #[unsafe(no_mangle)]
 pub fn register_lints(sess: &rustc_session::Session, lint_store: &mut rustc_lint::LintStore) {
+    cross_enum_duplicate_id::register_lints(sess, lint_store);
+    id_reference_literal::register_lints(sess, lint_store);
+    id_via_spread::register_lints(sess, lint_store);
+    ids_used_as_non_id::register_lints(sess, lint_store);
+    imperative_id_assignment::register_lints(sess, lint_store);
     literal_as_id_attribute_value::register_lints(sess, lint_store);
     tt_as_id_attribute_value::register_lints(sess, lint_store);
+    unknown_ids_variant::register_lints(sess, lint_store);
+    unused_ids_enum::register_lints(sess, lint_store);
+    unused_ids_variant::register_lints(sess, lint_store);
 }
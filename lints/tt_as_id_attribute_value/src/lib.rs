@@ -3,37 +3,101 @@
 #![warn(unused_extern_crates)]
 
 extern crate rustc_ast;
+extern crate rustc_lint;
+extern crate rustc_session;
+extern crate rustc_span;
 
 use clippy_utils::diagnostics::span_lint_and_help;
-use lints_helpers::{ViewMacroCallIdAttributeValueIter, is_leptos_view_macro_call};
+use lints_helpers::{
+    DEFAULT_VIEW_MACRO_NAMES, ViewMacroCallIdAttributeValueIter, is_leptos_view_macro_call,
+};
 use rustc_ast::{
-    token::{LitKind, TokenKind},
-    tokenstream::TokenTree,
+    token::{Delimiter, LitKind, TokenKind},
+    tokenstream::{TokenStream, TokenTree},
+};
+use rustc_lint::{EarlyContext, EarlyLintPass, LintStore};
+use rustc_session::{
+    Session, declare_lint, impl_lint_pass,
+    lint::{Level, Lint},
 };
-use rustc_lint::{EarlyContext, EarlyLintPass};
+use rustc_span::Symbol;
+use serde::Deserialize;
 
 const HELP: &str = concat!(
     "for further information visit ",
     "https://github.com/mondeja/leptos-unique-ids/tree/main/lints/tt_as_id_attribute_value#readme"
 );
 const MESSAGE: &str = "token tree that is not `Ids` enum passed as id attribute value";
+const DESCRIPTION: &str =
+    "Check for token trees passed as id attribute values (except for `Ids` enum variants).";
+
+#[cfg(not(feature = "rlib"))]
+dylint_linting::dylint_library!();
 
-dylint_linting::declare_pre_expansion_lint! {
+declare_lint! {
     /// ### What it does
     ///
     /// Check for token trees passed as id attribute values (except for `Ids` enum variants).
     ///
+    /// The `id` props of components, like `<MyButton id=foo/>`, are checked as well.
+    ///
     /// ### Why is this bad?
     ///
     /// Passing `Ids` enum to an id attribute value is the only way to ensure that
     /// `leptos-unique-ids` has to ensure that the id is unique in the DOM. Other token
     /// trees must be avoided to prevent potential issues with duplicate ids in the DOM.
     ///
+    /// Blocks, `if` and `match` expressions are allowed when all the values that
+    /// they can return are `Ids` enum variants.
+    ///
+    /// ### Configuration
+    ///
+    /// The macros scanned as Leptos views can be configured in the `dylint.toml`
+    /// file of the workspace with `macros`, which defaults to `["view"]`, to also
+    /// cover `template!` or wrappers around `view!`:
+    ///
+    /// ```toml
+    /// [tt_as_id_attribute_value]
+    /// macros = ["view", "template"]
+    /// ```
+    ///
+    /// Id attributes of elements that legitimately take ids from third parties, like
+    /// custom elements, can be skipped listing their tags in `ignore_tags`:
+    ///
+    /// ```toml
+    /// [tt_as_id_attribute_value]
+    /// ignore_tags = ["web-component-x"]
+    /// ```
+    ///
+    /// Paths of the enums generated by `leptos_unique_ids` are configured with
+    /// `ids_type_paths`, which defaults to `["Ids"]`. Set it when the enums have
+    /// other names, including `Ids` if it's still used:
+    ///
+    /// ```toml
+    /// [tt_as_id_attribute_value]
+    /// ids_type_paths = ["crate::ids::Ids", "crate::ids::FormIds"]
+    /// ```
+    ///
+    /// The lint warns by default. Set `level` to `"deny"` to make it an error
+    /// without adding `#![deny(tt_as_id_attribute_value)]` to each crate.
+    /// Explicit `#[allow]` and `#[warn]` attributes still take precedence:
+    ///
+    /// ```toml
+    /// [tt_as_id_attribute_value]
+    /// level = "deny"
+    /// ```
+    ///
     /// ### Known problems
     ///
     /// Only checks for tokens in the id attribute values of the `view!` macro.
     /// Currently, it does not check it in Leptos builder syntax.
     ///
+    /// The lint runs before the paths are resolved, so they are compared as
+    /// written. A value is allowed when the path of its enum is a suffix of a
+    /// configured path, like `FormIds::Name` or `ids::FormIds::Name` for
+    /// `crate::ids::FormIds`, so enums imported with another name with
+    /// `use ... as ...` are not recognized.
+    ///
     /// ### Example
     ///
     /// ```rust,ignore
@@ -55,18 +119,113 @@ dylint_linting::declare_pre_expansion_lint! {
     /// ```
     pub TT_AS_ID_ATTRIBUTE_VALUE,
     Warn,
-    "Check for token trees passed as id attribute values (except for `Ids` enum variants)."
+    DESCRIPTION
+}
+
+/// The same lint denied by default, registered instead of
+/// [`TT_AS_ID_ATTRIBUTE_VALUE`] with `level = "deny"`.
+static TT_AS_ID_ATTRIBUTE_VALUE_DENY: Lint = Lint {
+    name: "TT_AS_ID_ATTRIBUTE_VALUE",
+    default_level: Level::Deny,
+    desc: DESCRIPTION,
+    ..Lint::default_fields_for_macro()
+};
+
+impl_lint_pass!(TtAsIdAttributeValue => [TT_AS_ID_ATTRIBUTE_VALUE]);
+
+#[allow(clippy::no_mangle_with_rust_abi)]
+#[cfg_attr(not(feature = "rlib"), unsafe(no_mangle))]
+pub fn register_lints(sess: &Session, lint_store: &mut LintStore) {
+    dylint_linting::init_config(sess);
+    lint_store.register_lints(&[TtAsIdAttributeValue::new().lint]);
+    lint_store.register_pre_expansion_pass(|| Box::new(TtAsIdAttributeValue::new()));
+}
+
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    macros: Vec<String>,
+    ignore_tags: Vec<String>,
+    ids_type_paths: Vec<String>,
+    level: ConfiguredLevel,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            macros: DEFAULT_VIEW_MACRO_NAMES.map(String::from).to_vec(),
+            ignore_tags: Vec::new(),
+            ids_type_paths: vec!["Ids".to_string()],
+            level: ConfiguredLevel::Warn,
+        }
+    }
+}
+
+/// Default level of the lint, overridden by the attributes of the crate.
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum ConfiguredLevel {
+    Warn,
+    Deny,
+}
+
+pub struct TtAsIdAttributeValue {
+    config: Config,
+    // segments of the configured `ids_type_paths`, like `["crate", "ids", "Ids"]`
+    ids_types: Vec<Vec<String>>,
+    // lint registered with the configured default level
+    lint: &'static Lint,
+}
+
+impl TtAsIdAttributeValue {
+    pub fn new() -> Self {
+        let config: Config = dylint_linting::config_or_default(env!("CARGO_PKG_NAME"));
+        let ids_types = config
+            .ids_type_paths
+            .iter()
+            .map(|path| {
+                path.split("::")
+                    .map(|segment| segment.trim().to_string())
+                    .collect()
+            })
+            .collect();
+        let lint = match config.level {
+            ConfiguredLevel::Warn => TT_AS_ID_ATTRIBUTE_VALUE,
+            ConfiguredLevel::Deny => &TT_AS_ID_ATTRIBUTE_VALUE_DENY,
+        };
+        Self {
+            config,
+            ids_types,
+            lint,
+        }
+    }
+}
+
+impl Default for TtAsIdAttributeValue {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl EarlyLintPass for TtAsIdAttributeValue {
     fn check_mac(&mut self, cx: &EarlyContext, macro_call: &rustc_ast::MacCall) {
-        if !is_leptos_view_macro_call(macro_call) {
+        if !is_leptos_view_macro_call(macro_call, &self.config.macros) {
             return;
         }
-        for tt in ViewMacroCallIdAttributeValueIter::new(macro_call) {
+        let mut iter = ViewMacroCallIdAttributeValueIter::new(macro_call, &self.config.macros);
+        while let Some((tag_name, tt)) = iter.next() {
+            if self.config.ignore_tags.contains(&tag_name) {
+                continue;
+            }
             if let TokenTree::Token(token, _) = tt {
-                if let TokenKind::Ident(symbol, _) = token.kind {
-                    if symbol.as_str() == "Ids" {
+                if let TokenKind::Ident(..) = token.kind {
+                    // the value is yielded as its first token tree, so the rest
+                    // of the path is read from the following ones
+                    let following = iter.following().into_iter().flatten();
+                    if is_ids_path(
+                        &path_segments([tt].into_iter().chain(following)),
+                        &self.ids_types,
+                    ) {
                         continue;
                     }
                 } else if let TokenKind::Literal(lit) = token.kind
@@ -75,32 +234,191 @@ impl EarlyLintPass for TtAsIdAttributeValue {
                     // this case is catched by `literal_as_id_attribute_value` lint
                     continue;
                 }
-                span_lint_and_help(
-                    cx,
-                    TT_AS_ID_ATTRIBUTE_VALUE,
-                    token.span,
-                    MESSAGE,
-                    None,
-                    HELP,
-                );
-            } else if let TokenTree::Delimited(delim_span, ..) = tt {
-                span_lint_and_help(
-                    cx,
-                    TT_AS_ID_ATTRIBUTE_VALUE,
-                    delim_span.entire(),
-                    MESSAGE,
-                    None,
-                    HELP,
-                );
+                span_lint_and_help(cx, self.lint, token.span, MESSAGE, None, HELP);
+            } else if let TokenTree::Delimited(delim_span, ..) = tt
+                && !is_ids_expr(&[tt], &self.ids_types)
+            {
+                span_lint_and_help(cx, self.lint, delim_span.entire(), MESSAGE, None, HELP);
             }
         }
     }
 }
 
+/// Given the segments of a path, like `["ids", "Ids", "Foo"]`, return if it's
+/// a variant of an `Ids` enum.
+///
+/// The path without its last segment, the variant, must be a suffix of one of the
+/// configured paths of the enums.
+fn is_ids_path(segments: &[Symbol], ids_types: &[Vec<String>]) -> bool {
+    let Some((_, type_path)) = segments.split_last() else {
+        return false;
+    };
+    !type_path.is_empty()
+        && ids_types.iter().any(|ids_type| {
+            ids_type.len() >= type_path.len()
+                && ids_type[ids_type.len() - type_path.len()..]
+                    .iter()
+                    .zip(type_path)
+                    .all(|(configured, segment)| configured == segment.as_str())
+        })
+}
+
+/// Return the segments of the path at the start of the token trees, like
+/// `["Ids", "Foo"]` for `Ids::Foo.as_str()`.
+fn path_segments<'a>(mut tts: impl Iterator<Item = &'a TokenTree>) -> Vec<Symbol> {
+    let mut segments = Vec::new();
+    while let Some(TokenTree::Token(token, _)) = tts.next()
+        && let TokenKind::Ident(symbol, _) = token.kind
+    {
+        segments.push(symbol);
+        if !tts
+            .next()
+            .is_some_and(|tt| is_token(tt, &TokenKind::PathSep))
+        {
+            break;
+        }
+    }
+    segments
+}
+
+/// Given the token trees of an expression, return if all the values that it can
+/// return are `Ids` enum variants.
+///
+/// Blocks return their last expression, `if` expressions the values of all their
+/// branches and `match` expressions the values of all their arms.
+fn is_ids_expr(tts: &[&TokenTree], ids_types: &[Vec<String>]) -> bool {
+    match tts {
+        [TokenTree::Delimited(_, _, Delimiter::Brace, stream)] => is_ids_block(stream, ids_types),
+        [TokenTree::Delimited(_, _, Delimiter::Parenthesis, stream)] => {
+            is_ids_expr(&stream.iter().collect::<Vec<_>>(), ids_types)
+        }
+        [first, rest @ ..] if is_ident(first, "if") => is_ids_if(rest, ids_types),
+        [first, rest @ ..] if is_ident(first, "match") => is_ids_match(rest, ids_types),
+        [] => false,
+        _ => is_ids_path(&path_segments(tts.iter().copied()), ids_types),
+    }
+}
+
+/// Given the token stream inside the braces of a block, return if its last
+/// expression is an `Ids` enum variant.
+fn is_ids_block(stream: &TokenStream, ids_types: &[Vec<String>]) -> bool {
+    let tts = stream.iter().collect::<Vec<_>>();
+    let last_expr = tts
+        .rsplit(|tt| is_token(tt, &TokenKind::Semi))
+        .next()
+        .unwrap_or_default();
+    is_ids_expr(last_expr, ids_types)
+}
+
+/// Given the token trees of an `if` expression after the `if` keyword, return if
+/// all its branches return `Ids` enum variants.
+fn is_ids_if(tts: &[&TokenTree], ids_types: &[Vec<String>]) -> bool {
+    // the first block after the condition is the `then` branch
+    let Some(then_position) = tts.iter().position(|tt| is_brace_group(tt)) else {
+        return false;
+    };
+    if !is_ids_expr(&tts[then_position..=then_position], ids_types) {
+        return false;
+    }
+    match &tts[then_position + 1..] {
+        [else_keyword, rest @ ..] if is_ident(else_keyword, "else") => match rest {
+            [if_keyword, rest @ ..] if is_ident(if_keyword, "if") => is_ids_if(rest, ids_types),
+            [else_block] => is_ids_expr(&[*else_block], ids_types),
+            _ => false,
+        },
+        // without `else` branch the expression returns `()`
+        _ => false,
+    }
+}
+
+/// Given the token trees of a `match` expression after the `match` keyword,
+/// return if all its arms return `Ids` enum variants.
+fn is_ids_match(tts: &[&TokenTree], ids_types: &[Vec<String>]) -> bool {
+    let Some(TokenTree::Delimited(_, _, _, arms)) = tts.iter().find(|tt| is_brace_group(tt)) else {
+        return false;
+    };
+
+    let arms = arms.iter().collect::<Vec<_>>();
+    let mut rest = &arms[..];
+    let mut has_arms = false;
+    while !rest.is_empty() {
+        // pattern and guard of the arm
+        let Some(fat_arrow_position) = rest
+            .iter()
+            .position(|tt| is_token(tt, &TokenKind::FatArrow))
+        else {
+            return false;
+        };
+        rest = &rest[fat_arrow_position + 1..];
+
+        // arms with blocks don't need a comma to be separated
+        let expr_end = if rest.first().is_some_and(|tt| is_brace_group(tt)) {
+            1
+        } else {
+            rest.iter()
+                .position(|tt| is_token(tt, &TokenKind::Comma))
+                .unwrap_or(rest.len())
+        };
+        if !is_ids_expr(&rest[..expr_end], ids_types) {
+            return false;
+        }
+        has_arms = true;
+
+        rest = &rest[expr_end..];
+        if rest
+            .first()
+            .is_some_and(|tt| is_token(tt, &TokenKind::Comma))
+        {
+            rest = &rest[1..];
+        }
+    }
+    has_arms
+}
+
+fn is_ident(tt: &TokenTree, name: &str) -> bool {
+    if let TokenTree::Token(token, _) = tt
+        && let TokenKind::Ident(symbol, _) = token.kind
+    {
+        return symbol.as_str() == name;
+    }
+    false
+}
+
+fn is_token(tt: &TokenTree, kind: &TokenKind) -> bool {
+    matches!(tt, TokenTree::Token(token, _) if token.kind == *kind)
+}
+
+fn is_brace_group(tt: &TokenTree) -> bool {
+    matches!(tt, TokenTree::Delimited(_, _, Delimiter::Brace, _))
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
     fn ui() {
         dylint_testing::ui_test(env!("CARGO_PKG_NAME"), "ui");
     }
+
+    #[test]
+    fn ui_ids_type_paths() {
+        dylint_testing::ui::Test::src_base(env!("CARGO_PKG_NAME"), "ui_ids_type_paths")
+            .dylint_toml(
+                "[tt_as_id_attribute_value]\nids_type_paths = [\"crate::ids::Ids\", \"crate::ids::FormIds\"]",
+            )
+            .run();
+    }
+
+    #[test]
+    fn ui_ignore_tags() {
+        dylint_testing::ui::Test::src_base(env!("CARGO_PKG_NAME"), "ui_ignore_tags")
+            .dylint_toml("[tt_as_id_attribute_value]\nignore_tags = [\"web-component-x\"]")
+            .run();
+    }
+
+    #[test]
+    fn ui_level() {
+        dylint_testing::ui::Test::src_base(env!("CARGO_PKG_NAME"), "ui_level")
+            .dylint_toml("[tt_as_id_attribute_value]\nlevel = \"deny\"")
+            .run();
+    }
 }
@@ -37,4 +37,49 @@ fn main() {
             my_id
         }>Hello, world!</div>
     }
+
+    #[allow(unused_variables)]
+    let dark = true;
+
+    // conditionals returning `Ids` enum variants in all branches are allowed
+    view! {
+        <div id={if dark { Ids::Dark } else { Ids::Light }}>Hello, world!</div>
+    }
+
+    view! {
+        <div id={match dark {
+            true => Ids::Dark,
+            false => Ids::Light,
+        }}>Hello, world!</div>
+    }
+
+    view! {
+        <div id={if dark { Ids::Dark } else { foo }}>Hello, world!</div>
+    }
+
+    view! {
+        <div id={match dark {
+            true => Ids::Dark,
+            false => foo,
+        }}>Hello, world!</div>
+    }
+
+    // enums with other names are not recognized without configuring them
+    view! {
+        <div id=FormIds::Name>Hello, world!</div>
+    }
+
+    // text content is not an attribute
+    view! {
+        <label>Your id here</label>
+        <p>The id = not_an_id</p>
+    }
+
+    // id props of components
+    view! {
+        <MyButton id=foo/>
+    }
+    view! {
+        <MyButton id=Ids::MyButton/>
+    }
 }
@@ -0,0 +1,46 @@
+//! Allow the enums configured in `ids_type_paths`
+
+#[macro_export]
+macro_rules! view {
+    ($($arg:tt)*) => {
+        println!("View macro called with: {}", stringify!($($arg)*));
+    };
+}
+
+fn main() {
+    #[allow(unused_variables)]
+    let dark = true;
+
+    view! {
+        <div id=Ids::MyIdentifier>Hello, world!</div>
+    }
+
+    view! {
+        <div id=FormIds::Name>Hello, world!</div>
+    }
+
+    view! {
+        <div id=ids::FormIds::Name>Hello, world!</div>
+    }
+
+    view! {
+        <div id=crate::ids::FormIds::Name>Hello, world!</div>
+    }
+
+    view! {
+        <div id={if dark { FormIds::Dark } else { Ids::Light }}>Hello, world!</div>
+    }
+
+    // enums not configured are not allowed
+    view! {
+        <div id=OtherIds::Name>Hello, world!</div>
+    }
+
+    view! {
+        <div id=other::FormIds::Name>Hello, world!</div>
+    }
+
+    view! {
+        <div id={if dark { FormIds::Dark } else { OtherIds::Light }}>Hello, world!</div>
+    }
+}
@@ -0,0 +1,21 @@
+//! Skip id attributes of the tags listed in `ignore_tags`
+
+#[macro_export]
+macro_rules! view {
+    ($($arg:tt)*) => {
+        println!("View macro called with: {}", stringify!($($arg)*));
+    };
+}
+
+fn main() {
+    #[allow(unused_variables)]
+    let third_party_id = "third-party-id";
+
+    view! {
+        <web-component-x id=third_party_id>Hello, world!</web-component-x>
+    }
+
+    view! {
+        <div id=third_party_id>Hello, world!</div>
+    }
+}
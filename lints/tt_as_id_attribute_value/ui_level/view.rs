@@ -0,0 +1,29 @@
+//! Deny token trees with `level = "deny"`, unless allowed explicitly
+
+#[macro_export]
+macro_rules! view {
+    ($($arg:tt)*) => {
+        println!("View macro called with: {}", stringify!($($arg)*));
+    };
+}
+
+fn main() {
+    #[allow(unused_variables)]
+    let denied_id = "denied-id";
+
+    view! {
+        <div id=denied_id>Hello, world!</div>
+    }
+
+    allowed();
+}
+
+#[allow(tt_as_id_attribute_value)]
+fn allowed() {
+    #[allow(unused_variables)]
+    let allowed_id = "allowed-id";
+
+    view! {
+        <div id=allowed_id>Hello, world!</div>
+    }
+}
@@ -0,0 +1,133 @@
+#![feature(rustc_private)]
+#![feature(let_chains)]
+#![warn(unused_extern_crates)]
+
+extern crate rustc_hir;
+
+use clippy_utils::diagnostics::span_lint_and_help;
+use lints_helpers::is_leptos_unique_ids_variant;
+use rustc_hir::{
+    Expr, ExprKind, QPath, TyKind,
+    def::{CtorOf, DefKind, Res},
+};
+use rustc_lint::{LateContext, LateLintPass};
+
+const HELP: &str = concat!(
+    "for further information visit ",
+    "https://github.com/mondeja/leptos-unique-ids/tree/main/lints/unknown_ids_variant#readme"
+);
+const MESSAGE: &str =
+    "path passed as id attribute value is not a variant generated by `leptos_unique_ids`";
+
+dylint_linting::declare_late_lint! {
+    /// ### What it does
+    ///
+    /// Check for type-qualified paths, like `Ids::Foo`, passed as id attribute
+    /// values that are not variants of an enum generated by `leptos_unique_ids`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Other lints trust any path like `Ids::Foo` passed as id attribute value,
+    /// but it could resolve to an item that is not a variant of the real `Ids` enum,
+    /// like a variant of an unrelated enum named `Ids` or a constant defined in an
+    /// `impl Ids` block, so the identifier is not guaranteed to be unique. The
+    /// name of the enum is not checked, so variants of renamed enums like
+    /// `FormIds` are accepted and variants of other enums are reported.
+    ///
+    /// ### Known problems
+    ///
+    /// Only checks the arguments of functions and methods named `id`, which are
+    /// used to set id attributes by the code expanded from the `view!` macro and
+    /// by the Leptos builder syntax.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// mod fake {
+    ///     pub enum Ids {
+    ///         LanguageSelector,
+    ///     }
+    /// }
+    ///
+    /// view! {
+    ///     <div id=fake::Ids::LanguageSelector>Hello, world!</div>
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    ///
+    /// ```rust,ignore
+    /// use ids::Ids;
+    ///
+    /// view! {
+    ///     <div id=Ids::LanguageSelector>Hello, world!</div>
+    /// }
+    /// ```
+    pub UNKNOWN_IDS_VARIANT,
+    Warn,
+    "Check for type-qualified paths passed as id attribute values that are not variants of an enum generated by `leptos_unique_ids`."
+}
+
+impl<'tcx> LateLintPass<'tcx> for UnknownIdsVariant {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let Some(value) = id_attribute_value(expr) else {
+            return;
+        };
+        let ExprKind::Path(ref qpath) = value.kind else {
+            return;
+        };
+        if !is_type_qualified_qpath(qpath) {
+            return;
+        }
+        if let Res::Def(DefKind::Ctor(CtorOf::Variant, _), ctor_def_id) =
+            cx.qpath_res(qpath, value.hir_id)
+            && is_leptos_unique_ids_variant(cx, cx.tcx.parent(ctor_def_id))
+        {
+            return;
+        }
+        span_lint_and_help(cx, UNKNOWN_IDS_VARIANT, value.span, MESSAGE, None, HELP);
+    }
+}
+
+/// Given an expression, return the value passed to it if it sets an id attribute,
+/// like `.id(value)` or `id(value)`.
+fn id_attribute_value<'tcx>(expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    let (name, value) = match expr.kind {
+        ExprKind::MethodCall(segment, _, [value], _) => (segment.ident.name, value),
+        ExprKind::Call(callee, [value]) => {
+            let ExprKind::Path(QPath::Resolved(None, path)) = callee.kind else {
+                return None;
+            };
+            (path.segments.last()?.ident.name, value)
+        }
+        _ => return None,
+    };
+    (name.as_str() == "id").then_some(value)
+}
+
+/// Given a path, return if it's qualified by a type, like `Ids::Foo`,
+/// `ids::FormIds::Foo` or `Ids::FOO`.
+///
+/// The name of the type is not checked, as enums generated by `leptos_unique_ids`
+/// can be renamed and their variants are found by the origin of their spans.
+fn is_type_qualified_qpath(qpath: &QPath<'_>) -> bool {
+    match qpath {
+        QPath::Resolved(_, path) => path.segments.iter().rev().nth(1).is_some_and(|segment| {
+            matches!(
+                segment.res,
+                Res::Def(DefKind::Enum | DefKind::Struct | DefKind::TyAlias, _)
+                    | Res::SelfTyAlias { .. }
+            )
+        }),
+        QPath::TypeRelative(ty, _) => matches!(ty.kind, TyKind::Path(QPath::Resolved(..))),
+        QPath::LangItem(..) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn ui() {
+        dylint_testing::ui_test(env!("CARGO_PKG_NAME"), "ui");
+    }
+}
@@ -0,0 +1,69 @@
+//! Catch type-qualified paths passed as id attribute values that are not generated variants
+
+#![allow(dead_code)]
+
+use leptos_unique_ids::leptos_unique_ids;
+
+struct Div;
+
+impl Div {
+    fn id(self, _id: impl AsRef<str>) -> Self {
+        self
+    }
+}
+
+fn id(_id: impl AsRef<str>) {}
+
+#[leptos_unique_ids("language-selector")]
+pub enum Ids {}
+
+// Renamed enum generated by the macro
+#[leptos_unique_ids("preview")]
+pub enum FormIds {}
+
+// Misspelled variant that compiles because it's defined in an `impl Ids` block
+#[allow(non_upper_case_globals)]
+impl Ids {
+    const LanguageSelecter: Ids = Ids::LanguageSelector;
+}
+
+// Shadowing `Ids` enum not generated by the macro
+mod fake {
+    pub enum Ids {
+        LanguageSelector,
+    }
+
+    impl AsRef<str> for Ids {
+        fn as_ref(&self) -> &str {
+            "language-selector"
+        }
+    }
+
+    // Enum not generated by the macro, whatever its name
+    pub enum Status {
+        Active,
+    }
+
+    impl AsRef<str> for Status {
+        fn as_ref(&self) -> &str {
+            "active"
+        }
+    }
+}
+
+fn main() {
+    // valid variant
+    Div.id(Ids::LanguageSelector);
+    id(Ids::LanguageSelector);
+    Div.id(FormIds::Preview);
+
+    // misspelled variant
+    Div.id(Ids::LanguageSelecter);
+
+    // shadowing fake `Ids`
+    Div.id(fake::Ids::LanguageSelector);
+    id(fake::Ids::LanguageSelector);
+
+    // not generated enum with another name
+    Div.id(fake::Status::Active);
+}
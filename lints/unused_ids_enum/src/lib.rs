@@ -0,0 +1,173 @@
+#![feature(rustc_private)]
+#![feature(let_chains)]
+#![warn(unused_extern_crates)]
+
+extern crate rustc_data_structures;
+extern crate rustc_hir;
+extern crate rustc_span;
+
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use lints_helpers::is_leptos_unique_ids_variant;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::{
+    Expr, ExprKind, HirId, Item, ItemKind, Pat, PatExprKind, PatKind, QPath, TyKind,
+    def::{CtorOf, DefKind, Res},
+};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_span::{Span, def_id::DefId};
+
+const HELP: &str = concat!(
+    "for further information visit ",
+    "https://github.com/mondeja/leptos-unique-ids/tree/main/lints/unused_ids_enum#readme"
+);
+const MESSAGE: &str = "`Ids` enum whose variants are never used";
+
+dylint_linting::impl_late_lint! {
+    /// ### What it does
+    ///
+    /// Check for `Ids` enums whose variants are never used in the crate.
+    ///
+    /// The enums are found by the origin of their variants, so enums generated
+    /// by `leptos_unique_ids` with other names, like `FormIds`, are checked too.
+    ///
+    /// Variants in patterns, like `match` arms, and associated items of the
+    /// enum, like `Ids::ALL`, count as uses.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An `Ids` enum that is declared but never used is dead code that still
+    /// reserves identifiers. In large workspaces it usually means that the ids
+    /// have been moved to other enum and the old one was forgotten.
+    ///
+    /// ### Known problems
+    ///
+    /// Only the current crate is analyzed, so enums declared in a dedicated ids
+    /// crate and used from other crates of the workspace will be reported. Allow
+    /// the lint in the enum declaration for these cases:
+    ///
+    /// ```rust,ignore
+    /// #[allow(unused_ids_enum)]
+    /// #[leptos_unique_ids("language-selector")]
+    /// pub enum Ids {}
+    /// ```
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// #[leptos_unique_ids("language-selector")]
+    /// pub enum Ids {}
+    ///
+    /// view! {
+    ///     <div id="language-selector">Hello, world!</div>
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    ///
+    /// ```rust,ignore
+    /// #[leptos_unique_ids("language-selector")]
+    /// pub enum Ids {}
+    ///
+    /// view! {
+    ///     <div id=Ids::LanguageSelector>Hello, world!</div>
+    /// }
+    /// ```
+    pub UNUSED_IDS_ENUM,
+    Warn,
+    "Check for `Ids` enums whose variants are never used in the crate.",
+    UnusedIdsEnum::default()
+}
+
+#[derive(Default)]
+pub struct UnusedIdsEnum {
+    /// `Ids` enums declared in the crate, in declaration order.
+    enums: Vec<(DefId, HirId, Span)>,
+    /// `Ids` enums with at least one variant used in the crate.
+    used_enums: FxHashSet<DefId>,
+}
+
+impl<'tcx> LateLintPass<'tcx> for UnusedIdsEnum {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        let ItemKind::Enum(..) = item.kind else {
+            return;
+        };
+        let def_id = item.owner_id.to_def_id();
+        if cx
+            .tcx
+            .adt_def(def_id)
+            .variants()
+            .iter()
+            .next()
+            .is_some_and(|variant| is_leptos_unique_ids_variant(cx, variant.def_id))
+        {
+            self.enums
+                .push((def_id, item.hir_id(), cx.tcx.def_span(def_id)));
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        // Uses generated by the `leptos_unique_ids` macro itself don't count.
+        if expr.span.from_expansion() {
+            return;
+        }
+        if let ExprKind::Path(ref qpath) = expr.kind {
+            self.check_qpath(cx, qpath, expr.hir_id);
+        }
+    }
+
+    fn check_pat(&mut self, cx: &LateContext<'tcx>, pat: &'tcx Pat<'tcx>) {
+        if pat.span.from_expansion() {
+            return;
+        }
+        match pat.kind {
+            // unit variants, like `Ids::Foo => ..` in `match` arms
+            PatKind::Expr(pat_expr) => {
+                if let PatExprKind::Path(ref qpath) = pat_expr.kind {
+                    self.check_qpath(cx, qpath, pat_expr.hir_id);
+                }
+            }
+            PatKind::TupleStruct(ref qpath, ..) => {
+                self.check_qpath(cx, qpath, pat.hir_id);
+            }
+            _ => {}
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for (def_id, hir_id, span) in &self.enums {
+            if self.used_enums.contains(def_id) {
+                continue;
+            }
+            span_lint_hir_and_then(cx, UNUSED_IDS_ENUM, *hir_id, *span, MESSAGE, |diag| {
+                diag.help(HELP);
+            });
+        }
+    }
+}
+
+impl UnusedIdsEnum {
+    /// Mark as used the enum of a variant path, like `Ids::Foo`, or of an
+    /// associated item path, like `Ids::ALL` or `Ids::iter`.
+    fn check_qpath(&mut self, cx: &LateContext<'_>, qpath: &QPath<'_>, hir_id: HirId) {
+        if let QPath::TypeRelative(ty, _) = qpath {
+            if let TyKind::Path(QPath::Resolved(None, path)) = ty.kind
+                && let Res::Def(DefKind::Enum, enum_def_id) = path.res
+            {
+                self.used_enums.insert(enum_def_id);
+            }
+        } else if let Res::Def(DefKind::Ctor(CtorOf::Variant, _), ctor_def_id) =
+            cx.qpath_res(qpath, hir_id)
+        {
+            let variant_def_id = cx.tcx.parent(ctor_def_id);
+            self.used_enums.insert(cx.tcx.parent(variant_def_id));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn ui() {
+        dylint_testing::ui_test(env!("CARGO_PKG_NAME"), "ui");
+    }
+}
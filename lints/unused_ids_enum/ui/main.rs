@@ -0,0 +1,52 @@
+//! Catch `Ids` enums whose variants are never used
+
+#![allow(dead_code)]
+
+use leptos_unique_ids::leptos_unique_ids;
+
+mod unused {
+    use super::leptos_unique_ids;
+
+    #[leptos_unique_ids("foo", "bar")]
+    pub enum Ids {}
+}
+
+mod used {
+    use super::leptos_unique_ids;
+
+    #[leptos_unique_ids("foo", "bar")]
+    pub enum Ids {}
+
+    pub fn foo() -> &'static str {
+        Ids::Foo.as_str()
+    }
+}
+
+// Renamed enums are checked too
+mod form {
+    use super::leptos_unique_ids;
+
+    #[leptos_unique_ids("submit")]
+    pub enum FormIds {}
+}
+
+// Enums named `Ids` not generated by the macro are not checked
+mod hand_written {
+    pub enum Ids {
+        Foo,
+        Bar,
+    }
+}
+
+// Intentionally public ids crates can allow the lint
+mod allowed {
+    use super::leptos_unique_ids;
+
+    #[allow(unused_ids_enum)]
+    #[leptos_unique_ids("foo")]
+    pub enum Ids {}
+}
+
+fn main() {
+    used::foo();
+}
@@ -0,0 +1,34 @@
+//! Enums only used in patterns or through associated items are used
+
+#![allow(dead_code)]
+
+use leptos_unique_ids::leptos_unique_ids;
+
+mod pattern {
+    use super::leptos_unique_ids;
+
+    #[leptos_unique_ids("foo", "bar")]
+    pub enum Ids {}
+
+    pub fn label(id: Ids) -> &'static str {
+        match id {
+            Ids::Foo => "Foo",
+            Ids::Bar => "Bar",
+        }
+    }
+}
+
+mod associated {
+    use super::leptos_unique_ids;
+
+    #[leptos_unique_ids("foo", "bar")]
+    pub enum Ids {}
+
+    pub fn count() -> usize {
+        Ids::ALL.len()
+    }
+}
+
+fn main() {
+    associated::count();
+}
@@ -0,0 +1,151 @@
+#![feature(rustc_private)]
+#![feature(let_chains)]
+#![warn(unused_extern_crates)]
+
+extern crate rustc_data_structures;
+extern crate rustc_hir;
+extern crate rustc_span;
+
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use lints_helpers::is_leptos_unique_ids_variant;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::{
+    Expr, ExprKind, HirId, Item, ItemKind,
+    def::{CtorOf, DefKind, Res},
+};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_span::{Span, Symbol, def_id::DefId};
+
+const HELP: &str = concat!(
+    "for further information visit ",
+    "https://github.com/mondeja/leptos-unique-ids/tree/main/lints/unused_ids_variant#readme"
+);
+
+dylint_linting::impl_late_lint! {
+    /// ### What it does
+    ///
+    /// Check for variants of `Ids` enums that are never used in the crate.
+    ///
+    /// The enums are found by the origin of their variants, so enums generated
+    /// by `leptos_unique_ids` with other names, like `FormIds`, are checked too.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Identifiers are usually abandoned when the elements that used them are
+    /// removed, but they stay declared in the `Ids` enum reserving identifiers
+    /// that nobody uses.
+    ///
+    /// ### Known problems
+    ///
+    /// Only the current crate is analyzed, so variants used from other crates of
+    /// the workspace will be reported. Pass the identifiers of these variants in
+    /// the `allow_unused` argument of the `leptos_unique_ids` macro or allow the
+    /// lint for the whole enum:
+    ///
+    /// ```rust,ignore
+    /// #[leptos_unique_ids("language-selector", allow_unused = ["language-selector"])]
+    /// pub enum Ids {}
+    /// ```
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// #[leptos_unique_ids("language-selector", "old-language-selector")]
+    /// pub enum Ids {}
+    ///
+    /// view! {
+    ///     <div id=Ids::LanguageSelector>Hello, world!</div>
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    ///
+    /// ```rust,ignore
+    /// #[leptos_unique_ids("language-selector")]
+    /// pub enum Ids {}
+    ///
+    /// view! {
+    ///     <div id=Ids::LanguageSelector>Hello, world!</div>
+    /// }
+    /// ```
+    pub UNUSED_IDS_VARIANT,
+    Warn,
+    "Check for variants of `Ids` enums that are never used in the crate.",
+    UnusedIdsVariant::default()
+}
+
+#[derive(Default)]
+pub struct UnusedIdsVariant {
+    /// Variants of the `Ids` enums declared in the crate, in declaration order,
+    /// with the name and the span of their enum.
+    variants: Vec<(DefId, HirId, Symbol, Symbol, Span)>,
+    /// Variants of `Ids` enums used in the crate.
+    used_variants: FxHashSet<DefId>,
+}
+
+impl<'tcx> LateLintPass<'tcx> for UnusedIdsVariant {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        let ItemKind::Enum(..) = item.kind else {
+            return;
+        };
+        let def_id = item.owner_id.to_def_id();
+        let enum_name = cx.tcx.item_name(def_id);
+        let span = cx.tcx.def_span(def_id);
+        for variant in cx.tcx.adt_def(def_id).variants() {
+            if !is_leptos_unique_ids_variant(cx, variant.def_id) {
+                return;
+            }
+            let Some(variant_local_def_id) = variant.def_id.as_local() else {
+                continue;
+            };
+            self.variants.push((
+                variant.def_id,
+                cx.tcx.local_def_id_to_hir_id(variant_local_def_id),
+                enum_name,
+                variant.name,
+                span,
+            ));
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        // Uses generated by the `leptos_unique_ids` macro itself don't count.
+        if expr.span.from_expansion() {
+            return;
+        }
+        if let ExprKind::Path(ref qpath) = expr.kind
+            && let Res::Def(DefKind::Ctor(CtorOf::Variant, _), ctor_def_id) =
+                cx.qpath_res(qpath, expr.hir_id)
+        {
+            self.used_variants.insert(cx.tcx.parent(ctor_def_id));
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for (def_id, hir_id, enum_name, name, span) in &self.variants {
+            if self.used_variants.contains(def_id) {
+                continue;
+            }
+            // The lint level is taken from the variant, so it can be allowed
+            // for the variant or for the whole enum.
+            span_lint_hir_and_then(
+                cx,
+                UNUSED_IDS_VARIANT,
+                *hir_id,
+                *span,
+                format!("`{enum_name}` variant `{name}` is never used"),
+                |diag| {
+                    diag.help(HELP);
+                },
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn ui() {
+        dylint_testing::ui_test(env!("CARGO_PKG_NAME"), "ui");
+    }
+}
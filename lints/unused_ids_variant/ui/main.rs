@@ -0,0 +1,39 @@
+//! Catch variants of `Ids` enums that are never used
+
+use leptos_unique_ids::leptos_unique_ids;
+
+mod ids {
+    use super::leptos_unique_ids;
+
+    #[leptos_unique_ids("used", "unused", "shared", allow_unused = ["shared"])]
+    pub enum Ids {}
+}
+
+// Renamed enums are checked too
+mod form {
+    use super::leptos_unique_ids;
+
+    #[leptos_unique_ids("submit")]
+    pub enum FormIds {}
+}
+
+// Enums named `Ids` not generated by the macro are not checked
+mod hand_written {
+    #[allow(dead_code)]
+    pub enum Ids {
+        Unused,
+    }
+}
+
+// Intentionally public ids crates can allow the lint for the whole enum
+mod allowed {
+    use super::leptos_unique_ids;
+
+    #[allow(unused_ids_variant)]
+    #[leptos_unique_ids("foo")]
+    pub enum Ids {}
+}
+
+fn main() {
+    assert_eq!(ids::Ids::Used.as_str(), "used");
+}
@@ -0,0 +1,123 @@
+use crate::pascal_case::to_kebab_case;
+
+/// Case of the identifiers emitted by the `leptos_unique_ids` macro, passed
+/// in the `id_case` argument.
+#[derive(Clone, Copy)]
+pub enum IdCase {
+    /// `kebab-case`
+    Kebab,
+    /// `snake_case`
+    Snake,
+    /// `camelCase`
+    Camel,
+}
+
+impl IdCase {
+    /// Parse the value of the `id_case` argument.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "kebab" => Some(Self::Kebab),
+            "snake" => Some(Self::Snake),
+            "camel" => Some(Self::Camel),
+            _ => None,
+        }
+    }
+
+    /// Convert an identifier to this case.
+    ///
+    /// Non-alphanumeric characters, lowercase characters or digits followed by
+    /// uppercase ones and the last uppercase character of an acronym followed
+    /// by a lowercase one are treated as word boundaries, so `"LanguageSelector"`,
+    /// `"language_selector"` and `"language-selector"` are converted to the same
+    /// identifier and `"HTMLParser"` is split into `html` and `parser`.
+    pub fn convert(self, input: &str) -> String {
+        match self {
+            Self::Kebab => to_kebab_case(input),
+            // words are alphanumeric, so hyphens are only the separators
+            Self::Snake => to_kebab_case(input).replace('-', "_"),
+            Self::Camel => {
+                let mut output = String::with_capacity(input.len());
+                for (i, word) in to_kebab_case(input).split('-').enumerate() {
+                    let mut chars = word.chars();
+                    if i > 0
+                        && let Some(first) = chars.next()
+                    {
+                        output.extend(first.to_uppercase());
+                    }
+                    output.push_str(chars.as_str());
+                }
+                output
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IdCase;
+
+    #[test]
+    fn kebab() {
+        assert_eq!(
+            IdCase::Kebab.convert("LanguageSelector"),
+            "language-selector"
+        );
+        assert_eq!(
+            IdCase::Kebab.convert("language_selector"),
+            "language-selector"
+        );
+        assert_eq!(
+            IdCase::Kebab.convert("language-selector"),
+            "language-selector"
+        );
+    }
+
+    #[test]
+    fn snake() {
+        assert_eq!(
+            IdCase::Snake.convert("LanguageSelector"),
+            "language_selector"
+        );
+        assert_eq!(
+            IdCase::Snake.convert("languageSelector"),
+            "language_selector"
+        );
+        assert_eq!(
+            IdCase::Snake.convert("language-selector"),
+            "language_selector"
+        );
+    }
+
+    #[test]
+    fn camel() {
+        assert_eq!(
+            IdCase::Camel.convert("LanguageSelector"),
+            "languageSelector"
+        );
+        assert_eq!(
+            IdCase::Camel.convert("language_selector"),
+            "languageSelector"
+        );
+        assert_eq!(
+            IdCase::Camel.convert("language-selector"),
+            "languageSelector"
+        );
+    }
+
+    #[test]
+    fn acronyms() {
+        assert_eq!(IdCase::Kebab.convert("HTMLParser"), "html-parser");
+        assert_eq!(IdCase::Camel.convert("parse-HTML"), "parseHtml");
+    }
+
+    #[test]
+    fn digits() {
+        assert_eq!(IdCase::Kebab.convert("h1Title"), "h1-title");
+        assert_eq!(IdCase::Snake.convert("foo5bar"), "foo5bar");
+    }
+
+    #[test]
+    fn consecutive_separators() {
+        assert_eq!(IdCase::Kebab.convert("-foo__bar-"), "foo-bar");
+    }
+}
@@ -19,8 +19,8 @@
 //! # Usage
 //!
 //! Create a module in your Leptos application to manage unique identifiers. It must
-//! expose an enum with the name `Ids` and the `#[leptos_unique_ids]` attribute macro
-//! applied to it.
+//! expose an enum, usually with the name `Ids`, and the `#[leptos_unique_ids]`
+//! attribute macro applied to it.
 //!
 //! ```rust,ignore
 //! // ids/src/lib.rs
@@ -41,6 +41,7 @@
 //!
 //! let id: &'static str = Ids::LanguageSelector.as_str();
 //! let id: &'static str = Ids::PreviewDownloadSvgButton.into();
+//! let id: &str = Ids::PreviewUploadSvgButton.as_ref();
 //! ```
 //!
 //! By default implements [Leptos' `IntoAttributeValue` trait] for the `Ids` enum,
@@ -141,33 +142,126 @@
 //!
 //! | Rule | Description |
 //! | --- | --- |
+//! | [`cross_enum_duplicate_id`] | Check for identifiers declared in more than one enum generated by `leptos_unique_ids` in the same crate. |
+//! | [`id_reference_literal`] | Check for literals passed to attributes that reference ids, like `for` or `aria-labelledby`. |
+//! | [`id_via_spread`] | Check for attribute spreads in elements, which can set ids that can't be checked. |
+//! | [`ids_used_as_non_id`] | Check for `Ids` enum variants passed as values of attributes that are not id-like, like `class` or `name`. |
+//! | [`imperative_id_assignment`] | Check for ids assigned imperatively to `web_sys::Element`s with values that are not `Ids` enum variants. |
 //! | [`literal_as_id_attribute_value`] | Check for literals passed to id attribute values. |
 //! | [`tt_as_id_attribute_value`] | Check for token trees passed as id attribute values (except for `Ids` enum variants). |
+//! | [`unknown_ids_variant`] | Check for type-qualified paths passed as id attribute values that are not variants of an enum generated by `leptos_unique_ids`. |
+//! | [`unused_ids_enum`] | Check for `Ids` enums whose variants are never used in the crate. |
+//! | [`unused_ids_variant`] | Check for variants of `Ids` enums that are never used in the crate. |
 //!
+//! [`cross_enum_duplicate_id`]: https://github.com/mondeja/leptos-unique-ids/tree/main/lints/cross_enum_duplicate_id#readme
+//! [`id_reference_literal`]: https://github.com/mondeja/leptos-unique-ids/tree/main/lints/id_reference_literal#readme
+//! [`id_via_spread`]: https://github.com/mondeja/leptos-unique-ids/tree/main/lints/id_via_spread#readme
+//! [`ids_used_as_non_id`]: https://github.com/mondeja/leptos-unique-ids/tree/main/lints/ids_used_as_non_id#readme
+//! [`imperative_id_assignment`]: https://github.com/mondeja/leptos-unique-ids/tree/main/lints/imperative_id_assignment#readme
 //! [`literal_as_id_attribute_value`]: https://github.com/mondeja/leptos-unique-ids/tree/main/lints/literal_as_id_attribute_value#readme
 //! [`tt_as_id_attribute_value`]: https://github.com/mondeja/leptos-unique-ids/tree/main/lints/tt_as_id_attribute_value#readme
+//! [`unknown_ids_variant`]: https://github.com/mondeja/leptos-unique-ids/tree/main/lints/unknown_ids_variant#readme
+//! [`unused_ids_enum`]: https://github.com/mondeja/leptos-unique-ids/tree/main/lints/unused_ids_enum#readme
+//! [`unused_ids_variant`]: https://github.com/mondeja/leptos-unique-ids/tree/main/lints/unused_ids_variant#readme
 //!
 //! # Features
 //!
+//! - `as-str` (enabled by default): Makes public the `Ids::as_str` method that
+//!   returns the identifier of a variant. Without it, the method is private and
+//!   only used by the rest of the generated items, like the implementations of
+//!   the `into-str` and `into-attribute-value` features.
 //! - `into-str` (enabled by default): Implements the `Into<&'static str>` trait for
-//!   the `Ids` enum.
+//!   the `Ids` enum, and the `From<Ids>` trait for `String` and `Cow<'static, str>`
+//!   with the `alloc` feature.
+//! - `std` (enabled by default): Generates code for crates that link the standard
+//!   library. Without it, the generated code only uses `core` paths, so it can be
+//!   used in `#![no_std]` crates. Required by the `into-attribute-value`,
+//!   `into-oco`, `runtime-check` and `web-sys` features.
+//! - `alloc`: Enabled by `std`. Generates the conversions to `String` and
+//!   `Cow<'static, str>` of the `into-str` feature in `#![no_std]` crates, which
+//!   must declare `extern crate alloc`.
 //! - `into-attribute-value` (enabled by default): Implements the
 //!   [Leptos' `IntoAttributeValue` trait] in `Ids` enum, allowing to use the
 //!   identifiers as HTML attributes directly. Require inclusion of `leptos`
+//!   dependency in your consumer crate. Also adds an `Ids::as_data_attr` method
+//!   to spread the identifier as value of a custom `data-*` attribute and an
+//!   `Ids::attr` method that returns the `("id", identifier)` attribute pair.
+//! - `into-oco`: Implements the `From<Ids>` trait for Leptos' `Oco<'static, str>`,
+//!   borrowing the identifier, to pass it where Leptos expects attribute or prop
+//!   values without intermediate conversions. Require inclusion of `leptos`
+//!   dependency in your consumer crate. It's not implemented with
+//!   `leptos_version = "0.6"`.
+//! - `from-str`: Implements the `FromStr` and `TryFrom<&str>` traits for the
+//!   `Ids` enum. Identifiers that are not declared return an `UnknownId` error,
+//!   generated next to the enum and shared by all the conversions from strings.
+//! - `phf`: Enables `from-str`, looking up the strings in a perfect hash map
+//!   of the identifiers built while expanding the macro instead of comparing them
+//!   against all the identifiers of the same length, for enums with thousands of
+//!   variants. It doesn't add dependencies to your crate.
+//! - `unicode`: Allows non-ASCII characters in the identifiers, converting them
+//!   to `PascalCase` with Unicode rules to build the enum variants.
+//! - `constants`: Generates an `ids` module next to the `Ids` enum with a
+//!   `&'static str` constant for each identifier, named in `SCREAMING_SNAKE_CASE`
+//!   like `ids::LANGUAGE_SELECTOR`. The module is named after the enum in
+//!   `snake_case`.
+//! - `runtime-check`: Adds an `Ids::assert_unique_in_dom` method that panics when
+//!   more than one element of the DOM share an identifier of the enum, useful
+//!   to catch duplicates added by third-party components in development. It's a
+//!   no-op outside of `wasm32` targets. Require inclusion of `leptos` dependency
+//!   in your consumer crate and the `NodeList` feature of `web-sys`.
+//! - `web-sys`: Adds an `Ids::get_element` method that returns the element of the
+//!   current document with the identifier. It's only available in `wasm32`
+//!   targets and returns `None` when there is no document, like outside of the
+//!   browser main thread. Require inclusion of `web-sys` dependency with the
+//!   `Window`, `Document` and `Element` features in your consumer crate.
+//! - `serde_json`: Adds an `Ids::as_json_value` method that returns the identifier
+//!   as a `serde_json::Value::String`. Require inclusion of `serde_json`
 //!   dependency in your consumer crate.
+//! - `debug`: Implements `Debug` for the `Ids` enum, formatting the variants with
+//!   their identifiers like `LanguageSelector("language-selector")`. Deriving
+//!   `Debug` for the enum is an error with this feature.
+//! - `testid`: Adds an `Ids::attrs_with_testid` method that returns the
+//!   `[("id", identifier), ("data-testid", identifier)]` attribute pairs, to tag
+//!   elements for test automation with the same value of their id.
+//! - `borrow-str`: Implements `Borrow<str>` and `Hash` for the `Ids` enum,
+//!   hashing the identifier instead of the discriminant, so a `HashMap<Ids, _>`
+//!   can be looked up by `&str`, like `map.get("language-selector")`. The enum
+//!   must derive `PartialEq` and `Eq`, and deriving `Hash` is an error with this
+//!   feature.
+//! - `deref`: Implements `Deref<Target = str>` for the `Ids` enum, so `str`
+//!   methods can be called on the variants, like `Ids::Preview.starts_with("pre")`,
+//!   and they can be sliced like `&Ids::Preview[..3]`. Methods of the enum and
+//!   its traits take precedence over the `str` ones with the same name, like
+//!   `Ids::contains`, which checks if a string is an identifier instead of
+//!   searching a pattern, so call them on `as_str()` when in doubt. Functions
+//!   expecting `&str` also accept `&Ids` through deref coercion, which can hide
+//!   conversions that are better written explicitly.
+//! - `selector`: Adds an `Ids::as_selector` method that returns an `IdSelector`
+//!   displayed as the id selector of the identifier, like `#language-selector`,
+//!   to format selectors without allocating them. It holds the identifier, so
+//!   it's `Copy` even if the enum isn't.
 //!
 //! [Leptos]: https://leptos.dev
 //! [Dylint]: https://github.com/trailofbits/dylint
 //! [Leptos' `IntoAttributeValue` trait]: https://docs.rs/leptos/latest/leptos/attr/trait.IntoAttributeValue.html
 
+mod id_case;
 mod pascal_case;
+#[cfg(feature = "from-str")]
+mod phf;
+mod screaming_snake_case;
+
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet, hash_map::Entry},
+};
 
 use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
 
 /// Generate the implementation for a unique ids enum.
 ///
-/// The enum must have the name `Ids` and be annotated with the `#[leptos_unique_ids]` attribute.
-/// Pass all the identifiers as string literals in the attribute, separated by commas.
+/// The enum must be annotated with the `#[leptos_unique_ids]` attribute. Pass all the
+/// identifiers as string literals in the attribute, separated by commas.
 ///
 /// ## Example
 ///
@@ -185,355 +279,7880 @@ use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenSt
 /// ```
 ///
 /// The identifiers will be converted to `PascalCase` and used as enum variants.
-#[proc_macro_attribute]
-pub fn leptos_unique_ids(attr: TokenStream, item: TokenStream) -> TokenStream {
-    let item_clone = item.clone();
-    let output_item_iter = item.clone().into_iter();
-
-    let mut vis = None;
-
-    let mut enum_tokens_iter = output_item_iter.skip_while(|token| {
-        if let TokenTree::Ident(ident) = token {
-            let ident_str = ident.to_string();
-            if ident_str == "enum" {
-                return false;
-            } else if ident_str == "pub" {
-                vis = Some(TokenStream::from(TokenTree::Ident(ident.clone())));
-                return true; // skip searching for a possible next full pub(...) visibility
-            }
-        } else if let TokenTree::Group(group) = token
-            && group.delimiter() == Delimiter::Parenthesis
-        {
-            let mut new_vis = vis.clone().unwrap_or_default();
-            new_vis.extend([TokenTree::Group(group.clone())]);
-            vis = Some(new_vis);
-            return false;
+///
+/// To compose attribute strings manually without intermediate allocations, the
+/// `Ids::write_to` method appends the identifier to any `std::fmt::Write` buffer.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("language-selector", "preview")]
+/// pub enum Ids {}
+///
+/// let mut labelledby = String::new();
+/// Ids::LanguageSelector.write_to(&mut labelledby).unwrap();
+/// labelledby.push(' ');
+/// Ids::Preview.write_to(&mut labelledby).unwrap();
+/// assert_eq!(labelledby, "language-selector preview");
+/// ```
+///
+/// The `Ids::matches_selector` method checks if a selector string refers to the
+/// identifier, written as `#{id}` or just `{id}`.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("language-selector")]
+/// pub enum Ids {}
+///
+/// assert!(Ids::LanguageSelector.matches_selector("#language-selector"));
+/// assert!(Ids::LanguageSelector.matches_selector("language-selector"));
+/// assert!(!Ids::LanguageSelector.matches_selector(".language-selector"));
+/// ```
+///
+/// ## Variant names
+///
+/// Characters that are not letters or digits, like `-`, `_` or `.`, are word
+/// boundaries and are removed. The first letter of each word is uppercased and
+/// the rest are kept as is, so `"foo-bar"` and `"foo_bar"` build `FooBar` and
+/// `"fooBar"` builds `FooBar`. A digit ends the current word, so the letter that
+/// follows it is uppercased, like `"h1title"` building `H1Title`.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("foo-bar", "baz_qux", "h1title")]
+/// pub enum Ids {}
+///
+/// assert_eq!(Ids::FooBar.as_str(), "foo-bar");
+/// assert_eq!(Ids::BazQux.as_str(), "baz_qux");
+/// assert_eq!(Ids::H1Title.as_str(), "h1title");
+/// ```
+///
+/// Identifiers that only differ by case or separators, like `"user-name"` and
+/// `"username"`, are likely typos, so the second one emits a `deprecated`
+/// warning. Allow the lint in the module of the enum to keep both on purpose.
+/// Identifiers building the same variant, like `"user-name"` and `"user_name"`,
+/// emit it next to the error of the duplicated variant.
+///
+/// Variants are located at their string literals, so diagnostics about them and
+/// go to definition in rust-analyzer point to the literal instead of the whole
+/// attribute.
+///
+/// The conversion can't be exported as a function because procedural macro
+/// crates only export macros. Build scripts can read the identifiers written by
+/// the [`manifest`](#manifest) argument instead of computing the names again.
+///
+/// ## Enum name
+///
+/// The enum is usually named `Ids`, but any name is allowed to define separate
+/// namespaces of identifiers in the same crate. The items generated next to the
+/// enum are named after it, like the `UnknownFormId` error of the `from-str`
+/// feature and the `form_ids` module of the `constants` feature for `FormIds`.
+///
+/// The types are named after the singular of the enum name, built removing its
+/// trailing `s`, like `UnknownFormIdentifier` for `FormIdentifiers`. Names that
+/// don't end in `s`, like `Id`, are rejected, as their types would collide with
+/// the ones of `Ids`.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("login-form", "login-button")]
+/// pub enum FormIds {}
+///
+/// #[leptos_unique_ids("main-nav")]
+/// pub enum NavIds {}
+///
+/// # assert_eq!(FormIds::LoginForm.as_str(), "login-form");
+/// # assert_eq!(NavIds::MainNav.as_str(), "main-nav");
+/// ```
+///
+/// ## Struct form
+///
+/// Apply the macro to a unit struct instead of an enum to generate an associated
+/// `&'static str` constant for each identifier, named in `SCREAMING_SNAKE_CASE`
+/// like `Ids::LANGUAGE_SELECTOR`. The identifiers are validated and checked for
+/// duplicates like in the enum form, but the methods, trait implementations and
+/// the rest of the items of the enum are not generated, so the arguments that
+/// only affect them, like `default` or `ord`, are rejected.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("language-selector", "preview")]
+/// pub struct Ids;
+///
+/// assert_eq!(Ids::LANGUAGE_SELECTOR, "language-selector");
+/// assert_eq!(Ids::PREVIEW, "preview");
+/// ```
+///
+/// ## Groups
+///
+/// Identifiers can be grouped with `group "name" { ... }` to organize them by
+/// section of the application. They're still variants of the same enum, so the
+/// uniqueness checks span all the groups, and a module named after each group
+/// in `snake_case` re-exports its variants, like `nav::Logo` for `Ids::Logo`.
+///
+/// Add the `prefixed` flag after the name of the group to prepend it to the
+/// identifiers of the group, separated by a hyphen. The variants of the enum
+/// include the prefix, but the re-exports of the module don't.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids(
+///     "preview",
+///     group "nav" { "language-selector", "logo" },
+///     group "footer" prefixed { "logo" },
+/// )]
+/// pub enum Ids {}
+///
+/// # fn main() {
+/// assert_eq!(nav::Logo.as_str(), "logo");
+/// assert_eq!(footer::Logo.as_str(), "footer-logo");
+/// assert!(matches!(footer::Logo, Ids::FooterLogo));
+/// # }
+/// ```
+///
+/// The modules are emitted next to the enum and reference it through `super`, so
+/// the enum must be defined at module level, not inside a function. The lints
+/// only recognize paths of the enum, like `Ids::Logo`, in `view!` macros.
+///
+/// ## Acronyms
+///
+/// Pass an `acronyms` argument with a list of words that must be uppercased
+/// instead of capitalized when building the variant names. It doesn't affect the
+/// identifiers themselves.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids(
+///     "html-parser",
+///     "svg-url",
+///     acronyms = ["html", "svg", "url"],
+/// )]
+/// pub enum Ids {}
+///
+/// # assert_eq!(Ids::HTMLParser.as_str(), "html-parser");
+/// # assert_eq!(Ids::SVGURL.as_str(), "svg-url");
+/// ```
+///
+/// ## Order
+///
+/// All the variants are available in the `Ids::ALL` constant (also returned by
+/// `Ids::all()`) and paired with their identifiers in the `Ids::PAIRS` constant.
+/// By default they follow the declaration order, but passing `order = "sorted"`
+/// sorts them by identifier. The order of the enum variants, and so their
+/// discriminants, is not affected.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("preview", "language-selector", order = "sorted")]
+/// pub enum Ids {}
+///
+/// # assert_eq!(Ids::ALL[0].as_str(), "language-selector");
+/// # assert_eq!(Ids::PAIRS[1].0, "preview");
+/// # assert_eq!(Ids::Preview as usize, 0);
+/// ```
+///
+/// The `Ids::ALL_SELECTORS` constant joins the identifiers as id selectors in
+/// the same order, separated by commas, to select all the elements with a single
+/// `query_selector_all` call. Characters like `.` or `:` are escaped, and it's
+/// empty for enums without identifiers.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("preview", "language-selector")]
+/// pub enum Ids {}
+///
+/// assert_eq!(Ids::ALL_SELECTORS, "#preview,#language-selector");
+/// ```
+///
+/// Pass the `require_sorted` flag to require that the string literals are written
+/// in ascending lexical order, so lists kept alphabetized don't get identifiers
+/// inserted in the wrong place. The first literal out of order is reported.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("language-selector", "preview", require_sorted)]
+/// pub enum Ids {}
+/// ```
+///
+/// Regardless of the order, the `Ids::SORTED_NAMES` constant pairs the variants
+/// with their identifiers sorted at compile time, which allows `Ids::from_str_sorted`
+/// to find a variant by its identifier using a binary search.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("preview", "language-selector")]
+/// pub enum Ids {}
+///
+/// # assert_eq!(Ids::SORTED_NAMES[0].0, "language-selector");
+/// assert!(matches!(Ids::from_str_sorted("preview"), Some(Ids::Preview)));
+/// assert!(Ids::from_str_sorted("unknown").is_none());
+/// ```
+///
+/// To only check if a string is one of the identifiers, use `Ids::contains`.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("preview", "language-selector")]
+/// pub enum Ids {}
+///
+/// assert!(Ids::contains("preview"));
+/// assert!(!Ids::contains("unknown"));
+/// ```
+///
+/// The number of identifiers is returned by `Ids::variants_count`, which is
+/// zero when the macro is invoked without string literals.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("preview", "language-selector")]
+/// pub enum Ids {}
+///
+/// assert_eq!(Ids::variants_count(), 2);
+/// ```
+///
+/// The bytes of an identifier are returned by the `Ids::as_bytes` const
+/// method, which is the same as `Ids::as_str().as_bytes()` but usable in const
+/// contexts, for low-level writers and hashing.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("preview", "language-selector")]
+/// pub enum Ids {}
+///
+/// const PREVIEW: &[u8] = Ids::Preview.as_bytes();
+/// assert_eq!(PREVIEW, b"preview");
+/// ```
+///
+/// The identifiers are also returned by the `Ids::as_array` const function as
+/// an array of that length, in the order of `Ids::ALL`, to build fixed-size
+/// lookup tables in const contexts.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("preview", "language-selector")]
+/// pub enum Ids {}
+///
+/// const IDS: [&str; 2] = Ids::as_array();
+/// assert_eq!(IDS, ["preview", "language-selector"]);
+/// ```
+///
+/// The `Ids::index` method returns the position of a variant in `Ids::ALL` and
+/// `Ids::from_index` returns the variant at a position, which is useful to cycle
+/// through the identifiers, for example to move the focus with the keyboard.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("preview", "language-selector")]
+/// pub enum Ids {}
+///
+/// let next = Ids::from_index((Ids::Preview.index() + 1) % Ids::ALL.len());
+/// assert!(matches!(next, Some(Ids::LanguageSelector)));
+/// assert!(Ids::from_index(Ids::ALL.len()).is_none());
+/// ```
+///
+/// `Ids` also implements `TryFrom<usize>` with `Ids::from_index`, returning an
+/// `IdIndexOutOfRange` error with the position when it's out of range. The
+/// error is named after the singular of the enum name, like `UnknownId`.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("preview", "language-selector")]
+/// pub enum Ids {}
+///
+/// assert!(matches!(Ids::try_from(1), Ok(Ids::LanguageSelector)));
+/// assert_eq!(Ids::try_from(2).err(), Some(IdIndexOutOfRange(2)));
+/// ```
+///
+/// The `Ids::iter` method returns an iterator over the variants in the order
+/// of `Ids::ALL`, which is empty when the macro is invoked without string
+/// literals.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("preview", "language-selector")]
+/// pub enum Ids {}
+///
+/// let ids = Ids::iter().map(|id| id.as_str()).collect::<Vec<_>>();
+/// assert_eq!(ids, ["preview", "language-selector"]);
+/// ```
+///
+/// The `Ids::next` and `Ids::prev` methods return the following and previous
+/// variants in declaration order, wrapping around at the ends, so the next
+/// variant of the last one is the first one. An enum with a single variant
+/// returns itself.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("preview", "language-selector")]
+/// pub enum Ids {}
+///
+/// assert!(matches!(Ids::Preview.next(), Ids::LanguageSelector));
+/// assert!(matches!(Ids::LanguageSelector.next(), Ids::Preview));
+/// assert!(matches!(Ids::Preview.prev(), Ids::LanguageSelector));
+/// ```
+///
+/// Uniqueness is checked while expanding the macro, but the `Ids::assert_all_unique`
+/// method checks it again at runtime, panicking if two variants return the same
+/// identifier, to guard in unit tests against refactors that bypass the macro.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("preview", "language-selector")]
+/// pub enum Ids {}
+///
+/// Ids::assert_all_unique();
+/// ```
+///
+/// ## Unused identifiers
+///
+/// The [`unused_ids_variant`] lint reports the variants that are never used in
+/// the crate. Pass the identifiers that are used from other crates in the
+/// `allow_unused` argument to allow the lint for their variants.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids(
+///     "language-selector",
+///     "shared-footer",
+///     allow_unused = ["shared-footer"],
+/// )]
+/// pub enum Ids {}
+/// ```
+///
+/// [`unused_ids_variant`]: https://github.com/mondeja/leptos-unique-ids/tree/main/lints/unused_ids_variant#readme
+///
+/// ## Renaming
+///
+/// Pass `rename("old-id" => "new-id")` when renaming an identifier to keep
+/// the old variant name compiling during a migration. It's emitted as a
+/// deprecated associated constant aliasing the variant of the new identifier,
+/// so the old call sites return the new identifier with a deprecation warning.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("settings-panel", rename("preferences-panel" => "settings-panel"))]
+/// pub enum Ids {}
+///
+/// #[allow(deprecated)]
+/// let id = Ids::PreferencesPanel.as_str();
+/// assert_eq!(id, "settings-panel");
+/// ```
+///
+/// ## Non exhaustive
+///
+/// Pass the `non_exhaustive` flag to mark the enum as `#[non_exhaustive]`, so
+/// crates that depend on the crate that defines it must handle identifiers added
+/// in the future when matching its variants.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("language-selector", non_exhaustive)]
+/// pub enum Ids {}
+/// ```
+///
+/// ## Default
+///
+/// Pass the `default` flag to implement `Default` for the enum, returning the
+/// first declared variant.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("preview", "language-selector", default)]
+/// pub enum Ids {}
+///
+/// assert!(matches!(Ids::default(), Ids::Preview));
+/// ```
+///
+/// ## Ordering
+///
+/// Pass the `ord` flag to implement `PartialOrd` and `Ord` for the enum, so
+/// identifiers can be sorted or used as keys of a `BTreeMap`. Variants are
+/// compared by their position in `Ids::ALL`, as returned by `Ids::index`, which
+/// is the declaration order, or the order of the identifiers with `order =
+/// "sorted"`. It's not affected by the `prefix` or `id_case` arguments. The enum
+/// must derive `PartialEq` and `Eq`, which are consistent with this order.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("preview", "language-selector", ord)]
+/// #[derive(PartialEq, Eq)]
+/// pub enum Ids {}
+///
+/// assert!(Ids::Preview < Ids::LanguageSelector);
+/// ```
+///
+/// ## Comparisons
+///
+/// The variants can be compared with string slices on either side, like
+/// `Ids::Preview == "preview"` or `"preview" == Ids::Preview`, comparing their
+/// identifiers. `PartialEq` is implemented between the enum and `str`, `&str`
+/// and `&&str` in both directions, so references to variants can be compared
+/// too, like `&Ids::Preview == "preview"`. Compare `String`s with
+/// `Ids::as_str`, like `Ids::Preview.as_str() == string`.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("preview", "language-selector")]
+/// pub enum Ids {}
+///
+/// assert!(Ids::Preview == "preview");
+/// assert!("language-selector" != Ids::Preview);
+/// assert!(&Ids::Preview == "preview");
+/// ```
+///
+/// ## Representation
+///
+/// Pass `repr = "u16"` to emit the enum as `#[repr(u16)]` with explicit
+/// discriminants in declaration order, so identifiers can be stored compactly
+/// with `Ids::as_u16` and converted back with `Ids::from_u16` or the
+/// `TryFrom<u16>` implementation.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("preview", "language-selector", repr = "u16")]
+/// pub enum Ids {}
+///
+/// assert_eq!(Ids::LanguageSelector.as_u16(), 1);
+/// assert!(matches!(Ids::from_u16(1), Some(Ids::LanguageSelector)));
+/// assert!(Ids::from_u16(2).is_none());
+/// assert!(matches!(Ids::try_from(1_u16), Ok(Ids::LanguageSelector)));
+/// ```
+///
+/// ## Attributes
+///
+/// The attributes written below the macro, like documentation and derives, are
+/// preserved in the generated enum. Derives must be placed below the macro, as
+/// the compiler expands the ones written above with the enum without variants.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// /// Identifiers of the application.
+/// #[leptos_unique_ids("language-selector")]
+/// #[derive(Clone, PartialEq)]
+/// pub enum Ids {}
+///
+/// assert!(Ids::LanguageSelector.clone() == Ids::LanguageSelector);
+/// ```
+///
+/// ## Documentation
+///
+/// Each variant is documented with its identifier. Pass a string literal with
+/// a `doc` value in parentheses to add documentation to the variant.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids(
+///     ("language-selector", doc = "Dropdown of the top bar to switch the language."),
+///     "preview",
+/// )]
+/// pub enum Ids {}
+/// ```
+///
+/// ## Conditional compilation
+///
+/// Write a `#[cfg(...)]` attribute before a string literal to only generate its
+/// variant when the predicate holds, like for identifiers of optional features.
+/// The attribute is emitted on the variant and on every item generated for the
+/// identifier, like the arms of `Ids::as_str` or the elements of `Ids::ALL`.
+/// Positions returned by `Ids::index` and `Ids::variants_count` only count the
+/// compiled variants, while the discriminants of `repr = "u16"` don't change
+/// between configurations. The string literals are checked for duplicates and
+/// written to the manifest in all configurations.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids(
+///     "language-selector",
+///     #[cfg(feature = "admin")]
+///     "admin-panel",
+///     "preview",
+/// )]
+/// pub enum Ids {}
+///
+/// assert_eq!(Ids::Preview.index(), Ids::variants_count() - 1);
+/// ```
+///
+/// The first string literal can't be conditionally compiled with the `default`
+/// flag, which returns its variant.
+///
+/// ## Identifiers case
+///
+/// Pass `id_case = "kebab"`, `id_case = "snake"` or `id_case = "camel"` to
+/// convert the string literals to that case for the emitted identifiers. The
+/// names of the variants are built from the converted identifiers.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("LanguageSelector", "preview_panel", id_case = "kebab")]
+/// pub enum Ids {}
+///
+/// assert_eq!(Ids::LanguageSelector.as_str(), "language-selector");
+/// assert_eq!(Ids::PreviewPanel.as_str(), "preview-panel");
+/// ```
+///
+/// ## Prefix
+///
+/// Pass `prefix = "app-"` to prepend a prefix to the emitted identifiers, like
+/// to avoid collisions with the identifiers of other applications in the same
+/// page. The names of the variants are built from the identifiers without the
+/// prefix, which are returned by `Ids::local_name`.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("language-selector", prefix = "app-")]
+/// pub enum Ids {}
+///
+/// assert_eq!(Ids::LanguageSelector.as_str(), "app-language-selector");
+/// assert_eq!(Ids::LanguageSelector.local_name(), "language-selector");
+/// ```
+///
+/// The identifiers without the prefix are added as `#[doc(alias)]` to the variants,
+/// so they can be searched in the documentation, and the `cross_enum_duplicate_id`
+/// lint can find the ones declared with different prefixes in other enums.
+///
+/// ## Hashed in release
+///
+/// Pass the `hashed_in_release` flag to emit short hashes of the identifiers in
+/// release builds, where `debug_assertions` are disabled, to obfuscate them and
+/// reduce the size of the payloads. The hashes are computed while expanding the
+/// macro, so `Ids::as_str` still returns a `&'static str`, and are stable across
+/// builds. Debug builds keep the readable identifiers.
+///
+/// The rest of the items that contain identifiers, like `Ids::contains`, the
+/// `FromStr` implementation or the `SORTED_NAMES` constant, match the ones emitted
+/// in the active profile. The documentation of the variants, `Ids::local_name`
+/// and the manifest keep the readable identifiers.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("language-selector", hashed_in_release)]
+/// pub enum Ids {}
+///
+/// if cfg!(debug_assertions) {
+///     assert_eq!(Ids::LanguageSelector.as_str(), "language-selector");
+/// } else {
+///     assert_eq!(Ids::LanguageSelector.as_str(), "hwrd9k7");
+/// }
+/// ```
+///
+/// ## Manifest
+///
+/// Pass `manifest = "ids.json"` to write the identifiers as a JSON array of
+/// strings while expanding the macro, so tools outside Rust like end-to-end
+/// tests can read them. Relative paths are resolved from the `OUT_DIR` of the
+/// crate when it has a build script, or from its manifest directory otherwise.
+///
+/// The file is overwritten each time the macro is expanded, which can happen
+/// more than once per build, for example when running `cargo check` and `cargo
+/// build`, so don't edit it by hand.
+///
+/// ```rust,ignore
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("preview", "language-selector", manifest = "ids.json")]
+/// pub enum Ids {}
+/// ```
+///
+/// ## Environment variable
+///
+/// Pass `env = "APP_IDS"` to read a comma-separated list of identifiers from an
+/// environment variable while expanding the macro, like the ones generated from
+/// a central source in monorepos. The entries are trimmed, validated and checked
+/// for duplicates along with the string literals of the attribute, and an unset
+/// variable is an error.
+///
+/// Procedural macros can't declare the environment variables they read, so
+/// Cargo doesn't rebuild the crate when the variable changes, unless it's set
+/// in the `[env]` table of the Cargo configuration or a build script emits
+/// `cargo::rerun-if-env-changed=APP_IDS`.
+///
+/// ```rust,ignore
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// // APP_IDS="language-selector,preview"
+/// #[leptos_unique_ids("footer", env = "APP_IDS")]
+/// pub enum Ids {}
+/// ```
+///
+/// ## Files
+///
+/// Pass `files = ["core.txt", "admin.txt"]` to read identifiers from files with
+/// one identifier per line, so big applications can split them across modular
+/// sources while keeping a single enum. Relative paths are resolved from the
+/// directory of the file that invokes the macro, like `include_str!`. Lines are
+/// trimmed and empty ones skipped.
+///
+/// The identifiers of all the files are checked for duplicates along with the
+/// string literals of the attribute, reporting the file and line of both
+/// declarations. The files are included in the expansion, so Cargo rebuilds the
+/// crate when they change.
+///
+/// ```rust,ignore
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// // ids/core.txt: "language-selector\npreview\n"
+/// // ids/admin.txt: "users-table\n"
+/// #[leptos_unique_ids("footer", files = ["ids/core.txt", "ids/admin.txt"])]
+/// pub enum Ids {}
+/// ```
+///
+/// ## Strict HTML
+///
+/// Identifiers can't contain whitespace because they would break the CSS
+/// selectors that reference them. Pass the `strict_html` flag to also require
+/// that they start with a letter and only contain letters, digits, `-`, `_`,
+/// `:` and `.`, like valid ids of HTML 4.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("language-selector", "nav.main", strict_html)]
+/// pub enum Ids {}
+/// ```
+///
+/// Valid HTML ids like `nav.main` or `1st-item` must be escaped in CSS selectors.
+/// Pass the `strict_css` flag to require that identifiers are valid CSS
+/// identifiers, starting with a letter, `_` or `-` not followed by a digit, and
+/// only containing letters, digits, `-` and `_`. Non-ASCII characters are also
+/// allowed with the `unicode` feature.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("language-selector", "_private", strict_css)]
+/// pub enum Ids {}
+/// ```
+///
+/// ## Reserved identifiers
+///
+/// Pass `reserved = ["root", "app"]` to forbid identifiers used elsewhere in the
+/// page, like the mount points of the application or ids set by third-party
+/// scripts. The identifiers are compared after applying the `prefix` and
+/// `id_case` arguments, so the error points to the string literal that emits a
+/// reserved identifier.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("language-selector", "app-header", reserved = ["root", "app"])]
+/// pub enum Ids {}
+/// ```
+///
+/// ## Leptos version
+///
+/// The `into-attribute-value` feature implements the `IntoAttributeValue` trait
+/// of Leptos 0.7 and later. Pass `leptos_version = "0.6"` to implement the
+/// `IntoAttribute` trait of Leptos 0.6 instead. The `Ids::as_data_attr` method
+/// is not generated in that case, because custom attributes were added in Leptos
+/// 0.7.
+///
+/// ```rust,ignore
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("language-selector", leptos_version = "0.6")]
+/// pub enum Ids {}
+/// ```
+///
+/// ## Clippy
+///
+/// Variants built from identifiers often share prefixes and suffixes, like
+/// `PreviewDownloadSvgButton` and `PreviewUploadSvgButton`, so the enum is
+/// emitted with `#[allow(clippy::enum_variant_names)]`. Pass
+/// `allow_enum_variant_names = false` to don't emit it.
+///
+/// The accessors that only read the variant, like `Ids::as_str`, `Ids::local_name`
+/// or `Ids::index`, are emitted with `#[must_use]`, so discarding their result
+/// warns with the `unused_must_use` lint.
+///
+/// ```rust
+/// use leptos_unique_ids::leptos_unique_ids;
+///
+/// #[leptos_unique_ids("preview-button", "upload-button", allow_enum_variant_names = false)]
+/// pub enum Ids {}
+/// ```
+#[proc_macro_attribute]
+pub fn leptos_unique_ids(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item_clone = item.clone();
+    // capture the visibility as a unit, like `pub`, `pub(crate)`, `pub(super)`
+    // or `pub(in path)`, to apply it to the generated items
+    let mut vis: Option<TokenStream> = None;
+    // unit structs get a constant for each identifier instead of the enum
+    let mut is_struct = false;
+    let mut enum_tokens_iter = item.clone().into_iter();
+    for token in enum_tokens_iter.by_ref() {
+        match token {
+            TokenTree::Ident(ident) if ident.to_string() == "enum" => break,
+            TokenTree::Ident(ident) if ident.to_string() == "struct" => {
+                is_struct = true;
+                break;
+            }
+            TokenTree::Ident(ident) if ident.to_string() == "pub" => {
+                vis = Some(TokenStream::from(TokenTree::Ident(ident)));
+            }
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis => {
+                if let Some(vis) = &mut vis {
+                    vis.extend([TokenTree::Group(group)]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // the `debug` feature implements `Debug`, so it can't be derived too
+    #[cfg(feature = "debug")]
+    if !is_struct && let Some(span) = derived_trait_span(&item, "Debug") {
+        return error_with_help(
+            "`Debug` is implemented for the enum by the `debug` feature of `leptos-unique-ids`.",
+            "remove `Debug` from the derives of the enum",
+            span,
+        );
+    }
+
+    // the `borrow-str` feature implements `Hash` consistently with `Borrow<str>`,
+    // so it can't be derived too
+    #[cfg(feature = "borrow-str")]
+    if !is_struct && let Some(span) = derived_trait_span(&item, "Hash") {
+        return error_with_help(
+            "`Hash` is implemented for the enum by the `borrow-str` feature of `leptos-unique-ids`.",
+            "remove `Hash` from the derives of the enum",
+            span,
+        );
+    }
+
+    // the enum can have any name, but not generics
+    let (enum_name, enum_name_span) = match (
+        enum_tokens_iter.next(),
+        enum_tokens_iter.next(),
+        enum_tokens_iter.next(),
+    ) {
+        (Some(TokenTree::Ident(name)), Some(TokenTree::Group(group)), None)
+            if !is_struct && group.delimiter() == Delimiter::Brace =>
+        {
+            (name.to_string(), name.span())
+        }
+        (Some(TokenTree::Ident(name)), Some(TokenTree::Punct(punct)), None)
+            if is_struct && punct.as_char() == ';' =>
+        {
+            (name.to_string(), name.span())
+        }
+        _ if is_struct => {
+            let span = item_clone
+                .into_iter()
+                .find(|token| matches!(token, TokenTree::Ident(ident) if ident.to_string() == "struct"))
+                .map_or_else(Span::call_site, |token| token.span());
+            return error(
+                "Expected a unit struct without generics, like `struct Ids;`.",
+                span,
+            );
+        }
+        _ => {
+            let mut enum_tokens_iter = item_clone.into_iter().skip_while(|token| {
+                !matches!(token, proc_macro::TokenTree::Ident(ident) if ident.to_string() == "enum")
+            });
+            let first_token = enum_tokens_iter
+                .next()
+                .expect("Expected at least one token in the enum declaration");
+            let span = first_token.span();
+
+            return error(
+                "Expected an empty enum without generics, like `enum Ids {}`.",
+                span,
+            );
+        }
+    };
+
+    // the types generated next to the enum are named after its singular, which
+    // is only unique for names ending in `s`, as `Id` would generate the types
+    // of `Ids`
+    if !is_struct && singular_enum_name(&enum_name).is_none() {
+        return error_with_help(
+            "Expected an enum name ending in `s`, like `Ids` or `FormIds`.",
+            "the types generated next to the enum are named after its singular, like `UnknownId` for `Ids`",
+            enum_name_span,
+        );
+    }
+
+    let call_site_span = Span::call_site();
+
+    let mut ids: Vec<String> = Vec::new();
+    let mut ids_spans: Vec<Span> = Vec::new();
+    let mut ids_literals: Vec<String> = Vec::new();
+    let mut ids_docs: Vec<Option<String>> = Vec::new();
+    // predicates of the `#[cfg(...)]` attributes written before the string literals
+    let mut ids_cfgs: Vec<Option<TokenStream>> = Vec::new();
+    let mut acronyms: Vec<String> = Vec::new();
+    let mut reserved: Vec<String> = Vec::new();
+    let mut sorted = false;
+    let mut require_sorted = false;
+    let mut non_exhaustive = false;
+    let mut strict_html = false;
+    let mut strict_css = false;
+    let mut allow_enum_variant_names = true;
+    let mut hashed_in_release = false;
+    let mut leptos_06 = false;
+    let mut repr_u16 = false;
+    let mut default = false;
+    let mut default_span = call_site_span;
+    let mut ord = false;
+    let mut manifest: Option<(String, Span)> = None;
+    let mut prefix = String::new();
+    let mut id_case: Option<id_case::IdCase> = None;
+    let mut repr_span = call_site_span;
+    let mut allow_unused: Vec<String> = Vec::new();
+    let mut allow_unused_span = call_site_span;
+    // name of each group, the name of the module generated for it and whether
+    // its identifiers are prefixed with the name
+    let mut groups: Vec<(String, String, bool)> = Vec::new();
+    let mut ids_groups: Vec<Option<usize>> = Vec::new();
+    // old and new identifiers of the `rename` arguments
+    let mut renames: Vec<(String, String, Span)> = Vec::new();
+    // position, file and line of the identifiers read from the `files` argument
+    let mut ids_files_lines: Vec<(usize, String, usize)> = Vec::new();
+    // files read from the `files` argument, included in the expansion
+    let mut files_paths: Vec<std::path::PathBuf> = Vec::new();
+
+    // after a string literal or an argument value, only a comma or the
+    // end of the attribute are allowed
+    let mut expect_comma = false;
+    // predicate of a `#[cfg(...)]` attribute waiting for its string literal
+    let mut pending_cfg: Option<(TokenStream, Span)> = None;
+
+    let mut attr_iter = attr.into_iter();
+    // tokens between the braces of the group being parsed and its index in `groups`
+    let mut group_iter: Option<(usize, proc_macro::token_stream::IntoIter)> = None;
+    loop {
+        let token = match group_iter.as_mut().map(|(_, iter)| iter.next()) {
+            Some(Some(token)) => token,
+            Some(None) => {
+                if let Some((_, span)) = pending_cfg {
+                    return error(
+                        "Expected a string literal after the `#[cfg(...)]` attribute.",
+                        span,
+                    );
+                }
+                group_iter = None;
+                expect_comma = true;
+                continue;
+            }
+            None => match attr_iter.next() {
+                Some(token) => token,
+                None => break,
+            },
+        };
+
+        if let Some((_, span)) = &pending_cfg
+            && id_literal(&token).is_none()
+        {
+            return error(
+                "Expected a string literal after the `#[cfg(...)]` attribute.",
+                *span,
+            );
+        }
+
+        if expect_comma && !matches!(&token, TokenTree::Punct(_)) {
+            let span = token.span();
+            return error(
+                "Expected a comma between string literals in the attribute.",
+                span,
+            );
+        }
+
+        if let Some(maybe_id) = id_literal(&token) {
+            let (literal, doc) = match maybe_id {
+                Ok(literal_and_doc) => literal_and_doc,
+                Err(err) => return err,
+            };
+            let literal_str = literal.to_string();
+            let maybe_value = value_from_literal_str(&literal_str);
+            if let Err(err) = maybe_value {
+                let span = literal.span();
+                return error(err, span);
+            }
+            let mut value = maybe_value.unwrap().to_string();
+
+            let group = group_iter.as_ref().map(|(i, _)| *i);
+            if let Some(i) = group
+                && groups[i].2
+                && !value.is_empty()
+            {
+                value = format!("{}-{value}", groups[i].0);
+            }
+
+            if value.is_empty() {
+                let span = literal.span();
+                return error_with_help(
+                    "String literals in the attribute cannot be empty.",
+                    "remove the empty string literal from the attribute",
+                    span,
+                );
+            }
+
+            if value.chars().any(|char| char.is_ascii_whitespace()) {
+                let span = literal.span();
+                return error("Identifiers cannot contain whitespace.", span);
+            }
+
+            if let Some(i) = ids.iter().position(|id| *id == value) {
+                let span = literal.span();
+                if let Some((_, file, line)) = ids_files_lines.iter().find(|(j, ..)| *j == i) {
+                    return error_with_help(
+                        "Duplicated string literal found.",
+                        &format!("it's also declared in {file:?} at line {line}"),
+                        span,
+                    );
+                }
+                return error("Duplicated string literal found.", span);
+            }
+
+            ids.push(value);
+            ids_spans.push(literal.span());
+            ids_literals.push(literal_str);
+            ids_docs.push(doc);
+            ids_cfgs.push(pending_cfg.take().map(|(predicate, _)| predicate));
+            ids_groups.push(group);
+            expect_comma = true;
+        } else if let TokenTree::Ident(ident) = token {
+            let span = ident.span();
+            if group_iter.is_some() {
+                return error("Expected only string literals and commas in groups.", span);
+            }
+
+            // group "nav" { "language-selector", "logo" }
+            if ident.to_string() == "group" {
+                let maybe_group_name = string_literal_value(attr_iter.next(), span);
+                if let Err(err) = maybe_group_name {
+                    return err;
+                }
+                let group_name = maybe_group_name.unwrap();
+                let module_name =
+                    screaming_snake_case::to_screaming_snake_case(&group_name).to_lowercase();
+                if module_name.is_empty()
+                    || module_name.starts_with(|char: char| char.is_ascii_digit())
+                {
+                    return error(
+                        "Group names must start with a letter to build the name of their module.",
+                        span,
+                    );
+                }
+                if groups.iter().any(|(_, name, _)| *name == module_name) {
+                    return error("Duplicated group found.", span);
+                }
+
+                let mut next = attr_iter.next();
+                let prefixed = matches!(&next, Some(TokenTree::Ident(ident)) if ident.to_string() == "prefixed");
+                if prefixed {
+                    next = attr_iter.next();
+                }
+                let Some(TokenTree::Group(group)) = next else {
+                    return error(
+                        "Expected the string literals of the group between braces.",
+                        span,
+                    );
+                };
+                if group.delimiter() != Delimiter::Brace {
+                    return error(
+                        "Expected the string literals of the group between braces.",
+                        span,
+                    );
+                }
+
+                groups.push((group_name, module_name, prefixed));
+                group_iter = Some((groups.len() - 1, group.stream().into_iter()));
+                continue;
+            }
+
+            // rename("old-id" => "new-id")
+            if ident.to_string() == "rename" {
+                let Some(TokenTree::Group(group)) = attr_iter.next() else {
+                    return error(
+                        "Expected `(\"old-id\" => \"new-id\")` after the `rename` argument.",
+                        span,
+                    );
+                };
+                let mut rename_iter = group.stream().into_iter();
+                let maybe_old = string_literal_value(rename_iter.next(), span);
+                let (arrow_eq, arrow_gt) = (rename_iter.next(), rename_iter.next());
+                let maybe_new = string_literal_value(rename_iter.next(), span);
+                let (Ok(old_id), Ok(new_id)) = (maybe_old, maybe_new) else {
+                    return error(
+                        "Expected `(\"old-id\" => \"new-id\")` after the `rename` argument.",
+                        span,
+                    );
+                };
+                if group.delimiter() != Delimiter::Parenthesis
+                    || !matches!(arrow_eq, Some(TokenTree::Punct(punct)) if punct.as_char() == '=')
+                    || !matches!(arrow_gt, Some(TokenTree::Punct(punct)) if punct.as_char() == '>')
+                    || rename_iter.next().is_some()
+                {
+                    return error(
+                        "Expected `(\"old-id\" => \"new-id\")` after the `rename` argument.",
+                        span,
+                    );
+                }
+                if renames.iter().any(|(other, _, _)| *other == old_id) {
+                    return error("Duplicated renamed identifier found.", span);
+                }
+
+                renames.push((old_id, new_id, span));
+                expect_comma = true;
+                continue;
+            }
+
+            // arguments without value are flags, like `non_exhaustive`
+            let value = match attr_iter.next() {
+                Some(TokenTree::Punct(punct)) if punct.as_char() == '=' => {
+                    expect_comma = true;
+                    attr_iter.next()
+                }
+                Some(TokenTree::Punct(punct)) if punct.as_char() == ',' => None,
+                None => None,
+                _ => {
+                    return error("Expected `=` after the argument name.", span);
+                }
+            };
+
+            match ident.to_string().as_str() {
+                "acronyms" => {
+                    let maybe_acronyms = string_literals_from_list(value, span);
+                    if let Err(err) = maybe_acronyms {
+                        return err;
+                    }
+                    acronyms = maybe_acronyms.unwrap();
+                }
+                "reserved" => {
+                    let maybe_reserved = string_literals_from_list(value, span);
+                    if let Err(err) = maybe_reserved {
+                        return err;
+                    }
+                    reserved = maybe_reserved.unwrap();
+                }
+                "order" => {
+                    let maybe_order = string_literal_value(value, span);
+                    if let Err(err) = maybe_order {
+                        return err;
+                    }
+                    match maybe_order.unwrap().as_str() {
+                        "declaration" => sorted = false,
+                        "sorted" => sorted = true,
+                        _ => {
+                            return error(
+                                "Expected \"declaration\" or \"sorted\" as value of the `order` argument.",
+                                span,
+                            );
+                        }
+                    }
+                }
+                "ord" => {
+                    let maybe_ord = bool_value(value);
+                    if let Err(err) = maybe_ord {
+                        return err;
+                    }
+                    ord = maybe_ord.unwrap();
+                }
+                "require_sorted" => {
+                    let maybe_require_sorted = bool_value(value);
+                    if let Err(err) = maybe_require_sorted {
+                        return err;
+                    }
+                    require_sorted = maybe_require_sorted.unwrap();
+                }
+                "repr" => {
+                    let maybe_repr = string_literal_value(value, span);
+                    if let Err(err) = maybe_repr {
+                        return err;
+                    }
+                    if maybe_repr.unwrap() != "u16" {
+                        return error("Expected \"u16\" as value of the `repr` argument.", span);
+                    }
+                    repr_u16 = true;
+                    repr_span = span;
+                }
+                "allow_unused" => {
+                    let maybe_allow_unused = string_literals_from_list(value, span);
+                    if let Err(err) = maybe_allow_unused {
+                        return err;
+                    }
+                    allow_unused = maybe_allow_unused.unwrap();
+                    allow_unused_span = span;
+                }
+                "non_exhaustive" => {
+                    let maybe_non_exhaustive = bool_value(value);
+                    if let Err(err) = maybe_non_exhaustive {
+                        return err;
+                    }
+                    non_exhaustive = maybe_non_exhaustive.unwrap();
+                }
+                "id_case" => {
+                    let maybe_id_case = string_literal_value(value, span);
+                    if let Err(err) = maybe_id_case {
+                        return err;
+                    }
+                    id_case = id_case::IdCase::from_name(&maybe_id_case.unwrap());
+                    if id_case.is_none() {
+                        return error(
+                            "Expected \"kebab\", \"snake\" or \"camel\" as value of the `id_case` argument.",
+                            span,
+                        );
+                    }
+                }
+                "prefix" => {
+                    let maybe_prefix = string_literal_value(value, span);
+                    if let Err(err) = maybe_prefix {
+                        return err;
+                    }
+                    prefix = maybe_prefix.unwrap();
+                }
+                "env" => {
+                    let maybe_name = string_literal_value(value, span);
+                    if let Err(err) = maybe_name {
+                        return err;
+                    }
+                    let name = maybe_name.unwrap();
+                    let entries = match std::env::var(&name) {
+                        Ok(entries) => entries,
+                        Err(std::env::VarError::NotPresent) => {
+                            return error_with_help(
+                                &format!("The environment variable {name:?} is not set."),
+                                "set it to a comma-separated list of identifiers while building the crate",
+                                span,
+                            );
+                        }
+                        Err(std::env::VarError::NotUnicode(_)) => {
+                            return error(
+                                &format!("The environment variable {name:?} is not valid Unicode."),
+                                span,
+                            );
+                        }
+                    };
+
+                    // entries are trimmed and empty ones skipped, allowing
+                    // trailing commas and spaces after them
+                    for entry in entries.split(',').map(str::trim) {
+                        if entry.is_empty() {
+                            continue;
+                        }
+                        if entry.chars().any(|char| char.is_ascii_whitespace()) {
+                            return error("Identifiers cannot contain whitespace.", span);
+                        }
+                        if ids.iter().any(|id| id == entry) {
+                            return error("Duplicated string literal found.", span);
+                        }
+                        ids.push(entry.to_string());
+                        ids_spans.push(span);
+                        ids_literals.push(format!("{entry:?}"));
+                        ids_docs.push(None);
+                        ids_cfgs.push(None);
+                        ids_groups.push(None);
+                    }
+                }
+                "files" => {
+                    let maybe_files = string_literals_from_list(value, span);
+                    if let Err(err) = maybe_files {
+                        return err;
+                    }
+                    for file in maybe_files.unwrap() {
+                        let path = ids_file_path(&file);
+                        let content = match std::fs::read_to_string(&path) {
+                            Ok(content) => content,
+                            Err(err) => {
+                                return error(
+                                    &format!("Failed to read the identifiers file {file:?}: {err}"),
+                                    span,
+                                );
+                            }
+                        };
+                        files_paths.push(std::fs::canonicalize(&path).unwrap_or(path));
+
+                        // lines are trimmed and empty ones skipped, like the
+                        // entries of the `env` argument
+                        for (line_index, line) in content.lines().enumerate() {
+                            let entry = line.trim();
+                            if entry.is_empty() {
+                                continue;
+                            }
+                            let line_number = line_index + 1;
+                            if entry.chars().any(|char| char.is_ascii_whitespace()) {
+                                return error(
+                                    &format!(
+                                        "The identifier {entry:?} in {file:?} at line {line_number} contains whitespace."
+                                    ),
+                                    span,
+                                );
+                            }
+                            if let Some(i) = ids.iter().position(|id| id == entry) {
+                                let help = match ids_files_lines.iter().find(|(j, ..)| *j == i) {
+                                    Some((_, other_file, other_line)) => format!(
+                                        "it's also declared in {other_file:?} at line {other_line}"
+                                    ),
+                                    None => {
+                                        "it's also declared as a string literal in the attribute"
+                                            .to_string()
+                                    }
+                                };
+                                return error_with_help(
+                                    &format!(
+                                        "Duplicated identifier {entry:?} found in {file:?} at line {line_number}."
+                                    ),
+                                    &help,
+                                    span,
+                                );
+                            }
+                            ids_files_lines.push((ids.len(), file.clone(), line_number));
+                            ids.push(entry.to_string());
+                            ids_spans.push(span);
+                            ids_literals.push(format!("{entry:?}"));
+                            ids_docs.push(None);
+                            ids_cfgs.push(None);
+                            ids_groups.push(None);
+                        }
+                    }
+                }
+                "manifest" => {
+                    let maybe_manifest = string_literal_value(value, span);
+                    if let Err(err) = maybe_manifest {
+                        return err;
+                    }
+                    manifest = Some((maybe_manifest.unwrap(), span));
+                }
+                "default" => {
+                    let maybe_default = bool_value(value);
+                    if let Err(err) = maybe_default {
+                        return err;
+                    }
+                    default = maybe_default.unwrap();
+                    default_span = span;
+                }
+                "strict_html" => {
+                    let maybe_strict_html = bool_value(value);
+                    if let Err(err) = maybe_strict_html {
+                        return err;
+                    }
+                    strict_html = maybe_strict_html.unwrap();
+                }
+                "strict_css" => {
+                    let maybe_strict_css = bool_value(value);
+                    if let Err(err) = maybe_strict_css {
+                        return err;
+                    }
+                    strict_css = maybe_strict_css.unwrap();
+                }
+                "leptos_version" => {
+                    let maybe_leptos_version = string_literal_value(value, span);
+                    if let Err(err) = maybe_leptos_version {
+                        return err;
+                    }
+                    match maybe_leptos_version.unwrap().as_str() {
+                        "0.6" => leptos_06 = true,
+                        "0.7" | "0.8" => leptos_06 = false,
+                        _ => {
+                            return error(
+                                "Expected \"0.6\", \"0.7\" or \"0.8\" as value of the `leptos_version` argument.",
+                                span,
+                            );
+                        }
+                    }
+                }
+                "allow_enum_variant_names" => {
+                    let maybe_allow_enum_variant_names = bool_value(value);
+                    if let Err(err) = maybe_allow_enum_variant_names {
+                        return err;
+                    }
+                    allow_enum_variant_names = maybe_allow_enum_variant_names.unwrap();
+                }
+                "hashed_in_release" => {
+                    let maybe_hashed_in_release = bool_value(value);
+                    if let Err(err) = maybe_hashed_in_release {
+                        return err;
+                    }
+                    hashed_in_release = maybe_hashed_in_release.unwrap();
+                }
+                _ => {
+                    return error("Unknown argument in the attribute.", span);
+                }
+            }
+        } else if let TokenTree::Punct(punct) = token {
+            // #[cfg(feature = "admin")] "admin-panel"
+            if punct.as_char() == '#' && !expect_comma && pending_cfg.is_none() {
+                let next = match group_iter.as_mut() {
+                    Some((_, iter)) => iter.next(),
+                    None => attr_iter.next(),
+                };
+                let span = punct.span();
+                match cfg_predicate(next) {
+                    Some(predicate) => pending_cfg = Some((predicate, span)),
+                    None => {
+                        return error(
+                            "Expected a `#[cfg(...)]` attribute before the string literal.",
+                            span,
+                        );
+                    }
+                }
+                continue;
+            }
+            if punct.as_char() != ',' {
+                let span = punct.span();
+                return error(
+                    "Expected a comma between string literals in the attribute.",
+                    span,
+                );
+            }
+            if !expect_comma {
+                let span = punct.span();
+                return error("Unexpected comma in the attribute.", span);
+            }
+            expect_comma = false;
+        } else {
+            let span = token.span();
+            return error(
+                "Expected only string literals and commas in the attribute.",
+                span,
+            );
+        }
+    }
+
+    if let Some((_, span)) = pending_cfg {
+        return error(
+            "Expected a string literal after the `#[cfg(...)]` attribute.",
+            span,
+        );
+    }
+
+    // the string literals are checked as written, before converting their case
+    if require_sorted && let Some(i) = (1..ids.len()).find(|i| ids[*i] < ids[*i - 1]) {
+        let position = ids[..i].iter().position(|id| *id > ids[i]).unwrap();
+        return error_with_help(
+            &format!(
+                "The string literal {} is not sorted with `require_sorted`.",
+                ids_literals[i]
+            ),
+            &format!("move it before {}", ids_literals[position]),
+            ids_spans[i],
+        );
+    }
+
+    if allow_unused.iter().any(|id| !ids.contains(id)) {
+        return error(
+            "Identifiers of the `allow_unused` argument must be declared in the attribute.",
+            allow_unused_span,
+        );
+    }
+
+    // renamed identifiers are resolved to the position of their new identifier
+    let mut resolved_renames: Vec<(&str, &str, usize, Span)> = Vec::with_capacity(renames.len());
+    for (old, new, span) in &renames {
+        if ids.contains(old) {
+            return error_with_help(
+                &format!("The renamed identifier {old:?} is still declared in the attribute."),
+                "remove the string literal of the old identifier",
+                *span,
+            );
+        }
+        let Some(i) = ids.iter().position(|id| id == new) else {
+            return error(
+                &format!(
+                    "The new identifier {new:?} of the `rename` argument must be declared in the attribute."
+                ),
+                *span,
+            );
+        };
+        resolved_renames.push((old, new, i, *span));
+    }
+
+    if let Some(id_case) = id_case {
+        let mut converted_ids: Vec<String> = Vec::with_capacity(ids.len());
+        for (id, span) in ids.iter().zip(&ids_spans) {
+            let converted = id_case.convert(id);
+            if converted_ids.contains(&converted) {
+                return error(
+                    "Duplicated identifier found after converting its case.",
+                    *span,
+                );
+            }
+            converted_ids.push(converted);
+        }
+        for id in &mut allow_unused {
+            *id = id_case.convert(id);
+        }
+        ids = converted_ids;
+    }
+
+    // the names of the variants and constants are built from the identifiers
+    // without the prefix, which are returned by `local_name`
+    let local_ids = ids.clone();
+    if !prefix.is_empty() {
+        ids = ids.iter().map(|id| format!("{prefix}{id}")).collect();
+        for id in &mut allow_unused {
+            *id = format!("{prefix}{id}");
+        }
+    }
+
+    // names of the variants are built once and kept to compare them without
+    // converting the identifiers back to strings
+    let mut ids_variants_names: Vec<String> = Vec::with_capacity(ids.len());
+    let mut ids_variants_idents = Vec::with_capacity(ids.len());
+    for (((id, local_id), span), literal) in ids
+        .iter()
+        .zip(&local_ids)
+        .zip(&ids_spans)
+        .zip(&ids_literals)
+    {
+        // Identifiers are checked again here because they are the final values
+        // emitted after all the transformations applied to the string literals,
+        // like `id_case` converting a literal without words as `"--"` to `""`.
+        if id.is_empty() {
+            return error_with_help(
+                &format!("The identifier emitted for the string literal {literal} is empty."),
+                "include at least one letter or digit in the identifier",
+                *span,
+            );
+        }
+
+        if reserved.contains(id) {
+            return error_with_help(
+                &format!("The string literal {literal} emits the reserved identifier {id:?}."),
+                "rename it, because the identifier is declared in the `reserved` argument",
+                *span,
+            );
+        }
+
+        if strict_html && !is_strict_html_id(id) {
+            return error(
+                "Identifiers must start with a letter and contain only letters, digits, `-`, `_`, `:` and `.` with `strict_html`.",
+                *span,
+            );
+        }
+
+        if strict_css && !is_css_identifier(id) {
+            return error_with_help(
+                &format!(
+                    "The string literal {literal} is not a valid CSS identifier with `strict_css`."
+                ),
+                "start it with a letter, `_` or `-` not followed by a digit, and use only letters, digits, `-` and `_`, so selectors don't need escaping",
+                *span,
+            );
+        }
+
+        #[cfg(not(feature = "unicode"))]
+        if !id.is_ascii() {
+            return error_with_help(
+                &format!("The string literal {literal} contains non-ASCII characters."),
+                "enable the `unicode` feature of `leptos-unique-ids` to allow them",
+                *span,
+            );
+        }
+
+        let maybe_pascal = pascal_case::to_pascal_case_with_acronyms(local_id, &acronyms);
+        if let Err(err) = maybe_pascal {
+            return error(err, *span);
+        }
+        let pascal = maybe_pascal.unwrap();
+        if pascal.is_empty() {
+            return error_with_help(
+                &format!("The variant name built from the string literal {literal} is empty."),
+                "include at least one letter or digit in the identifier",
+                *span,
+            );
+        }
+        if pascal.starts_with(|char: char| char.is_ascii_digit()) {
+            return error_with_help(
+                &format!(
+                    "The variant name `{pascal}` built from the string literal {literal} starts with a digit."
+                ),
+                &format!("prefix the identifier with a letter, like \"id-{local_id}\""),
+                *span,
+            );
+        }
+        // located at the string literal to navigate to it from the variant, but
+        // resolved at the call site to keep the hygiene of the rest of the items
+        ids_variants_idents.push(Ident::new(&pascal, call_site_span.located_at(*span)));
+        ids_variants_names.push(pascal.into_owned());
+    }
+
+    // names of the variants in the modules of the groups, without the name of
+    // the group for prefixed groups, like `nav::Logo` for `Ids::NavLogo`
+    let groups_pascal: Vec<Option<Cow<str>>> = groups
+        .iter()
+        .map(|(name, _, prefixed)| {
+            if *prefixed {
+                pascal_case::to_pascal_case_with_acronyms(name, &acronyms).ok()
+            } else {
+                None
+            }
+        })
+        .collect();
+    let mut groups_variants_idents: Vec<Ident> = Vec::with_capacity(ids.len());
+    for ((group, variant_name), span) in ids_groups.iter().zip(&ids_variants_names).zip(&ids_spans)
+    {
+        let mut name = variant_name.as_str();
+        if let Some(i) = group
+            && let Some(group_pascal) = &groups_pascal[*i]
+            && let Some(unprefixed) = name.strip_prefix(group_pascal.as_ref() as &str)
+            && unprefixed.starts_with(|char: char| !char.is_ascii_digit())
+        {
+            name = unprefixed;
+        }
+        groups_variants_idents.push(Ident::new(name, call_site_span.located_at(*span)));
+    }
+
+    // names of the deprecated constants aliasing the variants of renamed identifiers
+    let mut renamed_names: Vec<Cow<str>> = Vec::with_capacity(resolved_renames.len());
+    for (old, _, _, span) in &resolved_renames {
+        let pascal = match pascal_case::to_pascal_case_with_acronyms(old, &acronyms) {
+            Ok(pascal) if pascal.starts_with(|char: char| char.is_alphabetic()) => pascal,
+            _ => {
+                return error(
+                    &format!("The renamed identifier {old:?} doesn't build a valid variant name."),
+                    *span,
+                );
+            }
+        };
+        if ids_variants_names.iter().any(|name| *name == pascal) || renamed_names.contains(&pascal)
+        {
+            return error(
+                &format!(
+                    "The renamed identifier {old:?} builds the name of another variant `{pascal}`."
+                ),
+                *span,
+            );
+        }
+        renamed_names.push(pascal);
+    }
+    let renamed_idents: Vec<Ident> = renamed_names
+        .iter()
+        .map(|name| Ident::new(name, call_site_span))
+        .collect();
+
+    let mut ids_constants_idents: Vec<Ident> = Vec::with_capacity(ids.len());
+    let mut constants_names: HashSet<String> = HashSet::with_capacity(ids.len());
+    for (id, span) in local_ids.iter().zip(&ids_spans) {
+        if !is_struct && !cfg!(feature = "constants") {
+            break;
+        }
+        let screaming = screaming_snake_case::to_screaming_snake_case(id);
+        ids_constants_idents.push(Ident::new(&screaming, call_site_span.located_at(*span)));
+        if !constants_names.insert(screaming) {
+            return error("Duplicated constant name found.", *span);
+        }
+    }
+
+    let ids_length = ids.len();
+
+    // `#[cfg(...)]` attributes of the variants and the rest of items generated
+    // for each identifier, empty for identifiers compiled unconditionally
+    let ids_cfg_attributes: Vec<TokenStream> = ids_cfgs
+        .iter()
+        .map(|predicate| cfg_attribute(predicate.as_ref(), call_site_span))
+        .collect();
+
+    // identifiers between quotes, used as the documentation of their items
+    let ids_quoted: Vec<String> = ids.iter().map(|id| quoted(id)).collect();
+
+    // warnings for identifiers that only differ by case or separators, which
+    // are likely typos, like "user-name" and "username"
+    let mut similar_ids_warnings: Vec<TokenTree> = Vec::new();
+    let mut normalized_ids: HashMap<String, usize> = HashMap::with_capacity(ids_length);
+    for (i, local_id) in local_ids.iter().enumerate() {
+        let normalized: String = local_id
+            .chars()
+            .filter(|char| char.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect();
+        match normalized_ids.entry(normalized) {
+            Entry::Occupied(entry) => {
+                let first = *entry.get();
+                similar_ids_warnings.extend(ids_cfg_attributes[first].clone());
+                similar_ids_warnings.extend(ids_cfg_attributes[i].clone());
+                similar_ids_warnings.extend(similar_id_warning(
+                    &format!(
+                        "the identifiers {} and {} only differ by case or separators",
+                        ids_literals[first], ids_literals[i]
+                    ),
+                    ids_spans[i],
+                ));
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(i);
+            }
+        }
+    }
+
+    if ids_length == 0 && default {
+        return error(
+            "The `default` flag requires at least one string literal in the attribute.",
+            default_span,
+        );
+    }
+
+    if default && ids_cfgs[0].is_some() {
+        return error_with_help(
+            "The `default` flag requires a first string literal without `#[cfg(...)]`.",
+            "move a string literal compiled unconditionally to the start of the attribute",
+            default_span,
+        );
+    }
+
+    // enums without variants can't have a primitive representation
+    if ids_length == 0 && repr_u16 {
+        return error(
+            "The `repr` argument requires at least one string literal in the attribute.",
+            repr_span,
+        );
+    }
+
+    if repr_u16 && ids_length > usize::from(u16::MAX) + 1 {
+        return error(
+            "Too many identifiers for `repr = \"u16\"`, at most 65536 are allowed.",
+            repr_span,
+        );
+    }
+
+    // order of the variants in `SORTED_NAMES`, sorted here so lookups
+    // can use binary search without sorting at runtime
+    let mut sorted_indexes: Vec<usize> = (0..ids_length).collect();
+    sorted_indexes.sort_by_key(|i| &ids[*i]);
+
+    // order of the variants in `ALL` and `PAIRS`
+    let ordered_indexes: Vec<usize> = if sorted {
+        sorted_indexes.clone()
+    } else {
+        (0..ids_length).collect()
+    };
+
+    // identifiers emitted in each build profile with the `cfg` attribute that
+    // selects the items containing them, and their order in `SORTED_NAMES`,
+    // release builds emit short hashes of the identifiers with `hashed_in_release`
+    let mut profiles: Vec<(TokenStream, Vec<String>, Vec<usize>)> = Vec::with_capacity(2);
+    if hashed_in_release {
+        let mut hashed_ids: Vec<String> = Vec::with_capacity(ids_length);
+        for (id, span) in ids.iter().zip(&ids_spans) {
+            let hashed_id = short_hash(id);
+            if hashed_ids.contains(&hashed_id) {
+                return error(
+                    &format!(
+                        "The hash {hashed_id:?} of the identifier {id:?} is duplicated with `hashed_in_release`."
+                    ),
+                    *span,
+                );
+            }
+            hashed_ids.push(hashed_id);
+        }
+        let mut hashed_sorted_indexes: Vec<usize> = (0..ids_length).collect();
+        hashed_sorted_indexes.sort_by_key(|i| &hashed_ids[*i]);
+
+        profiles.push((
+            debug_assertions_cfg(false, call_site_span),
+            ids.clone(),
+            sorted_indexes.clone(),
+        ));
+        profiles.push((
+            debug_assertions_cfg(true, call_site_span),
+            hashed_ids,
+            hashed_sorted_indexes,
+        ));
+    } else {
+        profiles.push((TokenStream::new(), ids.clone(), sorted_indexes.clone()));
+    }
+
+    if let Some((path, span)) = &manifest
+        && let Err(err) = write_manifest(path, &ids)
+    {
+        return error(
+            &format!("Failed to write the manifest {path:?}: {err}"),
+            *span,
+        );
+    }
+
+    // the files are included with `include_bytes!`, so Cargo rebuilds the
+    // crate when they change
+    let mut included_files: Vec<TokenTree> = Vec::with_capacity(files_paths.len() * 15);
+    for path in &files_paths {
+        included_files.extend(include_bytes_item(&path.to_string_lossy(), call_site_span));
+    }
+
+    if is_struct {
+        // the rest of the arguments only affect the enum and its items
+        for (enum_only, name) in [
+            (non_exhaustive, "non_exhaustive"),
+            (default, "default"),
+            (repr_u16, "repr"),
+            (ord, "ord"),
+            (!groups.is_empty(), "group"),
+            (!renames.is_empty(), "rename"),
+            (!allow_unused.is_empty(), "allow_unused"),
+        ] {
+            if enum_only {
+                return error(
+                    &format!("The `{name}` argument is only supported for enums."),
+                    call_site_span,
+                );
+            }
+        }
+
+        let mut tokens = item;
+        tokens.extend([
+            TokenTree::Ident(Ident::new("impl", call_site_span)),
+            TokenTree::Ident(Ident::new(&enum_name, call_site_span)),
+            TokenTree::Group(Group::new(Delimiter::Brace, {
+                let mut inner: Vec<TokenTree> =
+                    Vec::with_capacity(profiles.len() * ids_length * 16);
+                for (cfg, profile_ids, _) in &profiles {
+                    for i in 0..ids_length {
+                        inner.extend(cfg.clone());
+                        inner.extend(ids_cfg_attributes[i].clone());
+                        inner.extend([
+                            TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+                            TokenTree::Group(Group::new(
+                                Delimiter::Bracket,
+                                [
+                                    TokenTree::Ident(Ident::new("doc", call_site_span)),
+                                    TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                                    TokenTree::Literal(Literal::string(&ids_quoted[i])),
+                                ]
+                                .into_iter()
+                                .collect(),
+                            )),
+                        ]);
+                        if let Some(doc) = &ids_docs[i] {
+                            inner.extend([
+                                TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+                                TokenTree::Group(Group::new(
+                                    Delimiter::Bracket,
+                                    [
+                                        TokenTree::Ident(Ident::new("doc", call_site_span)),
+                                        TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                                        TokenTree::Literal(Literal::string(doc)),
+                                    ]
+                                    .into_iter()
+                                    .collect(),
+                                )),
+                            ]);
+                        }
+                        if let Some(vis) = &vis {
+                            inner.extend(vis.clone());
+                        }
+                        inner.extend([
+                            TokenTree::Ident(Ident::new("const", call_site_span)),
+                            TokenTree::Ident(ids_constants_idents[i].clone()),
+                            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                            TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("static", call_site_span)),
+                            TokenTree::Ident(Ident::new("str", call_site_span)),
+                            TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                            TokenTree::Literal(Literal::string(&profile_ids[i])),
+                            TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                        ]);
+                    }
+                }
+                inner.into_iter().collect()
+            })),
+        ]);
+        tokens.extend(similar_ids_warnings);
+        tokens.extend(included_files);
+        return tokens;
+    }
+
+    // remove the last token and add the implementation
+    let mut tokens: Vec<TokenTree> = item.into_iter().collect();
+    tokens.pop();
+
+    if non_exhaustive {
+        tokens.splice(
+            0..0,
+            [
+                TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Bracket,
+                    TokenStream::from(TokenTree::Ident(Ident::new(
+                        "non_exhaustive",
+                        call_site_span,
+                    ))),
+                )),
+            ],
+        );
+    }
+
+    // variants built from identifiers often share prefixes and suffixes
+    if allow_enum_variant_names {
+        tokens.splice(
+            0..0,
+            [
+                TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Bracket,
+                    [
+                        TokenTree::Ident(Ident::new("allow", call_site_span)),
+                        TokenTree::Group(Group::new(
+                            Delimiter::Parenthesis,
+                            [
+                                TokenTree::Ident(Ident::new("clippy", call_site_span)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("enum_variant_names", call_site_span)),
+                            ]
+                            .into_iter()
+                            .collect(),
+                        )),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+            ],
+        );
+    }
+
+    if repr_u16 {
+        tokens.splice(
+            0..0,
+            [
+                TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Bracket,
+                    [
+                        TokenTree::Ident(Ident::new("repr", call_site_span)),
+                        TokenTree::Group(Group::new(
+                            Delimiter::Parenthesis,
+                            TokenStream::from(TokenTree::Ident(Ident::new("u16", call_site_span))),
+                        )),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+            ],
+        );
+    }
+
+    // enum declaration
+    let group = Group::new(Delimiter::Brace, {
+        let mut inner: Vec<TokenTree> = Vec::with_capacity(ids_length * 4);
+        for i in 0..ids_length {
+            let ident = &ids_variants_idents[i];
+            let id = &ids[i];
+            // the documentation points to the string literal like the variant
+            let mut id_doc = Literal::string(&ids_quoted[i]);
+            id_doc.set_span(ident.span());
+            inner.extend(ids_cfg_attributes[i].clone());
+            inner.extend([
+                TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Bracket,
+                    [
+                        TokenTree::Ident(Ident::new("doc", call_site_span)),
+                        TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                        TokenTree::Literal(id_doc),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+            ]);
+            // the identifier without the prefix is searchable in the documentation,
+            // and read by the `cross_enum_duplicate_id` lint to compare local names
+            let local_id = &local_ids[i];
+            if local_id != id
+                && *local_id != ids_variants_names[i]
+                && !local_id.contains(['"', '\''])
+            {
+                inner.extend([
+                    TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Bracket,
+                        [
+                            TokenTree::Ident(Ident::new("doc", call_site_span)),
+                            TokenTree::Group(Group::new(
+                                Delimiter::Parenthesis,
+                                [
+                                    TokenTree::Ident(Ident::new("alias", call_site_span)),
+                                    TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                                    TokenTree::Literal(Literal::string(local_id)),
+                                ]
+                                .into_iter()
+                                .collect(),
+                            )),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                ]);
+            }
+            if let Some(doc) = &ids_docs[i] {
+                inner.extend([
+                    TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Bracket,
+                        [
+                            TokenTree::Ident(Ident::new("doc", call_site_span)),
+                            TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                            TokenTree::Literal(Literal::string(doc)),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                ]);
+            }
+            // the lint is only known when running Dylint
+            if allow_unused.contains(id) {
+                for lint in ["unknown_lints", "unused_ids_variant"] {
+                    inner.extend([
+                        TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+                        TokenTree::Group(Group::new(
+                            Delimiter::Bracket,
+                            [
+                                TokenTree::Ident(Ident::new("allow", call_site_span)),
+                                TokenTree::Group(Group::new(
+                                    Delimiter::Parenthesis,
+                                    TokenStream::from(TokenTree::Ident(Ident::new(
+                                        lint,
+                                        call_site_span,
+                                    ))),
+                                )),
+                            ]
+                            .into_iter()
+                            .collect(),
+                        )),
+                    ]);
+                }
+            }
+            inner.extend([TokenTree::Ident(ident.clone())]);
+            // explicit discriminants keep the representation stable
+            if repr_u16 {
+                inner.extend([
+                    TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                    TokenTree::Literal(Literal::u16_unsuffixed(
+                        u16::try_from(i).expect("The number of identifiers has been checked"),
+                    )),
+                ]);
+            }
+            inner.extend([TokenTree::Punct(Punct::new(',', Spacing::Alone))]);
+        }
+        inner.into_iter().collect()
+    });
+    tokens.push(TokenTree::Group(group));
+
+    // singular of the enum name, validated after parsing it
+    let singular_name =
+        singular_enum_name(&enum_name).expect("Expected an enum name ending in `s`");
+
+    // IdSelector wrapper, named after the singular of the enum name like
+    // `UnknownId`, displayed as the id selector of the identifier
+    #[cfg(feature = "selector")]
+    let selector_name = format!("{singular_name}Selector");
+
+    // as_str impl
+    tokens.extend([
+        TokenTree::Ident(Ident::new("impl", call_site_span)),
+        TokenTree::Ident(Ident::new(&enum_name, call_site_span)),
+    ]);
+
+    let impl_group = Group::new(Delimiter::Brace, {
+        let mut inner = TokenStream::new();
+
+        inner.extend(must_use_attribute(call_site_span));
+        // without the `as-str` feature the method is private, only used by
+        // the rest of the generated items that need the identifiers
+        #[cfg(feature = "as-str")]
+        if let Some(vis) = &vis {
+            inner.extend(vis.clone());
+        }
+        #[cfg(not(feature = "as-str"))]
+        inner.extend([
+            TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+            TokenTree::Group(Group::new(
+                Delimiter::Bracket,
+                [
+                    TokenTree::Ident(Ident::new("allow", call_site_span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        TokenStream::from(TokenTree::Ident(Ident::new(
+                            "dead_code",
+                            call_site_span,
+                        ))),
+                    )),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+        ]);
+
+        inner.extend([
+            TokenTree::Ident(Ident::new("fn", call_site_span)),
+            TokenTree::Ident(Ident::new("as_str", call_site_span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Parenthesis,
+                [
+                    TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("self", call_site_span)),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+            TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+            TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+            TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("static", call_site_span)),
+            TokenTree::Ident(Ident::new("str", call_site_span)),
+        ]);
+
+        let group = Group::new(
+            Delimiter::Brace,
+            [
+                TokenTree::Ident(Ident::new("match", call_site_span)),
+                TokenTree::Punct(Punct::new('*', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("self", call_site_span)),
+                TokenTree::Group(Group::new(Delimiter::Brace, {
+                    let mut inner: Vec<TokenTree> =
+                        Vec::with_capacity(profiles.len() * ids_length * 10);
+                    for (cfg, profile_ids, _) in &profiles {
+                        for ((id, ident), cfg_attribute) in profile_ids
+                            .iter()
+                            .zip(&ids_variants_idents)
+                            .zip(&ids_cfg_attributes)
+                        {
+                            inner.extend(cfg.clone());
+                            inner.extend(cfg_attribute.clone());
+                            inner.extend([
+                                TokenTree::Ident(Ident::new("Self", call_site_span)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Ident(ident.to_owned()),
+                                TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                                TokenTree::Literal(Literal::string(id)),
+                                TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                            ]);
+                        }
+                    }
+                    inner.into_iter().collect()
+                })),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        inner.extend([TokenTree::Group(group)]);
+
+        // as_bytes method, matching byte string literals to keep it const
+        inner.extend(must_use_attribute(call_site_span));
+        if let Some(vis) = &vis {
+            inner.extend(vis.clone());
+        }
+        inner.extend([
+            TokenTree::Ident(Ident::new("const", call_site_span)),
+            TokenTree::Ident(Ident::new("fn", call_site_span)),
+            TokenTree::Ident(Ident::new("as_bytes", call_site_span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Parenthesis,
+                [
+                    TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("self", call_site_span)),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+            TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+            TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+            TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("static", call_site_span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Bracket,
+                TokenStream::from(TokenTree::Ident(Ident::new("u8", call_site_span))),
+            )),
+            TokenTree::Group(Group::new(
+                Delimiter::Brace,
+                [
+                    TokenTree::Ident(Ident::new("match", call_site_span)),
+                    TokenTree::Punct(Punct::new('*', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("self", call_site_span)),
+                    TokenTree::Group(Group::new(Delimiter::Brace, {
+                        let mut inner: Vec<TokenTree> =
+                            Vec::with_capacity(profiles.len() * ids_length * 10);
+                        for (cfg, profile_ids, _) in &profiles {
+                            for ((id, ident), cfg_attribute) in profile_ids
+                                .iter()
+                                .zip(&ids_variants_idents)
+                                .zip(&ids_cfg_attributes)
+                            {
+                                inner.extend(cfg.clone());
+                                inner.extend(cfg_attribute.clone());
+                                inner.extend([
+                                    TokenTree::Ident(Ident::new("Self", call_site_span)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Ident(ident.to_owned()),
+                                    TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                                    TokenTree::Literal(Literal::byte_string(id.as_bytes())),
+                                    TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                                ]);
+                            }
+                        }
+                        inner.into_iter().collect()
+                    })),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+        ]);
+
+        // local_name method
+        inner.extend(must_use_attribute(call_site_span));
+        if let Some(vis) = &vis {
+            inner.extend(vis.clone());
+        }
+        inner.extend([
+            TokenTree::Ident(Ident::new("fn", call_site_span)),
+            TokenTree::Ident(Ident::new("local_name", call_site_span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Parenthesis,
+                [
+                    TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("self", call_site_span)),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+            TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+            TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+            TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("static", call_site_span)),
+            TokenTree::Ident(Ident::new("str", call_site_span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Brace,
+                [
+                    TokenTree::Ident(Ident::new("match", call_site_span)),
+                    TokenTree::Punct(Punct::new('*', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("self", call_site_span)),
+                    TokenTree::Group(Group::new(Delimiter::Brace, {
+                        let mut inner: Vec<TokenTree> = Vec::with_capacity(ids_length * 8);
+                        for ((ident, local_id), cfg_attribute) in ids_variants_idents
+                            .iter()
+                            .zip(&local_ids)
+                            .zip(&ids_cfg_attributes)
+                        {
+                            inner.extend(cfg_attribute.clone());
+                            inner.extend([
+                                TokenTree::Ident(Ident::new("Self", call_site_span)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Ident(ident.clone()),
+                                TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                                TokenTree::Literal(Literal::string(local_id)),
+                                TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                            ]);
+                        }
+                        inner.into_iter().collect()
+                    })),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+        ]);
+
+        // as_json_value method
+        #[cfg(feature = "serde_json")]
+        {
+            inner.extend(must_use_attribute(call_site_span));
+            if let Some(vis) = &vis {
+                inner.extend(vis.clone());
+            }
+            inner.extend([
+                TokenTree::Ident(Ident::new("fn", call_site_span)),
+                TokenTree::Ident(Ident::new("as_json_value", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("self", call_site_span)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+                TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("serde_json", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("Value", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Brace,
+                    [
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("serde_json", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("Value", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("String", call_site_span)),
+                        TokenTree::Group(Group::new(
+                            Delimiter::Parenthesis,
+                            [
+                                TokenTree::Ident(Ident::new("self", call_site_span)),
+                                TokenTree::Punct(Punct::new('.', Spacing::Joint)),
+                                TokenTree::Ident(Ident::new("as_str", call_site_span)),
+                                TokenTree::Group(Group::new(
+                                    Delimiter::Parenthesis,
+                                    TokenStream::new(),
+                                )),
+                                TokenTree::Punct(Punct::new('.', Spacing::Joint)),
+                                TokenTree::Ident(Ident::new("into", call_site_span)),
+                                TokenTree::Group(Group::new(
+                                    Delimiter::Parenthesis,
+                                    TokenStream::new(),
+                                )),
+                            ]
+                            .into_iter()
+                            .collect(),
+                        )),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+            ]);
+        }
+
+        // as_data_attr method, custom attributes were added in Leptos 0.7
+        #[cfg(feature = "into-attribute-value")]
+        if !leptos_06 {
+            inner.extend(must_use_attribute(call_site_span));
+            if let Some(vis) = &vis {
+                inner.extend(vis.clone());
+            }
+            inner.extend([
+                TokenTree::Ident(Ident::new("fn", call_site_span)),
+                TokenTree::Ident(Ident::new("as_data_attr", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Ident(Ident::new("self", call_site_span)),
+                        TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("name", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("static", call_site_span)),
+                        TokenTree::Ident(Ident::new("str", call_site_span)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+                TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("impl", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("leptos", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("attr", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("Attribute", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Brace,
+                    [
+                        // best-effort validation of the attribute name
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("core", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("debug_assert", call_site_span)),
+                        TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+                        TokenTree::Group(Group::new(
+                            Delimiter::Parenthesis,
+                            [
+                                TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("name", call_site_span)),
+                                TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("is_empty", call_site_span)),
+                                TokenTree::Group(Group::new(
+                                    Delimiter::Parenthesis,
+                                    TokenStream::new(),
+                                )),
+                                TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new('&', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("name", call_site_span)),
+                                TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("chars", call_site_span)),
+                                TokenTree::Group(Group::new(
+                                    Delimiter::Parenthesis,
+                                    TokenStream::new(),
+                                )),
+                                TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("all", call_site_span)),
+                                TokenTree::Group(Group::new(
+                                    Delimiter::Parenthesis,
+                                    [
+                                        TokenTree::Punct(Punct::new('|', Spacing::Alone)),
+                                        TokenTree::Ident(Ident::new("char", call_site_span)),
+                                        TokenTree::Punct(Punct::new('|', Spacing::Alone)),
+                                        TokenTree::Ident(Ident::new("char", call_site_span)),
+                                        TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                                        TokenTree::Ident(Ident::new(
+                                            "is_ascii_alphanumeric",
+                                            call_site_span,
+                                        )),
+                                        TokenTree::Group(Group::new(
+                                            Delimiter::Parenthesis,
+                                            TokenStream::new(),
+                                        )),
+                                        TokenTree::Punct(Punct::new('|', Spacing::Joint)),
+                                        TokenTree::Punct(Punct::new('|', Spacing::Alone)),
+                                        TokenTree::Ident(Ident::new("char", call_site_span)),
+                                        TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                                        TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                                        TokenTree::Literal(Literal::character('-')),
+                                        TokenTree::Punct(Punct::new('|', Spacing::Joint)),
+                                        TokenTree::Punct(Punct::new('|', Spacing::Alone)),
+                                        TokenTree::Ident(Ident::new("char", call_site_span)),
+                                        TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                                        TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                                        TokenTree::Literal(Literal::character('_')),
+                                    ]
+                                    .into_iter()
+                                    .collect(),
+                                )),
+                                TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                                TokenTree::Literal(Literal::string(
+                                    "Invalid data attribute name {name:?}",
+                                )),
+                            ]
+                            .into_iter()
+                            .collect(),
+                        )),
+                        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                        // data-<name>=<id>
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("leptos", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("attr", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("custom", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("custom_attribute", call_site_span)),
+                        TokenTree::Group(Group::new(
+                            Delimiter::Parenthesis,
+                            [
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Ident(Ident::new("std", call_site_span)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Ident(Ident::new("borrow", call_site_span)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Ident(Ident::new("Cow", call_site_span)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new('<', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+                                TokenTree::Ident(Ident::new("static", call_site_span)),
+                                TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("str", call_site_span)),
+                                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Ident(Ident::new("Owned", call_site_span)),
+                                TokenTree::Group(Group::new(
+                                    Delimiter::Parenthesis,
+                                    [
+                                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                        TokenTree::Ident(Ident::new("std", call_site_span)),
+                                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                        TokenTree::Ident(Ident::new("format", call_site_span)),
+                                        TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+                                        TokenTree::Group(Group::new(
+                                            Delimiter::Parenthesis,
+                                            TokenStream::from(TokenTree::Literal(Literal::string(
+                                                "data-{name}",
+                                            ))),
+                                        )),
+                                    ]
+                                    .into_iter()
+                                    .collect(),
+                                )),
+                                TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("self", call_site_span)),
+                                TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("as_str", call_site_span)),
+                                TokenTree::Group(Group::new(
+                                    Delimiter::Parenthesis,
+                                    TokenStream::new(),
+                                )),
+                            ]
+                            .into_iter()
+                            .collect(),
+                        )),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+            ]);
+        }
+
+        // attr method
+        #[cfg(feature = "into-attribute-value")]
+        {
+            if let Some(vis) = &vis {
+                inner.extend(vis.clone());
+            }
+            inner.extend([
+                TokenTree::Ident(Ident::new("fn", call_site_span)),
+                TokenTree::Ident(Ident::new("attr", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("self", call_site_span)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+                TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("static", call_site_span)),
+                        TokenTree::Ident(Ident::new("str", call_site_span)),
+                        TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                        TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("static", call_site_span)),
+                        TokenTree::Ident(Ident::new("str", call_site_span)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+                TokenTree::Group(Group::new(
+                    Delimiter::Brace,
+                    TokenStream::from(TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        [
+                            TokenTree::Literal(Literal::string("id")),
+                            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("self", call_site_span)),
+                            TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("as_str", call_site_span)),
+                            TokenTree::Group(Group::new(
+                                Delimiter::Parenthesis,
+                                TokenStream::new(),
+                            )),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    ))),
+                )),
+            ]);
+        }
+
+        // attrs_with_testid method
+        #[cfg(feature = "testid")]
+        {
+            inner.extend(must_use_attribute(call_site_span));
+            if let Some(vis) = &vis {
+                inner.extend(vis.clone());
+            }
+            let static_str = [
+                TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("static", call_site_span)),
+                TokenTree::Ident(Ident::new("str", call_site_span)),
+            ];
+            let attr_pair = |name: &str| {
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Literal(Literal::string(name)),
+                        TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("self", call_site_span)),
+                        TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("as_str", call_site_span)),
+                        TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+                    ]
+                    .into_iter()
+                    .collect(),
+                ))
+            };
+            inner.extend([
+                TokenTree::Ident(Ident::new("fn", call_site_span)),
+                TokenTree::Ident(Ident::new("attrs_with_testid", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("self", call_site_span)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+                TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                // [(&'static str, &'static str); 2]
+                TokenTree::Group(Group::new(
+                    Delimiter::Bracket,
+                    [
+                        TokenTree::Group(Group::new(
+                            Delimiter::Parenthesis,
+                            static_str
+                                .iter()
+                                .cloned()
+                                .chain([TokenTree::Punct(Punct::new(',', Spacing::Alone))])
+                                .chain(static_str.iter().cloned())
+                                .collect(),
+                        )),
+                        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                        TokenTree::Literal(Literal::usize_unsuffixed(2)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+                TokenTree::Group(Group::new(
+                    Delimiter::Brace,
+                    TokenStream::from(TokenTree::Group(Group::new(
+                        Delimiter::Bracket,
+                        [
+                            attr_pair("id"),
+                            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                            attr_pair("data-testid"),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    ))),
+                )),
+            ]);
+        }
+
+        // ALL and PAIRS constants
+        if let Some(vis) = &vis {
+            inner.extend(vis.clone());
+        }
+        inner.extend([
+            TokenTree::Ident(Ident::new("const", call_site_span)),
+            TokenTree::Ident(Ident::new("ALL", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+            TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+            TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("static", call_site_span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Bracket,
+                TokenStream::from(TokenTree::Ident(Ident::new("Self", call_site_span))),
+            )),
+            TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+            TokenTree::Punct(Punct::new('&', Spacing::Alone)),
+            TokenTree::Group(Group::new(Delimiter::Bracket, {
+                let mut inner: Vec<TokenTree> = Vec::with_capacity(ids_length * 5);
+                for i in &ordered_indexes {
+                    inner.extend(ids_cfg_attributes[*i].clone());
+                    inner.extend([
+                        TokenTree::Ident(Ident::new("Self", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(ids_variants_idents[*i].clone()),
+                        TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                    ]);
+                }
+                inner.into_iter().collect()
+            })),
+            TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+        ]);
+
+        for (cfg, profile_ids, profile_sorted_indexes) in &profiles {
+            for (name, indexes) in [
+                ("PAIRS", &ordered_indexes),
+                ("SORTED_NAMES", profile_sorted_indexes),
+            ] {
+                inner.extend(cfg.clone());
+                if let Some(vis) = &vis {
+                    inner.extend(vis.clone());
+                }
+                inner.extend([
+                    TokenTree::Ident(Ident::new("const", call_site_span)),
+                    TokenTree::Ident(Ident::new(name, call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                    TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("static", call_site_span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Bracket,
+                        [TokenTree::Group(Group::new(
+                            Delimiter::Parenthesis,
+                            [
+                                TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+                                TokenTree::Ident(Ident::new("static", call_site_span)),
+                                TokenTree::Ident(Ident::new("str", call_site_span)),
+                                TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("Self", call_site_span)),
+                            ]
+                            .into_iter()
+                            .collect(),
+                        ))]
+                        .into_iter()
+                        .collect(),
+                    )),
+                    TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                    TokenTree::Punct(Punct::new('&', Spacing::Alone)),
+                    TokenTree::Group(Group::new(Delimiter::Bracket, {
+                        let mut inner: Vec<TokenTree> = Vec::with_capacity(indexes.len() * 2);
+                        for i in indexes {
+                            inner.extend(ids_cfg_attributes[*i].clone());
+                            inner.extend([
+                                TokenTree::Group(Group::new(
+                                    Delimiter::Parenthesis,
+                                    [
+                                        TokenTree::Literal(Literal::string(&profile_ids[*i])),
+                                        TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                                        TokenTree::Ident(Ident::new("Self", call_site_span)),
+                                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                        TokenTree::Ident(ids_variants_idents[*i].clone()),
+                                    ]
+                                    .into_iter()
+                                    .collect(),
+                                )),
+                                TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                            ]);
+                        }
+                        inner.into_iter().collect()
+                    })),
+                    TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                ]);
+            }
+
+            // ALL_SELECTORS constant, joined here in the order of `ALL`, or at
+            // compile time when some identifiers are conditionally compiled
+            let selectors = if ids_cfgs.iter().any(Option::is_some) {
+                let mut parts: Vec<TokenTree> = Vec::with_capacity(ids_length * 4);
+                for i in &ordered_indexes {
+                    let mut selector = String::from('#');
+                    push_css_escaped(&mut selector, &profile_ids[*i]);
+                    parts.extend(ids_cfg_attributes[*i].clone());
+                    parts.extend([
+                        TokenTree::Literal(Literal::string(&selector)),
+                        TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                    ]);
+                }
+                joined_selectors_block(parts.into_iter().collect(), call_site_span)
+            } else {
+                let mut selectors = String::new();
+                for i in &ordered_indexes {
+                    if !selectors.is_empty() {
+                        selectors.push(',');
+                    }
+                    selectors.push('#');
+                    push_css_escaped(&mut selectors, &profile_ids[*i]);
+                }
+                TokenTree::Literal(Literal::string(&selectors))
+            };
+            inner.extend(cfg.clone());
+            if let Some(vis) = &vis {
+                inner.extend(vis.clone());
+            }
+            inner.extend([
+                TokenTree::Ident(Ident::new("const", call_site_span)),
+                TokenTree::Ident(Ident::new("ALL_SELECTORS", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("static", call_site_span)),
+                TokenTree::Ident(Ident::new("str", call_site_span)),
+                TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                selectors,
+                TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+            ]);
+        }
+
+        // from_str_sorted method
+        if let Some(vis) = &vis {
+            inner.extend(vis.clone());
+        }
+        inner.extend([
+            TokenTree::Ident(Ident::new("fn", call_site_span)),
+            TokenTree::Ident(Ident::new("from_str_sorted", call_site_span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Parenthesis,
+                [
+                    TokenTree::Ident(Ident::new("name", call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                    TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("str", call_site_span)),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+            TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("core", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("option", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("Option", call_site_span)),
+            TokenTree::Punct(Punct::new('<', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("Self", call_site_span)),
+            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+            TokenTree::Group(Group::new(
+                Delimiter::Brace,
+                [
+                    TokenTree::Ident(Ident::new("match", call_site_span)),
+                    // Self::SORTED_NAMES.binary_search_by(|(id, _)| (*id).cmp(name))
+                    TokenTree::Ident(Ident::new("Self", call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("SORTED_NAMES", call_site_span)),
+                    TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("binary_search_by", call_site_span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        [
+                            TokenTree::Punct(Punct::new('|', Spacing::Alone)),
+                            TokenTree::Group(Group::new(
+                                Delimiter::Parenthesis,
+                                [
+                                    TokenTree::Ident(Ident::new("id", call_site_span)),
+                                    TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                                    TokenTree::Ident(Ident::new("_", call_site_span)),
+                                ]
+                                .into_iter()
+                                .collect(),
+                            )),
+                            TokenTree::Punct(Punct::new('|', Spacing::Alone)),
+                            TokenTree::Group(Group::new(
+                                Delimiter::Parenthesis,
+                                [
+                                    TokenTree::Punct(Punct::new('*', Spacing::Alone)),
+                                    TokenTree::Ident(Ident::new("id", call_site_span)),
+                                ]
+                                .into_iter()
+                                .collect(),
+                            )),
+                            TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("cmp", call_site_span)),
+                            TokenTree::Group(Group::new(
+                                Delimiter::Parenthesis,
+                                TokenStream::from(TokenTree::Ident(Ident::new(
+                                    "name",
+                                    call_site_span,
+                                ))),
+                            )),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                    TokenTree::Group(Group::new(Delimiter::Brace, {
+                        let mut inner: Vec<TokenTree> =
+                            Vec::with_capacity(profiles.len() * ids_length * 10);
+                        for (cfg, _, profile_sorted_indexes) in &profiles {
+                            for (position, i) in profile_sorted_indexes.iter().enumerate() {
+                                let (pattern, guard) = position_pattern(
+                                    position_expression(
+                                        profile_sorted_indexes[..position]
+                                            .iter()
+                                            .map(|j| ids_cfgs[*j].as_ref()),
+                                        call_site_span,
+                                    ),
+                                    call_site_span,
+                                );
+                                inner.extend(cfg.clone());
+                                inner.extend(ids_cfg_attributes[*i].clone());
+                                inner.extend([
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Ident(Ident::new("core", call_site_span)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Ident(Ident::new("result", call_site_span)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Ident(Ident::new("Result", call_site_span)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Ident(Ident::new("Ok", call_site_span)),
+                                    TokenTree::Group(Group::new(Delimiter::Parenthesis, pattern)),
+                                ]);
+                                inner.extend(guard);
+                                inner.extend([
+                                    TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Ident(Ident::new("core", call_site_span)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Ident(Ident::new("option", call_site_span)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Ident(Ident::new("Option", call_site_span)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Ident(Ident::new("Some", call_site_span)),
+                                    TokenTree::Group(Group::new(
+                                        Delimiter::Parenthesis,
+                                        [
+                                            TokenTree::Ident(Ident::new("Self", call_site_span)),
+                                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                            TokenTree::Ident(ids_variants_idents[*i].clone()),
+                                        ]
+                                        .into_iter()
+                                        .collect(),
+                                    )),
+                                    TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                                ]);
+                            }
+                        }
+                        inner.extend([
+                            TokenTree::Ident(Ident::new("_", call_site_span)),
+                            TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("core", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("option", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("Option", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("None", call_site_span)),
+                            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                        ]);
+                        inner.into_iter().collect()
+                    })),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+        ]);
+
+        // contains method
+        if let Some(vis) = &vis {
+            inner.extend(vis.clone());
+        }
+        inner.extend([
+            TokenTree::Ident(Ident::new("fn", call_site_span)),
+            TokenTree::Ident(Ident::new("contains", call_site_span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Parenthesis,
+                [
+                    TokenTree::Ident(Ident::new("name", call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                    TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("str", call_site_span)),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+            TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("bool", call_site_span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Brace,
+                [
+                    TokenTree::Ident(Ident::new("match", call_site_span)),
+                    TokenTree::Ident(Ident::new("name", call_site_span)),
+                    TokenTree::Group(Group::new(Delimiter::Brace, {
+                        let mut inner: Vec<TokenTree> =
+                            Vec::with_capacity(profiles.len() * ids_length * 2);
+                        // "foo" | "bar" => true, with an arm for each conditionally
+                        // compiled identifier, because patterns can't have attributes
+                        for (cfg, profile_ids, _) in &profiles {
+                            let mut alternatives: Vec<TokenTree> =
+                                Vec::with_capacity(ids_length * 2);
+                            for (id, predicate) in profile_ids.iter().zip(&ids_cfgs) {
+                                if predicate.is_some() {
+                                    continue;
+                                }
+                                if !alternatives.is_empty() {
+                                    alternatives
+                                        .push(TokenTree::Punct(Punct::new('|', Spacing::Alone)));
+                                }
+                                alternatives.push(TokenTree::Literal(Literal::string(id)));
+                            }
+                            let arms = std::iter::once((TokenStream::new(), alternatives)).chain(
+                                profile_ids
+                                    .iter()
+                                    .zip(&ids_cfg_attributes)
+                                    .filter(|(_, cfg_attribute)| !cfg_attribute.is_empty())
+                                    .map(|(id, cfg_attribute)| {
+                                        (
+                                            cfg_attribute.clone(),
+                                            vec![TokenTree::Literal(Literal::string(id))],
+                                        )
+                                    }),
+                            );
+                            for (cfg_attribute, pattern) in arms {
+                                if pattern.is_empty() {
+                                    continue;
+                                }
+                                inner.extend(cfg.clone());
+                                inner.extend(cfg_attribute);
+                                inner.extend(pattern);
+                                inner.extend([
+                                    TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                                    TokenTree::Ident(Ident::new("true", call_site_span)),
+                                    TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                                ]);
+                            }
+                        }
+                        inner.extend([
+                            TokenTree::Ident(Ident::new("_", call_site_span)),
+                            TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("false", call_site_span)),
+                            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                        ]);
+                        inner.into_iter().collect()
+                    })),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+        ]);
+
+        // variants_count method
+        if let Some(vis) = &vis {
+            inner.extend(vis.clone());
+        }
+        inner.extend([
+            TokenTree::Ident(Ident::new("const", call_site_span)),
+            TokenTree::Ident(Ident::new("fn", call_site_span)),
+            TokenTree::Ident(Ident::new("variants_count", call_site_span)),
+            TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+            TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("usize", call_site_span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Brace,
+                position_expression(ids_cfgs.iter().map(Option::as_ref), call_site_span),
+            )),
+        ]);
+
+        // as_array method, returning the identifiers in the order of `ALL`
+        for (cfg, profile_ids, _) in &profiles {
+            inner.extend(cfg.clone());
+            inner.extend(must_use_attribute(call_site_span));
+            if let Some(vis) = &vis {
+                inner.extend(vis.clone());
+            }
+            inner.extend([
+                TokenTree::Ident(Ident::new("const", call_site_span)),
+                TokenTree::Ident(Ident::new("fn", call_site_span)),
+                TokenTree::Ident(Ident::new("as_array", call_site_span)),
+                TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+                TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Bracket,
+                    [
+                        TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("static", call_site_span)),
+                        TokenTree::Ident(Ident::new("str", call_site_span)),
+                        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                    ]
+                    .into_iter()
+                    .chain(position_expression(
+                        ids_cfgs.iter().map(Option::as_ref),
+                        call_site_span,
+                    ))
+                    .collect(),
+                )),
+                TokenTree::Group(Group::new(
+                    Delimiter::Brace,
+                    TokenStream::from(TokenTree::Group(Group::new(Delimiter::Bracket, {
+                        let mut inner: Vec<TokenTree> = Vec::with_capacity(ids_length * 2);
+                        for i in &ordered_indexes {
+                            inner.extend(ids_cfg_attributes[*i].clone());
+                            inner.extend([
+                                TokenTree::Literal(Literal::string(&profile_ids[*i])),
+                                TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                            ]);
+                        }
+                        inner.into_iter().collect()
+                    }))),
+                )),
+            ]);
+        }
+
+        // all method
+        if let Some(vis) = &vis {
+            inner.extend(vis.clone());
+        }
+        inner.extend([
+            TokenTree::Ident(Ident::new("const", call_site_span)),
+            TokenTree::Ident(Ident::new("fn", call_site_span)),
+            TokenTree::Ident(Ident::new("all", call_site_span)),
+            TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+            TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+            TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+            TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("static", call_site_span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Bracket,
+                TokenStream::from(TokenTree::Ident(Ident::new("Self", call_site_span))),
+            )),
+            TokenTree::Group(Group::new(
+                Delimiter::Brace,
+                [
+                    TokenTree::Ident(Ident::new("Self", call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("ALL", call_site_span)),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+        ]);
+
+        // iter method, yielding the variants in the order of ALL
+        if let Some(vis) = &vis {
+            inner.extend(vis.clone());
+        }
+        inner.extend([
+            TokenTree::Ident(Ident::new("fn", call_site_span)),
+            TokenTree::Ident(Ident::new("iter", call_site_span)),
+            TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+            TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("impl", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("core", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("iter", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("Iterator", call_site_span)),
+            TokenTree::Punct(Punct::new('<', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("Item", call_site_span)),
+            TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("Self", call_site_span)),
+            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+            TokenTree::Group(Group::new(
+                Delimiter::Brace,
+                [
+                    TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        [
+                            TokenTree::Literal(Literal::usize_unsuffixed(0)),
+                            TokenTree::Punct(Punct::new('.', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("Self", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("ALL", call_site_span)),
+                            TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("len", call_site_span)),
+                            TokenTree::Group(Group::new(
+                                Delimiter::Parenthesis,
+                                TokenStream::new(),
+                            )),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                    TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("filter_map", call_site_span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        [
+                            TokenTree::Ident(Ident::new("Self", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("from_index", call_site_span)),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+        ]);
+
+        // index method
+        inner.extend(must_use_attribute(call_site_span));
+        if let Some(vis) = &vis {
+            inner.extend(vis.clone());
+        }
+        inner.extend([
+            TokenTree::Ident(Ident::new("const", call_site_span)),
+            TokenTree::Ident(Ident::new("fn", call_site_span)),
+            TokenTree::Ident(Ident::new("index", call_site_span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Parenthesis,
+                [
+                    TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("self", call_site_span)),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+            TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("usize", call_site_span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Brace,
+                [
+                    TokenTree::Ident(Ident::new("match", call_site_span)),
+                    TokenTree::Punct(Punct::new('*', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("self", call_site_span)),
+                    TokenTree::Group(Group::new(Delimiter::Brace, {
+                        let mut inner: Vec<TokenTree> = Vec::with_capacity(ids_length * 8);
+                        for (position, i) in ordered_indexes.iter().enumerate() {
+                            inner.extend(ids_cfg_attributes[*i].clone());
+                            inner.extend([
+                                TokenTree::Ident(Ident::new("Self", call_site_span)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Ident(ids_variants_idents[*i].clone()),
+                                TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                            ]);
+                            inner.extend(position_expression(
+                                ordered_indexes[..position]
+                                    .iter()
+                                    .map(|j| ids_cfgs[*j].as_ref()),
+                                call_site_span,
+                            ));
+                            inner.extend([TokenTree::Punct(Punct::new(',', Spacing::Alone))]);
+                        }
+                        inner.into_iter().collect()
+                    })),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+        ]);
+
+        // from_index method
+        if let Some(vis) = &vis {
+            inner.extend(vis.clone());
+        }
+        inner.extend([
+            TokenTree::Ident(Ident::new("const", call_site_span)),
+            TokenTree::Ident(Ident::new("fn", call_site_span)),
+            TokenTree::Ident(Ident::new("from_index", call_site_span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Parenthesis,
+                [
+                    TokenTree::Ident(Ident::new("index", call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("usize", call_site_span)),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+            TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("core", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("option", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("Option", call_site_span)),
+            TokenTree::Punct(Punct::new('<', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("Self", call_site_span)),
+            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+            TokenTree::Group(Group::new(
+                Delimiter::Brace,
+                [
+                    TokenTree::Ident(Ident::new("match", call_site_span)),
+                    TokenTree::Ident(Ident::new("index", call_site_span)),
+                    TokenTree::Group(Group::new(Delimiter::Brace, {
+                        let mut inner: Vec<TokenTree> = Vec::with_capacity(ids_length * 6);
+                        for (position, i) in ordered_indexes.iter().enumerate() {
+                            let (pattern, guard) = position_pattern(
+                                position_expression(
+                                    ordered_indexes[..position]
+                                        .iter()
+                                        .map(|j| ids_cfgs[*j].as_ref()),
+                                    call_site_span,
+                                ),
+                                call_site_span,
+                            );
+                            inner.extend(ids_cfg_attributes[*i].clone());
+                            inner.extend(pattern);
+                            inner.extend(guard);
+                            inner.extend([
+                                TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Ident(Ident::new("core", call_site_span)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Ident(Ident::new("option", call_site_span)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Ident(Ident::new("Option", call_site_span)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Ident(Ident::new("Some", call_site_span)),
+                                TokenTree::Group(Group::new(
+                                    Delimiter::Parenthesis,
+                                    [
+                                        TokenTree::Ident(Ident::new("Self", call_site_span)),
+                                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                        TokenTree::Ident(ids_variants_idents[*i].clone()),
+                                    ]
+                                    .into_iter()
+                                    .collect(),
+                                )),
+                                TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                            ]);
+                        }
+                        inner.extend([
+                            TokenTree::Ident(Ident::new("_", call_site_span)),
+                            TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("core", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("option", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("Option", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("None", call_site_span)),
+                            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                        ]);
+                        inner.into_iter().collect()
+                    })),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+        ]);
+
+        // next and prev methods, cycling in declaration order
+        for (name, offset) in [("next", 1), ("prev", ids_length.saturating_sub(1))] {
+            if let Some(vis) = &vis {
+                inner.extend(vis.clone());
+            }
+            inner.extend([
+                TokenTree::Ident(Ident::new("const", call_site_span)),
+                TokenTree::Ident(Ident::new("fn", call_site_span)),
+                TokenTree::Ident(Ident::new(name, call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("self", call_site_span)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+                TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("Self", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Brace,
+                    [
+                        TokenTree::Ident(Ident::new("match", call_site_span)),
+                        TokenTree::Punct(Punct::new('*', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("self", call_site_span)),
+                        TokenTree::Group(Group::new(Delimiter::Brace, {
+                            let mut inner: Vec<TokenTree> = Vec::with_capacity(ids_length * 11);
+                            for (i, ident) in ids_variants_idents.iter().enumerate() {
+                                // the following variants are skipped while they are
+                                // conditionally compiled and their `cfg` doesn't hold,
+                                // emitting an arm for each of them
+                                let mut skipped: Vec<&TokenStream> = Vec::new();
+                                for step in 1..=ids_length {
+                                    let other = (i + step * offset) % ids_length;
+                                    let predicate = ids_cfgs[other].as_ref().filter(|_| other != i);
+                                    inner.extend(ids_cfg_attributes[i].clone());
+                                    if predicate.is_some() || !skipped.is_empty() {
+                                        inner.extend(cfg_chain_attribute(
+                                            &skipped,
+                                            predicate,
+                                            call_site_span,
+                                        ));
+                                    }
+                                    inner.extend([
+                                        TokenTree::Ident(Ident::new("Self", call_site_span)),
+                                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                        TokenTree::Ident(ident.clone()),
+                                        TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                                        TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                                        TokenTree::Ident(Ident::new("Self", call_site_span)),
+                                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                        TokenTree::Ident(ids_variants_idents[other].clone()),
+                                        TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                                    ]);
+                                    let Some(predicate) = predicate else {
+                                        break;
+                                    };
+                                    skipped.push(predicate);
+                                }
+                            }
+                            inner.into_iter().collect()
+                        })),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+            ]);
+        }
+
+        // as_u16 and from_u16 methods
+        if repr_u16 {
+            inner.extend(must_use_attribute(call_site_span));
+            if let Some(vis) = &vis {
+                inner.extend(vis.clone());
+            }
+            inner.extend([
+                TokenTree::Ident(Ident::new("const", call_site_span)),
+                TokenTree::Ident(Ident::new("fn", call_site_span)),
+                TokenTree::Ident(Ident::new("as_u16", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("self", call_site_span)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+                TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("u16", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Brace,
+                    [
+                        TokenTree::Ident(Ident::new("match", call_site_span)),
+                        TokenTree::Punct(Punct::new('*', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("self", call_site_span)),
+                        TokenTree::Group(Group::new(Delimiter::Brace, {
+                            let mut inner: Vec<TokenTree> = Vec::with_capacity(ids_length * 8);
+                            for ((i, ident), cfg_attribute) in (0..=u16::MAX)
+                                .zip(&ids_variants_idents)
+                                .zip(&ids_cfg_attributes)
+                            {
+                                inner.extend(cfg_attribute.clone());
+                                inner.extend([
+                                    TokenTree::Ident(Ident::new("Self", call_site_span)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Ident(ident.clone()),
+                                    TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                                    TokenTree::Literal(Literal::u16_unsuffixed(i)),
+                                    TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                                ]);
+                            }
+                            inner.into_iter().collect()
+                        })),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+            ]);
+
+            if let Some(vis) = &vis {
+                inner.extend(vis.clone());
+            }
+            inner.extend([
+                TokenTree::Ident(Ident::new("const", call_site_span)),
+                TokenTree::Ident(Ident::new("fn", call_site_span)),
+                TokenTree::Ident(Ident::new("from_u16", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Ident(Ident::new("value", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("u16", call_site_span)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+                TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("core", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("option", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("Option", call_site_span)),
+                TokenTree::Punct(Punct::new('<', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("Self", call_site_span)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Brace,
+                    [
+                        TokenTree::Ident(Ident::new("match", call_site_span)),
+                        TokenTree::Ident(Ident::new("value", call_site_span)),
+                        TokenTree::Group(Group::new(Delimiter::Brace, {
+                            let mut inner: Vec<TokenTree> = Vec::with_capacity(ids_length * 11);
+                            for ((i, ident), cfg_attribute) in (0..=u16::MAX)
+                                .zip(&ids_variants_idents)
+                                .zip(&ids_cfg_attributes)
+                            {
+                                inner.extend(cfg_attribute.clone());
+                                inner.extend([
+                                    TokenTree::Literal(Literal::u16_unsuffixed(i)),
+                                    TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Ident(Ident::new("core", call_site_span)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Ident(Ident::new("option", call_site_span)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Ident(Ident::new("Option", call_site_span)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Ident(Ident::new("Some", call_site_span)),
+                                    TokenTree::Group(Group::new(
+                                        Delimiter::Parenthesis,
+                                        [
+                                            TokenTree::Ident(Ident::new("Self", call_site_span)),
+                                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                            TokenTree::Ident(ident.clone()),
+                                        ]
+                                        .into_iter()
+                                        .collect(),
+                                    )),
+                                    TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                                ]);
+                            }
+                            inner.extend([
+                                TokenTree::Ident(Ident::new("_", call_site_span)),
+                                TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Ident(Ident::new("core", call_site_span)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Ident(Ident::new("option", call_site_span)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Ident(Ident::new("Option", call_site_span)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Ident(Ident::new("None", call_site_span)),
+                                TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                            ]);
+                            inner.into_iter().collect()
+                        })),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+            ]);
+        }
+
+        // get_element method
+        #[cfg(feature = "web-sys")]
+        {
+            // only available in the browser
+            inner.extend([
+                TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Bracket,
+                    [
+                        TokenTree::Ident(Ident::new("cfg", call_site_span)),
+                        TokenTree::Group(Group::new(
+                            Delimiter::Parenthesis,
+                            [
+                                TokenTree::Ident(Ident::new("target_arch", call_site_span)),
+                                TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                                TokenTree::Literal(Literal::string("wasm32")),
+                            ]
+                            .into_iter()
+                            .collect(),
+                        )),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+            ]);
+            if let Some(vis) = &vis {
+                inner.extend(vis.clone());
+            }
+            inner.extend([
+                TokenTree::Ident(Ident::new("fn", call_site_span)),
+                TokenTree::Ident(Ident::new("get_element", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("self", call_site_span)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+                TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("core", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("option", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("Option", call_site_span)),
+                TokenTree::Punct(Punct::new('<', Spacing::Alone)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("web_sys", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("Element", call_site_span)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Brace,
+                    [
+                        // ::web_sys::window()
+                        //     .and_then(|window| window.document())
+                        //     .and_then(|document| document.get_element_by_id(self.as_str()))
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("web_sys", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("window", call_site_span)),
+                        TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+                        TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("and_then", call_site_span)),
+                        TokenTree::Group(Group::new(
+                            Delimiter::Parenthesis,
+                            [
+                                TokenTree::Punct(Punct::new('|', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("window", call_site_span)),
+                                TokenTree::Punct(Punct::new('|', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("window", call_site_span)),
+                                TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("document", call_site_span)),
+                                TokenTree::Group(Group::new(
+                                    Delimiter::Parenthesis,
+                                    TokenStream::new(),
+                                )),
+                            ]
+                            .into_iter()
+                            .collect(),
+                        )),
+                        TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("and_then", call_site_span)),
+                        TokenTree::Group(Group::new(
+                            Delimiter::Parenthesis,
+                            [
+                                TokenTree::Punct(Punct::new('|', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("document", call_site_span)),
+                                TokenTree::Punct(Punct::new('|', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("document", call_site_span)),
+                                TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("get_element_by_id", call_site_span)),
+                                TokenTree::Group(Group::new(
+                                    Delimiter::Parenthesis,
+                                    [
+                                        TokenTree::Ident(Ident::new("self", call_site_span)),
+                                        TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                                        TokenTree::Ident(Ident::new("as_str", call_site_span)),
+                                        TokenTree::Group(Group::new(
+                                            Delimiter::Parenthesis,
+                                            TokenStream::new(),
+                                        )),
+                                    ]
+                                    .into_iter()
+                                    .collect(),
+                                )),
+                            ]
+                            .into_iter()
+                            .collect(),
+                        )),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+            ]);
+        }
+
+        // assert_unique_in_dom method
+        #[cfg(feature = "runtime-check")]
+        {
+            if let Some(vis) = &vis {
+                inner.extend(vis.clone());
+            }
+            inner.extend([
+                TokenTree::Ident(Ident::new("fn", call_site_span)),
+                TokenTree::Ident(Ident::new("assert_unique_in_dom", call_site_span)),
+                TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+                TokenTree::Group(Group::new(
+                    Delimiter::Brace,
+                    [
+                        // the DOM is only available in the browser
+                        TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+                        TokenTree::Group(Group::new(
+                            Delimiter::Bracket,
+                            [
+                                TokenTree::Ident(Ident::new("cfg", call_site_span)),
+                                TokenTree::Group(Group::new(
+                                    Delimiter::Parenthesis,
+                                    [
+                                        TokenTree::Ident(Ident::new(
+                                            "target_arch",
+                                            call_site_span,
+                                        )),
+                                        TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                                        TokenTree::Literal(Literal::string("wasm32")),
+                                    ]
+                                    .into_iter()
+                                    .collect(),
+                                )),
+                            ]
+                            .into_iter()
+                            .collect(),
+                        )),
+                        TokenTree::Group(Group::new(
+                            Delimiter::Brace,
+                            [
+                                // let document = ::leptos::prelude::document();
+                                TokenTree::Ident(Ident::new("let", call_site_span)),
+                                TokenTree::Ident(Ident::new("document", call_site_span)),
+                                TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Ident(Ident::new("leptos", call_site_span)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Ident(Ident::new("prelude", call_site_span)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Ident(Ident::new("document", call_site_span)),
+                                TokenTree::Group(Group::new(
+                                    Delimiter::Parenthesis,
+                                    TokenStream::new(),
+                                )),
+                                TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                                // for variant in Self::ALL
+                                TokenTree::Ident(Ident::new("for", call_site_span)),
+                                TokenTree::Ident(Ident::new("variant", call_site_span)),
+                                TokenTree::Ident(Ident::new("in", call_site_span)),
+                                TokenTree::Ident(Ident::new("Self", call_site_span)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                TokenTree::Ident(Ident::new("ALL", call_site_span)),
+                                TokenTree::Group(Group::new(
+                                    Delimiter::Brace,
+                                    [
+                                        // let id = variant.as_str();
+                                        TokenTree::Ident(Ident::new("let", call_site_span)),
+                                        TokenTree::Ident(Ident::new("id", call_site_span)),
+                                        TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                                        TokenTree::Ident(Ident::new("variant", call_site_span)),
+                                        TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                                        TokenTree::Ident(Ident::new("as_str", call_site_span)),
+                                        TokenTree::Group(Group::new(
+                                            Delimiter::Parenthesis,
+                                            TokenStream::new(),
+                                        )),
+                                        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                                        // let count = document
+                                        //     .query_selector_all(&format!("[id=\"{id}\"]"))
+                                        //     .map_or(0, |nodes| nodes.length());
+                                        TokenTree::Ident(Ident::new("let", call_site_span)),
+                                        TokenTree::Ident(Ident::new("count", call_site_span)),
+                                        TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                                        TokenTree::Ident(Ident::new("document", call_site_span)),
+                                        TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                                        TokenTree::Ident(Ident::new(
+                                            "query_selector_all",
+                                            call_site_span,
+                                        )),
+                                        TokenTree::Group(Group::new(
+                                            Delimiter::Parenthesis,
+                                            [
+                                                TokenTree::Punct(Punct::new('&', Spacing::Alone)),
+                                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                                TokenTree::Ident(Ident::new(
+                                                    "std",
+                                                    call_site_span,
+                                                )),
+                                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                                TokenTree::Ident(Ident::new(
+                                                    "format",
+                                                    call_site_span,
+                                                )),
+                                                TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+                                                TokenTree::Group(Group::new(
+                                                    Delimiter::Parenthesis,
+                                                    TokenStream::from(TokenTree::Literal(
+                                                        Literal::string("[id=\"{id}\"]"),
+                                                    )),
+                                                )),
+                                            ]
+                                            .into_iter()
+                                            .collect(),
+                                        )),
+                                        TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                                        TokenTree::Ident(Ident::new("map_or", call_site_span)),
+                                        TokenTree::Group(Group::new(
+                                            Delimiter::Parenthesis,
+                                            [
+                                                TokenTree::Literal(Literal::u32_unsuffixed(0)),
+                                                TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                                                TokenTree::Punct(Punct::new('|', Spacing::Alone)),
+                                                TokenTree::Ident(Ident::new(
+                                                    "nodes",
+                                                    call_site_span,
+                                                )),
+                                                TokenTree::Punct(Punct::new('|', Spacing::Alone)),
+                                                TokenTree::Ident(Ident::new(
+                                                    "nodes",
+                                                    call_site_span,
+                                                )),
+                                                TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                                                TokenTree::Ident(Ident::new(
+                                                    "length",
+                                                    call_site_span,
+                                                )),
+                                                TokenTree::Group(Group::new(
+                                                    Delimiter::Parenthesis,
+                                                    TokenStream::new(),
+                                                )),
+                                            ]
+                                            .into_iter()
+                                            .collect(),
+                                        )),
+                                        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                                        // assert!(count <= 1, "...");
+                                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                        TokenTree::Ident(Ident::new("std", call_site_span)),
+                                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                        TokenTree::Ident(Ident::new("assert", call_site_span)),
+                                        TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+                                        TokenTree::Group(Group::new(
+                                            Delimiter::Parenthesis,
+                                            [
+                                                TokenTree::Ident(Ident::new(
+                                                    "count",
+                                                    call_site_span,
+                                                )),
+                                                TokenTree::Punct(Punct::new('<', Spacing::Joint)),
+                                                TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                                                TokenTree::Literal(Literal::u32_unsuffixed(1)),
+                                                TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                                                TokenTree::Literal(Literal::string(
+                                                    "Identifier {id:?} is used by {count} elements in the DOM.",
+                                                )),
+                                            ]
+                                            .into_iter()
+                                            .collect(),
+                                        )),
+                                        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                                    ]
+                                    .into_iter()
+                                    .collect(),
+                                )),
+                            ]
+                            .into_iter()
+                            .collect(),
+                        )),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+            ]);
+        }
+
+        // write_to method
+        if let Some(vis) = &vis {
+            inner.extend(vis.clone());
+        }
+        inner.extend([
+            TokenTree::Ident(Ident::new("fn", call_site_span)),
+            TokenTree::Ident(Ident::new("write_to", call_site_span)),
+            TokenTree::Punct(Punct::new('<', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("W", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("core", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("fmt", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("Write", call_site_span)),
+            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+            TokenTree::Group(Group::new(
+                Delimiter::Parenthesis,
+                [
+                    TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("self", call_site_span)),
+                    TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("w", call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                    TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("mut", call_site_span)),
+                    TokenTree::Ident(Ident::new("W", call_site_span)),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+            TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("core", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("fmt", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("Result", call_site_span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Brace,
+                [
+                    // w.write_str(self.as_str())
+                    TokenTree::Ident(Ident::new("w", call_site_span)),
+                    TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("write_str", call_site_span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        [
+                            TokenTree::Ident(Ident::new("self", call_site_span)),
+                            TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("as_str", call_site_span)),
+                            TokenTree::Group(Group::new(
+                                Delimiter::Parenthesis,
+                                TokenStream::new(),
+                            )),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+        ]);
+
+        // matches_selector method
+        inner.extend(must_use_attribute(call_site_span));
+        if let Some(vis) = &vis {
+            inner.extend(vis.clone());
+        }
+        inner.extend([
+            TokenTree::Ident(Ident::new("fn", call_site_span)),
+            TokenTree::Ident(Ident::new("matches_selector", call_site_span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Parenthesis,
+                [
+                    TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("self", call_site_span)),
+                    TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("sel", call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                    TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("str", call_site_span)),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+            TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("bool", call_site_span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Brace,
+                [
+                    // let id = self.as_str().as_bytes();
+                    TokenTree::Ident(Ident::new("let", call_site_span)),
+                    TokenTree::Ident(Ident::new("id", call_site_span)),
+                    TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("self", call_site_span)),
+                    TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("as_str", call_site_span)),
+                    TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+                    TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("as_bytes", call_site_span)),
+                    TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+                    TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                    // let sel = sel.as_bytes();
+                    TokenTree::Ident(Ident::new("let", call_site_span)),
+                    TokenTree::Ident(Ident::new("sel", call_site_span)),
+                    TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("sel", call_site_span)),
+                    TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("as_bytes", call_site_span)),
+                    TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+                    TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                    // sel == id || sel.strip_prefix(b"#") == Some(id)
+                    TokenTree::Ident(Ident::new("sel", call_site_span)),
+                    TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("id", call_site_span)),
+                    TokenTree::Punct(Punct::new('|', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new('|', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("sel", call_site_span)),
+                    TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("strip_prefix", call_site_span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        TokenStream::from(TokenTree::Literal(Literal::byte_string(b"#"))),
+                    )),
+                    TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("core", call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("option", call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("Option", call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("Some", call_site_span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        TokenStream::from(TokenTree::Ident(Ident::new("id", call_site_span))),
+                    )),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+        ]);
+
+        // as_selector method
+        #[cfg(feature = "selector")]
+        {
+            inner.extend(must_use_attribute(call_site_span));
+            if let Some(vis) = &vis {
+                inner.extend(vis.clone());
+            }
+            inner.extend([
+                TokenTree::Ident(Ident::new("fn", call_site_span)),
+                TokenTree::Ident(Ident::new("as_selector", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Punct(Punct::new('&', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("self", call_site_span)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+                TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Ident(Ident::new(&selector_name, call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Brace,
+                    [
+                        TokenTree::Ident(Ident::new(&selector_name, call_site_span)),
+                        TokenTree::Group(Group::new(
+                            Delimiter::Parenthesis,
+                            [
+                                TokenTree::Ident(Ident::new("self", call_site_span)),
+                                TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("as_str", call_site_span)),
+                                TokenTree::Group(Group::new(
+                                    Delimiter::Parenthesis,
+                                    TokenStream::new(),
+                                )),
+                            ]
+                            .into_iter()
+                            .collect(),
+                        )),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+            ]);
+        }
+
+        // assert_all_unique method
+        if let Some(vis) = &vis {
+            inner.extend(vis.clone());
+        }
+        inner.extend([
+            TokenTree::Ident(Ident::new("fn", call_site_span)),
+            TokenTree::Ident(Ident::new("assert_all_unique", call_site_span)),
+            TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+            TokenTree::Group(Group::new(
+                Delimiter::Brace,
+                [
+                    // for (i, variant) in Self::ALL.iter().enumerate()
+                    TokenTree::Ident(Ident::new("for", call_site_span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        [
+                            TokenTree::Ident(Ident::new("i", call_site_span)),
+                            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("variant", call_site_span)),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                    TokenTree::Ident(Ident::new("in", call_site_span)),
+                    TokenTree::Ident(Ident::new("Self", call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("ALL", call_site_span)),
+                    TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("iter", call_site_span)),
+                    TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+                    TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("enumerate", call_site_span)),
+                    TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Brace,
+                        [
+                            // let id = variant.as_str();
+                            TokenTree::Ident(Ident::new("let", call_site_span)),
+                            TokenTree::Ident(Ident::new("id", call_site_span)),
+                            TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("variant", call_site_span)),
+                            TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("as_str", call_site_span)),
+                            TokenTree::Group(Group::new(
+                                Delimiter::Parenthesis,
+                                TokenStream::new(),
+                            )),
+                            TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                            // for other in &Self::ALL[i + 1..]
+                            TokenTree::Ident(Ident::new("for", call_site_span)),
+                            TokenTree::Ident(Ident::new("other", call_site_span)),
+                            TokenTree::Ident(Ident::new("in", call_site_span)),
+                            TokenTree::Punct(Punct::new('&', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("Self", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("ALL", call_site_span)),
+                            TokenTree::Group(Group::new(
+                                Delimiter::Bracket,
+                                [
+                                    TokenTree::Ident(Ident::new("i", call_site_span)),
+                                    TokenTree::Punct(Punct::new('+', Spacing::Alone)),
+                                    TokenTree::Literal(Literal::usize_unsuffixed(1)),
+                                    TokenTree::Punct(Punct::new('.', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                                ]
+                                .into_iter()
+                                .collect(),
+                            )),
+                            TokenTree::Group(Group::new(
+                                Delimiter::Brace,
+                                [
+                                    // assert!(other.as_str() != id, "...");
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Ident(Ident::new("core", call_site_span)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Ident(Ident::new("assert", call_site_span)),
+                                    TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+                                    TokenTree::Group(Group::new(
+                                        Delimiter::Parenthesis,
+                                        [
+                                            TokenTree::Ident(Ident::new("other", call_site_span)),
+                                            TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                                            TokenTree::Ident(Ident::new("as_str", call_site_span)),
+                                            TokenTree::Group(Group::new(
+                                                Delimiter::Parenthesis,
+                                                TokenStream::new(),
+                                            )),
+                                            TokenTree::Punct(Punct::new('!', Spacing::Joint)),
+                                            TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                                            TokenTree::Ident(Ident::new("id", call_site_span)),
+                                            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                                            TokenTree::Literal(Literal::string(
+                                                "Identifier {id:?} is used by more than one variant.",
+                                            )),
+                                        ]
+                                        .into_iter()
+                                        .collect(),
+                                    )),
+                                    TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                                ]
+                                .into_iter()
+                                .collect(),
+                            )),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+        ]);
+
+        // deprecated constants aliasing the variants of renamed identifiers
+        for ((old, new, i, _), ident) in resolved_renames.iter().zip(&renamed_idents) {
+            let variant = &ids_variants_idents[*i];
+            inner.extend(ids_cfg_attributes[*i].clone());
+            inner.extend([
+                TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Bracket,
+                    [
+                        TokenTree::Ident(Ident::new("doc", call_site_span)),
+                        TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                        TokenTree::Literal(Literal::string(&format!(
+                            "Alias of [`{enum_name}::{variant}`] for the identifier {old:?}, which was renamed."
+                        ))),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+                TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Bracket,
+                    [
+                        TokenTree::Ident(Ident::new("deprecated", call_site_span)),
+                        TokenTree::Group(Group::new(
+                            Delimiter::Parenthesis,
+                            [
+                                TokenTree::Ident(Ident::new("note", call_site_span)),
+                                TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                                TokenTree::Literal(Literal::string(&format!(
+                                    "the identifier {old:?} was renamed to {new:?}, use `{enum_name}::{variant}` instead"
+                                ))),
+                            ]
+                            .into_iter()
+                            .collect(),
+                        )),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+                TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Bracket,
+                    [
+                        TokenTree::Ident(Ident::new("allow", call_site_span)),
+                        TokenTree::Group(Group::new(
+                            Delimiter::Parenthesis,
+                            TokenStream::from(TokenTree::Ident(Ident::new(
+                                "non_upper_case_globals",
+                                call_site_span,
+                            ))),
+                        )),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+            ]);
+            if let Some(vis) = &vis {
+                inner.extend(vis.clone());
+            }
+            // const OldId: Self = Self::NewId;
+            inner.extend([
+                TokenTree::Ident(Ident::new("const", call_site_span)),
+                TokenTree::Ident(ident.clone()),
+                TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("Self", call_site_span)),
+                TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("Self", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(variant.clone()),
+                TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+            ]);
+        }
+
+        inner
+    });
+    tokens.push(TokenTree::Group(impl_group));
+
+    // AsRef<str> impl
+    tokens.extend([
+        TokenTree::Ident(Ident::new("impl", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("core", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("convert", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("AsRef", call_site_span)),
+        TokenTree::Punct(Punct::new('<', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("str", call_site_span)),
+        TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("for", call_site_span)),
+        TokenTree::Ident(Ident::new(&enum_name, call_site_span)),
+        TokenTree::Group(Group::new(
+            Delimiter::Brace,
+            [
+                TokenTree::Ident(Ident::new("fn", call_site_span)),
+                TokenTree::Ident(Ident::new("as_ref", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("self", call_site_span)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+                TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("str", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Brace,
+                    [
+                        TokenTree::Ident(Ident::new("self", call_site_span)),
+                        TokenTree::Punct(Punct::new('.', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("as_str", call_site_span)),
+                        TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+            ]
+            .into_iter()
+            .collect(),
+        )),
+    ]);
+
+    // PartialEq impls between the enum and `str`, `&str` and `&&str` in both
+    // directions, comparing the identifiers
+    for refs in 0..3 {
+        let mut str_type = vec![TokenTree::Punct(Punct::new('&', Spacing::Alone)); refs];
+        str_type.push(TokenTree::Ident(Ident::new("str", call_site_span)));
+        let partial_eq_path = [
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("core", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("cmp", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("PartialEq", call_site_span)),
+        ];
+        let enum_type = [TokenTree::Ident(Ident::new(&enum_name, call_site_span))];
+
+        // impl ::core::cmp::PartialEq<&str> for Ids {
+        //     fn eq(&self, other: &&str) -> bool {
+        //         self.as_str() == *other
+        //     }
+        // }
+        // impl ::core::cmp::PartialEq<Ids> for &str {
+        //     fn eq(&self, other: &Ids) -> bool {
+        //         other.as_str() == *self
+        //     }
+        // }
+        for (self_type, other_type, enum_arg, str_arg) in [
+            (&enum_type[..], &str_type[..], "self", "other"),
+            (&str_type[..], &enum_type[..], "other", "self"),
+        ] {
+            tokens.push(TokenTree::Ident(Ident::new("impl", call_site_span)));
+            tokens.extend(partial_eq_path.iter().cloned());
+            tokens.push(TokenTree::Punct(Punct::new('<', Spacing::Alone)));
+            tokens.extend(other_type.iter().cloned());
+            tokens.push(TokenTree::Punct(Punct::new('>', Spacing::Alone)));
+            tokens.push(TokenTree::Ident(Ident::new("for", call_site_span)));
+            tokens.extend(self_type.iter().cloned());
+
+            let mut signature = vec![
+                TokenTree::Punct(Punct::new('&', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("self", call_site_span)),
+                TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("other", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                TokenTree::Punct(Punct::new('&', Spacing::Alone)),
+            ];
+            signature.extend(other_type.iter().cloned());
+
+            let mut body = vec![
+                TokenTree::Ident(Ident::new(enum_arg, call_site_span)),
+                TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("as_str", call_site_span)),
+                TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+                TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+            ];
+            body.extend(vec![
+                TokenTree::Punct(Punct::new('*', Spacing::Alone));
+                refs
+            ]);
+            body.push(TokenTree::Ident(Ident::new(str_arg, call_site_span)));
+
+            tokens.push(TokenTree::Group(Group::new(
+                Delimiter::Brace,
+                [
+                    TokenTree::Ident(Ident::new("fn", call_site_span)),
+                    TokenTree::Ident(Ident::new("eq", call_site_span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        signature.into_iter().collect(),
+                    )),
+                    TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("bool", call_site_span)),
+                    TokenTree::Group(Group::new(Delimiter::Brace, body.into_iter().collect())),
+                ]
+                .into_iter()
+                .collect(),
+            )));
+        }
+    }
+
+    // Default impl
+    if default {
+        tokens.extend([
+            TokenTree::Ident(Ident::new("impl", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("core", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("default", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("Default", call_site_span)),
+            TokenTree::Ident(Ident::new("for", call_site_span)),
+            TokenTree::Ident(Ident::new(&enum_name, call_site_span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Brace,
+                [
+                    TokenTree::Ident(Ident::new("fn", call_site_span)),
+                    TokenTree::Ident(Ident::new("default", call_site_span)),
+                    TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+                    TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("Self", call_site_span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Brace,
+                        [
+                            TokenTree::Ident(Ident::new("Self", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Ident(ids_variants_idents[0].clone()),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+        ]);
+    }
+
+    // PartialOrd and Ord impls, comparing the positions in `ALL`
+    if ord {
+        tokens.extend([
+            TokenTree::Ident(Ident::new("impl", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("core", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("cmp", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("PartialOrd", call_site_span)),
+            TokenTree::Ident(Ident::new("for", call_site_span)),
+            TokenTree::Ident(Ident::new(&enum_name, call_site_span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Brace,
+                [
+                    TokenTree::Ident(Ident::new("fn", call_site_span)),
+                    TokenTree::Ident(Ident::new("partial_cmp", call_site_span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        [
+                            TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("self", call_site_span)),
+                            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("other", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                            TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("Self", call_site_span)),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                    TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("core", call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("option", call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("Option", call_site_span)),
+                    TokenTree::Punct(Punct::new('<', Spacing::Alone)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("core", call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("cmp", call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("Ordering", call_site_span)),
+                    TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Brace,
+                        [
+                            // Some(Ord::cmp(self, other))
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("core", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("option", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("Option", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("Some", call_site_span)),
+                            TokenTree::Group(Group::new(
+                                Delimiter::Parenthesis,
+                                [
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Ident(Ident::new("core", call_site_span)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Ident(Ident::new("cmp", call_site_span)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Ident(Ident::new("Ord", call_site_span)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Ident(Ident::new("cmp", call_site_span)),
+                                    TokenTree::Group(Group::new(
+                                        Delimiter::Parenthesis,
+                                        [
+                                            TokenTree::Ident(Ident::new("self", call_site_span)),
+                                            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                                            TokenTree::Ident(Ident::new("other", call_site_span)),
+                                        ]
+                                        .into_iter()
+                                        .collect(),
+                                    )),
+                                ]
+                                .into_iter()
+                                .collect(),
+                            )),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+            TokenTree::Ident(Ident::new("impl", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("core", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("cmp", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("Ord", call_site_span)),
+            TokenTree::Ident(Ident::new("for", call_site_span)),
+            TokenTree::Ident(Ident::new(&enum_name, call_site_span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Brace,
+                [
+                    TokenTree::Ident(Ident::new("fn", call_site_span)),
+                    TokenTree::Ident(Ident::new("cmp", call_site_span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        [
+                            TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("self", call_site_span)),
+                            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("other", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                            TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("Self", call_site_span)),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                    TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("core", call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("cmp", call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("Ordering", call_site_span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Brace,
+                        [
+                            // Ord::cmp(&self.index(), &other.index())
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("core", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("cmp", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("Ord", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("cmp", call_site_span)),
+                            TokenTree::Group(Group::new(
+                                Delimiter::Parenthesis,
+                                [
+                                    TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                                    TokenTree::Ident(Ident::new("self", call_site_span)),
+                                    TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                                    TokenTree::Ident(Ident::new("index", call_site_span)),
+                                    TokenTree::Group(Group::new(
+                                        Delimiter::Parenthesis,
+                                        TokenStream::new(),
+                                    )),
+                                    TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                                    TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                                    TokenTree::Ident(Ident::new("other", call_site_span)),
+                                    TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                                    TokenTree::Ident(Ident::new("index", call_site_span)),
+                                    TokenTree::Group(Group::new(
+                                        Delimiter::Parenthesis,
+                                        TokenStream::new(),
+                                    )),
+                                ]
+                                .into_iter()
+                                .collect(),
+                            )),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+        ]);
+    }
+
+    // Borrow<str> and Hash impls, hashing the identifier so looking up a
+    // `HashMap<Ids, _>` by `&str` finds the same entries
+    #[cfg(feature = "borrow-str")]
+    tokens.extend([
+        TokenTree::Ident(Ident::new("impl", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("core", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("borrow", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("Borrow", call_site_span)),
+        TokenTree::Punct(Punct::new('<', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("str", call_site_span)),
+        TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("for", call_site_span)),
+        TokenTree::Ident(Ident::new(&enum_name, call_site_span)),
+        TokenTree::Group(Group::new(
+            Delimiter::Brace,
+            [
+                TokenTree::Ident(Ident::new("fn", call_site_span)),
+                TokenTree::Ident(Ident::new("borrow", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("self", call_site_span)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+                TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("str", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Brace,
+                    [
+                        TokenTree::Ident(Ident::new("self", call_site_span)),
+                        TokenTree::Punct(Punct::new('.', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("as_str", call_site_span)),
+                        TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+            ]
+            .into_iter()
+            .collect(),
+        )),
+        // impl ::core::hash::Hash for Ids {
+        //     fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+        //         ::core::hash::Hash::hash(self.as_str(), state);
+        //     }
+        // }
+        TokenTree::Ident(Ident::new("impl", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("core", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("hash", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("Hash", call_site_span)),
+        TokenTree::Ident(Ident::new("for", call_site_span)),
+        TokenTree::Ident(Ident::new(&enum_name, call_site_span)),
+        TokenTree::Group(Group::new(
+            Delimiter::Brace,
+            [
+                TokenTree::Ident(Ident::new("fn", call_site_span)),
+                TokenTree::Ident(Ident::new("hash", call_site_span)),
+                TokenTree::Punct(Punct::new('<', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("H", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("core", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("hash", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("Hasher", call_site_span)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("self", call_site_span)),
+                        TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("state", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("mut", call_site_span)),
+                        TokenTree::Ident(Ident::new("H", call_site_span)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+                TokenTree::Group(Group::new(
+                    Delimiter::Brace,
+                    [
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("core", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("hash", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("Hash", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("hash", call_site_span)),
+                        TokenTree::Group(Group::new(
+                            Delimiter::Parenthesis,
+                            [
+                                TokenTree::Ident(Ident::new("self", call_site_span)),
+                                TokenTree::Punct(Punct::new('.', Spacing::Joint)),
+                                TokenTree::Ident(Ident::new("as_str", call_site_span)),
+                                TokenTree::Group(Group::new(
+                                    Delimiter::Parenthesis,
+                                    TokenStream::new(),
+                                )),
+                                TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("state", call_site_span)),
+                            ]
+                            .into_iter()
+                            .collect(),
+                        )),
+                        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+            ]
+            .into_iter()
+            .collect(),
+        )),
+    ]);
+
+    // Deref impl, so `str` methods can be called on the variants
+    #[cfg(feature = "deref")]
+    tokens.extend([
+        TokenTree::Ident(Ident::new("impl", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("core", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("ops", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("Deref", call_site_span)),
+        TokenTree::Ident(Ident::new("for", call_site_span)),
+        TokenTree::Ident(Ident::new(&enum_name, call_site_span)),
+        TokenTree::Group(Group::new(
+            Delimiter::Brace,
+            [
+                TokenTree::Ident(Ident::new("type", call_site_span)),
+                TokenTree::Ident(Ident::new("Target", call_site_span)),
+                TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("str", call_site_span)),
+                TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("fn", call_site_span)),
+                TokenTree::Ident(Ident::new("deref", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("self", call_site_span)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+                TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("str", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Brace,
+                    [
+                        TokenTree::Ident(Ident::new("self", call_site_span)),
+                        TokenTree::Punct(Punct::new('.', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("as_str", call_site_span)),
+                        TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+            ]
+            .into_iter()
+            .collect(),
+        )),
+    ]);
+
+    // Debug impl, formatting like `LanguageSelector("language-selector")`
+    #[cfg(feature = "debug")]
+    tokens.extend([
+        TokenTree::Ident(Ident::new("impl", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("core", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("fmt", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("Debug", call_site_span)),
+        TokenTree::Ident(Ident::new("for", call_site_span)),
+        TokenTree::Ident(Ident::new(&enum_name, call_site_span)),
+        TokenTree::Group(Group::new(
+            Delimiter::Brace,
+            [
+                TokenTree::Ident(Ident::new("fn", call_site_span)),
+                TokenTree::Ident(Ident::new("fmt", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("self", call_site_span)),
+                        TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("f", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("mut", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("core", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("fmt", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("Formatter", call_site_span)),
+                        TokenTree::Punct(Punct::new('<', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("_", call_site_span)),
+                        TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+                TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("core", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("fmt", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("Result", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Brace,
+                    [
+                        TokenTree::Ident(Ident::new("match", call_site_span)),
+                        TokenTree::Punct(Punct::new('*', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("self", call_site_span)),
+                        TokenTree::Group(Group::new(Delimiter::Brace, {
+                            let mut inner: Vec<TokenTree> = Vec::with_capacity(ids_length * 17);
+                            // Self::Foo => f.debug_tuple("Foo").field(&self.as_str()).finish(),
+                            for ((ident, name), cfg_attribute) in ids_variants_idents
+                                .iter()
+                                .zip(&ids_variants_names)
+                                .zip(&ids_cfg_attributes)
+                            {
+                                inner.extend(cfg_attribute.clone());
+                                inner.extend([
+                                    TokenTree::Ident(Ident::new("Self", call_site_span)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                    TokenTree::Ident(ident.clone()),
+                                    TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                                    TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                                    TokenTree::Ident(Ident::new("f", call_site_span)),
+                                    TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                                    TokenTree::Ident(Ident::new("debug_tuple", call_site_span)),
+                                    TokenTree::Group(Group::new(
+                                        Delimiter::Parenthesis,
+                                        TokenStream::from(TokenTree::Literal(Literal::string(
+                                            name,
+                                        ))),
+                                    )),
+                                    TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                                    TokenTree::Ident(Ident::new("field", call_site_span)),
+                                    TokenTree::Group(Group::new(
+                                        Delimiter::Parenthesis,
+                                        [
+                                            TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                                            TokenTree::Ident(Ident::new("self", call_site_span)),
+                                            TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                                            TokenTree::Ident(Ident::new("as_str", call_site_span)),
+                                            TokenTree::Group(Group::new(
+                                                Delimiter::Parenthesis,
+                                                TokenStream::new(),
+                                            )),
+                                        ]
+                                        .into_iter()
+                                        .collect(),
+                                    )),
+                                    TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                                    TokenTree::Ident(Ident::new("finish", call_site_span)),
+                                    TokenTree::Group(Group::new(
+                                        Delimiter::Parenthesis,
+                                        TokenStream::new(),
+                                    )),
+                                    TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                                ]);
+                            }
+                            inner.into_iter().collect()
+                        })),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+            ]
+            .into_iter()
+            .collect(),
+        )),
+    ]);
+
+    // Into<&'static str> impl
+    #[cfg(feature = "into-str")]
+    tokens.extend([
+        TokenTree::Ident(Ident::new("impl", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("core", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("convert", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("Into", call_site_span)),
+        TokenTree::Punct(Punct::new('<', Spacing::Joint)),
+        TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+        TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("static", call_site_span)),
+        TokenTree::Ident(Ident::new("str", call_site_span)),
+        TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("for", call_site_span)),
+        TokenTree::Ident(Ident::new(&enum_name, call_site_span)),
+        TokenTree::Group(Group::new(
+            Delimiter::Brace,
+            [
+                TokenTree::Ident(Ident::new("fn", call_site_span)),
+                TokenTree::Ident(Ident::new("into", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    TokenStream::from(TokenTree::Ident(Ident::new("self", call_site_span))),
+                )),
+                TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("static", call_site_span)),
+                TokenTree::Ident(Ident::new("str", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Brace,
+                    [
+                        TokenTree::Ident(Ident::new("self", call_site_span)),
+                        TokenTree::Punct(Punct::new('.', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("as_str", call_site_span)),
+                        TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+            ]
+            .into_iter()
+            .collect(),
+        )),
+    ]);
+
+    // From<Ids> for String and Cow<'static, str> impls, which are in the `alloc`
+    // crate, only available with `extern crate alloc` in `no_std` crates
+    #[cfg(all(feature = "into-str", feature = "alloc"))]
+    let alloc_crate = if cfg!(feature = "std") {
+        "std"
+    } else {
+        "alloc"
+    };
+    #[cfg(all(feature = "into-str", feature = "alloc"))]
+    for target in [
+        vec![
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new(alloc_crate, call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("string", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("String", call_site_span)),
+        ],
+        vec![
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new(alloc_crate, call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("borrow", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("Cow", call_site_span)),
+            TokenTree::Punct(Punct::new('<', Spacing::Joint)),
+            TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("static", call_site_span)),
+            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("str", call_site_span)),
+            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+        ],
+    ] {
+        tokens.extend([
+            TokenTree::Ident(Ident::new("impl", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("core", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("convert", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("From", call_site_span)),
+            TokenTree::Punct(Punct::new('<', Spacing::Joint)),
+            TokenTree::Ident(Ident::new(&enum_name, call_site_span)),
+            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("for", call_site_span)),
+        ]);
+        tokens.extend(target);
+        tokens.push(TokenTree::Group(Group::new(
+            Delimiter::Brace,
+            [
+                TokenTree::Ident(Ident::new("fn", call_site_span)),
+                TokenTree::Ident(Ident::new("from", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Ident(Ident::new("id", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new(&enum_name, call_site_span)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+                TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("Self", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Brace,
+                    [
+                        TokenTree::Ident(Ident::new("id", call_site_span)),
+                        TokenTree::Punct(Punct::new('.', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("as_str", call_site_span)),
+                        TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+                        TokenTree::Punct(Punct::new('.', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("into", call_site_span)),
+                        TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+            ]
+            .into_iter()
+            .collect(),
+        )));
+    }
+
+    // From<Ids> for leptos::prelude::Oco<'static, str> impl, which is only
+    // available since Leptos 0.7
+    #[cfg(feature = "into-oco")]
+    if !leptos_06 {
+        tokens.extend([
+            TokenTree::Ident(Ident::new("impl", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("core", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("convert", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("From", call_site_span)),
+            TokenTree::Punct(Punct::new('<', Spacing::Joint)),
+            TokenTree::Ident(Ident::new(&enum_name, call_site_span)),
+            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("for", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("leptos", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("prelude", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("Oco", call_site_span)),
+            TokenTree::Punct(Punct::new('<', Spacing::Joint)),
+            TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("static", call_site_span)),
+            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("str", call_site_span)),
+            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+            TokenTree::Group(Group::new(
+                Delimiter::Brace,
+                [
+                    TokenTree::Ident(Ident::new("fn", call_site_span)),
+                    TokenTree::Ident(Ident::new("from", call_site_span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        [
+                            TokenTree::Ident(Ident::new("id", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new(&enum_name, call_site_span)),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                    TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("Self", call_site_span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Brace,
+                        [
+                            TokenTree::Ident(Ident::new("Self", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("Borrowed", call_site_span)),
+                            TokenTree::Group(Group::new(
+                                Delimiter::Parenthesis,
+                                [
+                                    TokenTree::Ident(Ident::new("id", call_site_span)),
+                                    TokenTree::Punct(Punct::new('.', Spacing::Joint)),
+                                    TokenTree::Ident(Ident::new("as_str", call_site_span)),
+                                    TokenTree::Group(Group::new(
+                                        Delimiter::Parenthesis,
+                                        TokenStream::new(),
+                                    )),
+                                ]
+                                .into_iter()
+                                .collect(),
+                            )),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+        ]);
+    }
+
+    // name of the error after the singular of the enum name, like `UnknownId`
+    // for `Ids`, so enums with different names can live in the same module
+    #[cfg(feature = "from-str")]
+    let unknown_id_name = format!("Unknown{singular_name}");
+
+    // UnknownId error, shared by all the conversions from strings
+    #[cfg(feature = "from-str")]
+    {
+        tokens.extend([
+            TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+            TokenTree::Group(Group::new(
+                Delimiter::Bracket,
+                [
+                    TokenTree::Ident(Ident::new("doc", call_site_span)),
+                    TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                    TokenTree::Literal(Literal::string(&format!(
+                        "Error returned when a string is not one of the identifiers of `{enum_name}`."
+                    ))),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+            TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+            TokenTree::Group(Group::new(
+                Delimiter::Bracket,
+                [
+                    TokenTree::Ident(Ident::new("derive", call_site_span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        [
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("core", call_site_span)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("fmt", call_site_span)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("Debug", call_site_span)),
+                            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("core", call_site_span)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("clone", call_site_span)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("Clone", call_site_span)),
+                            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("core", call_site_span)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("marker", call_site_span)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("Copy", call_site_span)),
+                            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("core", call_site_span)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("cmp", call_site_span)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("PartialEq", call_site_span)),
+                            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("core", call_site_span)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("cmp", call_site_span)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("Eq", call_site_span)),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+        ]);
+        if let Some(vis) = &vis {
+            tokens.extend(vis.clone());
+        }
+        tokens.extend([
+            TokenTree::Ident(Ident::new("struct", call_site_span)),
+            TokenTree::Ident(Ident::new(&unknown_id_name, call_site_span)),
+            TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+            // Display impl
+            TokenTree::Ident(Ident::new("impl", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("core", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("fmt", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("Display", call_site_span)),
+            TokenTree::Ident(Ident::new("for", call_site_span)),
+            TokenTree::Ident(Ident::new(&unknown_id_name, call_site_span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Brace,
+                [
+                    TokenTree::Ident(Ident::new("fn", call_site_span)),
+                    TokenTree::Ident(Ident::new("fmt", call_site_span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        [
+                            TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("self", call_site_span)),
+                            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("f", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                            TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("mut", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("core", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("fmt", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("Formatter", call_site_span)),
+                            TokenTree::Punct(Punct::new('<', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("_", call_site_span)),
+                            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                    TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("core", call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("fmt", call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("Result", call_site_span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Brace,
+                        [
+                            TokenTree::Ident(Ident::new("f", call_site_span)),
+                            TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("write_str", call_site_span)),
+                            TokenTree::Group(Group::new(
+                                Delimiter::Parenthesis,
+                                TokenStream::from(TokenTree::Literal(Literal::string(
+                                    "unknown identifier",
+                                ))),
+                            )),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+            // Error impl
+            TokenTree::Ident(Ident::new("impl", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("core", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("error", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("Error", call_site_span)),
+            TokenTree::Ident(Ident::new("for", call_site_span)),
+            TokenTree::Ident(Ident::new(&unknown_id_name, call_site_span)),
+            TokenTree::Group(Group::new(Delimiter::Brace, TokenStream::new())),
+        ]);
+    }
+
+    // body of FromStr::from_str, which looks up the string in a perfect hash
+    // map with the `phf` feature instead of matching its length
+    #[cfg(feature = "from-str")]
+    let from_str_body: TokenStream = if cfg!(feature = "phf") && ids_length > 0 {
+        phf_from_str_body(
+            &profiles,
+            &ids_variants_idents,
+            &ids_cfg_attributes,
+            &unknown_id_name,
+            call_site_span,
+        )
+    } else {
+        [
+            TokenTree::Ident(Ident::new("match", call_site_span)),
+            TokenTree::Ident(Ident::new("s", call_site_span)),
+            TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("len", call_site_span)),
+            TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+            TokenTree::Group(Group::new(Delimiter::Brace, {
+                let mut inner: Vec<TokenTree> = Vec::new();
+                // the string is only compared against the identifiers
+                // with its same length, bucketed at expansion time
+                for (cfg, profile_ids, _) in &profiles {
+                    let mut buckets: Vec<(usize, Vec<usize>)> = Vec::new();
+                    for (i, id) in profile_ids.iter().enumerate() {
+                        match buckets.iter_mut().find(|(length, _)| *length == id.len()) {
+                            Some((_, bucket)) => bucket.push(i),
+                            None => buckets.push((id.len(), vec![i])),
+                        }
+                    }
+                    for (length, bucket) in &buckets {
+                        inner.extend(cfg.clone());
+                        inner.extend([
+                            TokenTree::Literal(Literal::usize_unsuffixed(*length)),
+                            TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("match", call_site_span)),
+                            TokenTree::Ident(Ident::new("s", call_site_span)),
+                            TokenTree::Group(Group::new(Delimiter::Brace, {
+                                let mut inner: Vec<TokenTree> =
+                                    Vec::with_capacity(bucket.len() * 6);
+                                for i in bucket {
+                                    inner.extend(ids_cfg_attributes[*i].clone());
+                                    inner.extend([
+                                        TokenTree::Literal(Literal::string(&profile_ids[*i])),
+                                        TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                                        TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                        TokenTree::Ident(Ident::new("core", call_site_span)),
+                                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                        TokenTree::Ident(Ident::new("result", call_site_span)),
+                                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                        TokenTree::Ident(Ident::new("Result", call_site_span)),
+                                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                        TokenTree::Ident(Ident::new("Ok", call_site_span)),
+                                        TokenTree::Group(Group::new(
+                                            Delimiter::Parenthesis,
+                                            [
+                                                TokenTree::Ident(Ident::new(
+                                                    "Self",
+                                                    call_site_span,
+                                                )),
+                                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                                                TokenTree::Ident(ids_variants_idents[*i].clone()),
+                                            ]
+                                            .into_iter()
+                                            .collect(),
+                                        )),
+                                        TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                                    ]);
+                                }
+                                inner.extend(unknown_id_arm(&unknown_id_name, call_site_span));
+                                inner.into_iter().collect()
+                            })),
+                            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                        ]);
+                    }
+                }
+                inner.extend(unknown_id_arm(&unknown_id_name, call_site_span));
+                inner.into_iter().collect()
+            })),
+        ]
+        .into_iter()
+        .collect()
+    };
+
+    // FromStr impl
+    #[cfg(feature = "from-str")]
+    tokens.extend([
+        TokenTree::Ident(Ident::new("impl", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("core", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("str", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("FromStr", call_site_span)),
+        TokenTree::Ident(Ident::new("for", call_site_span)),
+        TokenTree::Ident(Ident::new(&enum_name, call_site_span)),
+        TokenTree::Group(Group::new(
+            Delimiter::Brace,
+            [
+                TokenTree::Ident(Ident::new("type", call_site_span)),
+                TokenTree::Ident(Ident::new("Err", call_site_span)),
+                TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                TokenTree::Ident(Ident::new(&unknown_id_name, call_site_span)),
+                TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("fn", call_site_span)),
+                TokenTree::Ident(Ident::new("from_str", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Ident(Ident::new("s", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("str", call_site_span)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+                TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("core", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("result", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("Result", call_site_span)),
+                TokenTree::Punct(Punct::new('<', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("Self", call_site_span)),
+                TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("Self", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("Err", call_site_span)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Group(Group::new(Delimiter::Brace, from_str_body)),
+            ]
+            .into_iter()
+            .collect(),
+        )),
+    ]);
+
+    // TryFrom<&str> impl, delegating to FromStr to share its match
+    #[cfg(feature = "from-str")]
+    tokens.extend([
+        TokenTree::Ident(Ident::new("impl", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("core", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("convert", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("TryFrom", call_site_span)),
+        TokenTree::Punct(Punct::new('<', Spacing::Alone)),
+        TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("str", call_site_span)),
+        TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("for", call_site_span)),
+        TokenTree::Ident(Ident::new(&enum_name, call_site_span)),
+        TokenTree::Group(Group::new(
+            Delimiter::Brace,
+            [
+                TokenTree::Ident(Ident::new("type", call_site_span)),
+                TokenTree::Ident(Ident::new("Error", call_site_span)),
+                TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                TokenTree::Ident(Ident::new(&unknown_id_name, call_site_span)),
+                TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("fn", call_site_span)),
+                TokenTree::Ident(Ident::new("try_from", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Ident(Ident::new("s", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("str", call_site_span)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+                TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("core", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("result", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("Result", call_site_span)),
+                TokenTree::Punct(Punct::new('<', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("Self", call_site_span)),
+                TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("Self", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("Error", call_site_span)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Brace,
+                    [
+                        TokenTree::Punct(Punct::new('<', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("Self", call_site_span)),
+                        TokenTree::Ident(Ident::new("as", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("core", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("str", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("FromStr", call_site_span)),
+                        TokenTree::Punct(Punct::new('>', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("from_str", call_site_span)),
+                        TokenTree::Group(Group::new(
+                            Delimiter::Parenthesis,
+                            TokenStream::from(TokenTree::Ident(Ident::new("s", call_site_span))),
+                        )),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+            ]
+            .into_iter()
+            .collect(),
+        )),
+    ]);
+
+    // IdIndexOutOfRange error, named after the singular of the enum name like
+    // `UnknownId`, returned by the conversions from positions
+    let index_out_of_range_name = format!("{singular_name}IndexOutOfRange");
+    tokens.extend([
+        TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+        TokenTree::Group(Group::new(
+            Delimiter::Bracket,
+            [
+                TokenTree::Ident(Ident::new("doc", call_site_span)),
+                TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                TokenTree::Literal(Literal::string(&format!(
+                    "Error returned when a position is out of range of the variants of `{enum_name}`."
+                ))),
+            ]
+            .into_iter()
+            .collect(),
+        )),
+        TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+        TokenTree::Group(Group::new(
+            Delimiter::Bracket,
+            [
+                TokenTree::Ident(Ident::new("derive", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("core", call_site_span)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("fmt", call_site_span)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("Debug", call_site_span)),
+                        TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("core", call_site_span)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("clone", call_site_span)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("Clone", call_site_span)),
+                        TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("core", call_site_span)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("marker", call_site_span)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("Copy", call_site_span)),
+                        TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("core", call_site_span)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("cmp", call_site_span)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("PartialEq", call_site_span)),
+                        TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("core", call_site_span)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("cmp", call_site_span)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+TokenTree::Ident(Ident::new("Eq", call_site_span)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+            ]
+            .into_iter()
+            .collect(),
+        )),
+    ]);
+    if let Some(vis) = &vis {
+        tokens.extend(vis.clone());
+    }
+    tokens.extend([
+        TokenTree::Ident(Ident::new("struct", call_site_span)),
+        TokenTree::Ident(Ident::new(&index_out_of_range_name, call_site_span)),
+        TokenTree::Group(Group::new(Delimiter::Parenthesis, {
+            let mut inner = TokenStream::new();
+            if let Some(vis) = &vis {
+                inner.extend(vis.clone());
+            }
+            inner.extend([TokenTree::Ident(Ident::new("usize", call_site_span))]);
+            inner
+        })),
+        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+        // Display impl
+        TokenTree::Ident(Ident::new("impl", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("core", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("fmt", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("Display", call_site_span)),
+        TokenTree::Ident(Ident::new("for", call_site_span)),
+        TokenTree::Ident(Ident::new(&index_out_of_range_name, call_site_span)),
+        TokenTree::Group(Group::new(
+            Delimiter::Brace,
+            [
+                TokenTree::Ident(Ident::new("fn", call_site_span)),
+                TokenTree::Ident(Ident::new("fmt", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("self", call_site_span)),
+                        TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("f", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("mut", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("core", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("fmt", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("Formatter", call_site_span)),
+                        TokenTree::Punct(Punct::new('<', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("_", call_site_span)),
+                        TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+                TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("core", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("fmt", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("Result", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Brace,
+                    [
+                        // write!(f, "position {} is out of range", self.0)
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("core", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("write", call_site_span)),
+                        TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+                        TokenTree::Group(Group::new(
+                            Delimiter::Parenthesis,
+                            [
+                                TokenTree::Ident(Ident::new("f", call_site_span)),
+                                TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                                TokenTree::Literal(Literal::string("position {} is out of range")),
+                                TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("self", call_site_span)),
+                                TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                                TokenTree::Literal(Literal::usize_unsuffixed(0)),
+                            ]
+                            .into_iter()
+                            .collect(),
+                        )),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+            ]
+            .into_iter()
+            .collect(),
+        )),
+        // Error impl
+        TokenTree::Ident(Ident::new("impl", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("core", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("error", call_site_span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("Error", call_site_span)),
+        TokenTree::Ident(Ident::new("for", call_site_span)),
+        TokenTree::Ident(Ident::new(&index_out_of_range_name, call_site_span)),
+        TokenTree::Group(Group::new(Delimiter::Brace, TokenStream::new())),
+    ]);
+
+    // TryFrom<usize> impl with `from_index` and TryFrom<u16> impl with `from_u16`,
+    // so the conversion from `u16` is the inverse of `as_u16` with `repr = "u16"`
+    for (integer, method) in [("usize", "from_index"), ("u16", "from_u16")] {
+        if integer == "u16" && !repr_u16 {
+            continue;
+        }
+        // the error stores the position as `usize`, like `usize::from(value)`
+        let mut position = TokenStream::new();
+        if integer == "usize" {
+            position.extend([TokenTree::Ident(Ident::new("value", call_site_span))]);
+        } else {
+            position.extend([
+                TokenTree::Ident(Ident::new("usize", call_site_span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("from", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    TokenStream::from(TokenTree::Ident(Ident::new("value", call_site_span))),
+                )),
+            ]);
+        }
+        tokens.extend([
+            TokenTree::Ident(Ident::new("impl", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("core", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("convert", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("TryFrom", call_site_span)),
+            TokenTree::Punct(Punct::new('<', Spacing::Alone)),
+            TokenTree::Ident(Ident::new(integer, call_site_span)),
+            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("for", call_site_span)),
+            TokenTree::Ident(Ident::new(&enum_name, call_site_span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Brace,
+                [
+                    TokenTree::Ident(Ident::new("type", call_site_span)),
+                    TokenTree::Ident(Ident::new("Error", call_site_span)),
+                    TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new(&index_out_of_range_name, call_site_span)),
+                    TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("fn", call_site_span)),
+                    TokenTree::Ident(Ident::new("try_from", call_site_span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        [
+                            TokenTree::Ident(Ident::new("value", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new(integer, call_site_span)),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                    TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("core", call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("result", call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("Result", call_site_span)),
+                    TokenTree::Punct(Punct::new('<', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("Self", call_site_span)),
+                    TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("Self", call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("Error", call_site_span)),
+                    TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Brace,
+                        [
+                            // Self::from_index(value).ok_or(IdIndexOutOfRange(value))
+                            TokenTree::Ident(Ident::new("Self", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new(method, call_site_span)),
+                            TokenTree::Group(Group::new(
+                                Delimiter::Parenthesis,
+                                TokenStream::from(TokenTree::Ident(Ident::new(
+                                    "value",
+                                    call_site_span,
+                                ))),
+                            )),
+                            TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("ok_or", call_site_span)),
+                            TokenTree::Group(Group::new(
+                                Delimiter::Parenthesis,
+                                [
+                                    TokenTree::Ident(Ident::new(
+                                        &index_out_of_range_name,
+                                        call_site_span,
+                                    )),
+                                    TokenTree::Group(Group::new(Delimiter::Parenthesis, position)),
+                                ]
+                                .into_iter()
+                                .collect(),
+                            )),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+        ]);
+    }
+
+    #[cfg(feature = "selector")]
+    {
+        tokens.extend([
+            TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+            TokenTree::Group(Group::new(
+                Delimiter::Bracket,
+                [
+                    TokenTree::Ident(Ident::new("doc", call_site_span)),
+                    TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                    TokenTree::Literal(Literal::string(&format!(
+                        "Identifier of `{enum_name}` displayed as an id selector, like `#language-selector`."
+                    ))),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+        TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+        TokenTree::Group(Group::new(
+            Delimiter::Bracket,
+            [
+                TokenTree::Ident(Ident::new("derive", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("core", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("fmt", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("Debug", call_site_span)),
+                        TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("core", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("clone", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("Clone", call_site_span)),
+                        TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("core", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("marker", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("Copy", call_site_span)),
+                        TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("core", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("cmp", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("PartialEq", call_site_span)),
+                        TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("core", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("cmp", call_site_span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("Eq", call_site_span)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+            ]
+            .into_iter()
+            .collect(),
+        )),
+        ]);
+        if let Some(vis) = &vis {
+            tokens.extend(vis.clone());
+        }
+        tokens.extend([
+            TokenTree::Ident(Ident::new("struct", call_site_span)),
+            TokenTree::Ident(Ident::new(&selector_name, call_site_span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Parenthesis,
+                [
+                    TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("static", call_site_span)),
+                    TokenTree::Ident(Ident::new("str", call_site_span)),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+            TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("impl", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("core", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("fmt", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("Display", call_site_span)),
+            TokenTree::Ident(Ident::new("for", call_site_span)),
+            TokenTree::Ident(Ident::new(&selector_name, call_site_span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Brace,
+                [
+                    TokenTree::Ident(Ident::new("fn", call_site_span)),
+                    TokenTree::Ident(Ident::new("fmt", call_site_span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        [
+                            TokenTree::Punct(Punct::new('&', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("self", call_site_span)),
+                            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("f", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                            TokenTree::Punct(Punct::new('&', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("mut", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("core", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("fmt", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("Formatter", call_site_span)),
+                            TokenTree::Punct(Punct::new('<', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("_", call_site_span)),
+                            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                    TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("core", call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("fmt", call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("Result", call_site_span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Brace,
+                        [
+                            TokenTree::Ident(Ident::new("f", call_site_span)),
+                            TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("write_str", call_site_span)),
+                            TokenTree::Group(Group::new(
+                                Delimiter::Parenthesis,
+                                [TokenTree::Literal(Literal::string("#"))]
+                                    .into_iter()
+                                    .collect(),
+                            )),
+                            TokenTree::Punct(Punct::new('?', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("f", call_site_span)),
+                            TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("write_str", call_site_span)),
+                            TokenTree::Group(Group::new(
+                                Delimiter::Parenthesis,
+                                [
+                                    TokenTree::Ident(Ident::new("self", call_site_span)),
+                                    TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                                    TokenTree::Literal(Literal::usize_unsuffixed(0)),
+                                ]
+                                .into_iter()
+                                .collect(),
+                            )),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+        ]);
+    }
+
+    // leptos::prelude::IntoAttributeValue impl
+    #[cfg(feature = "into-attribute-value")]
+    if !leptos_06 {
+        tokens.extend([
+            TokenTree::Ident(Ident::new("impl", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("leptos", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("prelude", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("IntoAttributeValue", call_site_span)),
+            TokenTree::Ident(Ident::new("for", call_site_span)),
+            TokenTree::Ident(Ident::new(&enum_name, call_site_span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Brace,
+                [
+                    TokenTree::Ident(Ident::new("type", call_site_span)),
+                    TokenTree::Ident(Ident::new("Output", call_site_span)),
+                    TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                    TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("static", call_site_span)),
+                    TokenTree::Ident(Ident::new("str", call_site_span)),
+                    TokenTree::Punct(Punct::new(';', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("fn", call_site_span)),
+                    TokenTree::Ident(Ident::new("into_attribute_value", call_site_span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        TokenStream::from(TokenTree::Ident(Ident::new("self", call_site_span))),
+                    )),
+                    TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("Self", call_site_span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Ident(Ident::new("Output", call_site_span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Brace,
+                        [
+                            TokenTree::Ident(Ident::new("self", call_site_span)),
+                            TokenTree::Punct(Punct::new('.', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("as_str", call_site_span)),
+                            TokenTree::Group(Group::new(
+                                Delimiter::Parenthesis,
+                                TokenStream::new(),
+                            )),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+        ]);
+    }
+
+    // leptos::IntoAttribute impl, the shape of the trait before Leptos 0.7
+    #[cfg(feature = "into-attribute-value")]
+    if leptos_06 {
+        let attribute_path = [
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("leptos", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("Attribute", call_site_span)),
+        ];
+        let into_attribute_path = [
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("leptos", call_site_span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Ident(Ident::new("IntoAttribute", call_site_span)),
+        ];
+        tokens.extend([TokenTree::Ident(Ident::new("impl", call_site_span))]);
+        tokens.extend(into_attribute_path.clone());
+        tokens.extend([
+            TokenTree::Ident(Ident::new("for", call_site_span)),
+            TokenTree::Ident(Ident::new(&enum_name, call_site_span)),
+            TokenTree::Group(Group::new(Delimiter::Brace, {
+                let mut inner = TokenStream::new();
+                // fn into_attribute(self) -> ::leptos::Attribute {
+                //     ::leptos::Attribute::String(self.as_str().into())
+                // }
+                inner.extend([
+                    TokenTree::Ident(Ident::new("fn", call_site_span)),
+                    TokenTree::Ident(Ident::new("into_attribute", call_site_span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        TokenStream::from(TokenTree::Ident(Ident::new("self", call_site_span))),
+                    )),
+                    TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                ]);
+                inner.extend(attribute_path.clone());
+                inner.extend([TokenTree::Group(Group::new(Delimiter::Brace, {
+                    let mut inner = TokenStream::new();
+                    inner.extend(attribute_path.clone());
+                    inner.extend([
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("String", call_site_span)),
+                        TokenTree::Group(Group::new(
+                            Delimiter::Parenthesis,
+                            [
+                                TokenTree::Ident(Ident::new("self", call_site_span)),
+                                TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("as_str", call_site_span)),
+                                TokenTree::Group(Group::new(
+                                    Delimiter::Parenthesis,
+                                    TokenStream::new(),
+                                )),
+                                TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("into", call_site_span)),
+                                TokenTree::Group(Group::new(
+                                    Delimiter::Parenthesis,
+                                    TokenStream::new(),
+                                )),
+                            ]
+                            .into_iter()
+                            .collect(),
+                        )),
+                    ]);
+                    inner
+                }))]);
+                // fn into_attribute_boxed(self: Box<Self>) -> ::leptos::Attribute {
+                //     ::leptos::IntoAttribute::into_attribute(*self)
+                // }
+                inner.extend([
+                    TokenTree::Ident(Ident::new("fn", call_site_span)),
+                    TokenTree::Ident(Ident::new("into_attribute_boxed", call_site_span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        [
+                            TokenTree::Ident(Ident::new("self", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("Box", call_site_span)),
+                            TokenTree::Punct(Punct::new('<', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new("Self", call_site_span)),
+                            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                    TokenTree::Punct(Punct::new('-', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                ]);
+                inner.extend(attribute_path);
+                inner.extend([TokenTree::Group(Group::new(Delimiter::Brace, {
+                    let mut inner = TokenStream::new();
+                    inner.extend(into_attribute_path);
+                    inner.extend([
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("into_attribute", call_site_span)),
+                        TokenTree::Group(Group::new(
+                            Delimiter::Parenthesis,
+                            [
+                                TokenTree::Punct(Punct::new('*', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("self", call_site_span)),
+                            ]
+                            .into_iter()
+                            .collect(),
+                        )),
+                    ]);
+                    inner
+                }))]);
+                inner
+            })),
+        ]);
+    }
+    #[cfg(not(any(feature = "into-attribute-value", feature = "into-oco")))]
+    let _ = leptos_06;
+
+    // ids module with a string constant for each identifier
+    #[cfg(feature = "constants")]
+    {
+        if let Some(vis) = &vis {
+            tokens.extend(vis.clone());
+        }
+        tokens.extend([
+            TokenTree::Ident(Ident::new("mod", call_site_span)),
+            TokenTree::Ident(Ident::new(
+                &screaming_snake_case::to_screaming_snake_case(&enum_name).to_lowercase(),
+                call_site_span,
+            )),
+            TokenTree::Group(Group::new(Delimiter::Brace, {
+                let mut inner: Vec<TokenTree> =
+                    Vec::with_capacity(profiles.len() * ids_length * 15);
+                for (cfg, profile_ids, _) in &profiles {
+                    for i in 0..ids_length {
+                        let ident = &ids_constants_idents[i];
+                        inner.extend(cfg.clone());
+                        inner.extend(ids_cfg_attributes[i].clone());
+                        inner.extend([
+                            TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+                            TokenTree::Group(Group::new(
+                                Delimiter::Bracket,
+                                [
+                                    TokenTree::Ident(Ident::new("doc", call_site_span)),
+                                    TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                                    TokenTree::Literal(Literal::string(&ids_quoted[i])),
+                                ]
+                                .into_iter()
+                                .collect(),
+                            )),
+                            TokenTree::Ident(Ident::new("pub", call_site_span)),
+                            TokenTree::Ident(Ident::new("const", call_site_span)),
+                            TokenTree::Ident(ident.clone()),
+                            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                            TokenTree::Punct(Punct::new('&', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+                            TokenTree::Ident(Ident::new("static", call_site_span)),
+                            TokenTree::Ident(Ident::new("str", call_site_span)),
+                            TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                            TokenTree::Literal(Literal::string(&profile_ids[i])),
+                            TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                        ]);
+                    }
+                }
+                inner.into_iter().collect()
+            })),
+        ]);
+    }
+
+    // module for each group re-exporting the variants of its identifiers
+    if !groups.is_empty() {
+        // items of a module are private to its parent by default, so the
+        // re-exports need at least `pub(super)` to be reachable from the enum
+        let use_vis = match &vis {
+            Some(vis)
+                if matches!(
+                    vis.to_string().as_str(),
+                    "pub" | "pub(crate)" | "pub (crate)"
+                ) =>
+            {
+                vis.clone()
+            }
+            _ => TokenStream::from_iter([
+                TokenTree::Ident(Ident::new("pub", call_site_span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    TokenStream::from(TokenTree::Ident(Ident::new("super", call_site_span))),
+                )),
+            ]),
+        };
+        for (group_index, (group_name, module_name, _)) in groups.iter().enumerate() {
+            tokens.extend([
+                TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Bracket,
+                    [
+                        TokenTree::Ident(Ident::new("doc", call_site_span)),
+                        TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                        TokenTree::Literal(Literal::string(&format!(
+                            "Identifiers of the `{group_name}` group of [`{enum_name}`]."
+                        ))),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+            ]);
+            if let Some(vis) = &vis {
+                tokens.extend(vis.clone());
+            }
+            tokens.extend([
+                TokenTree::Ident(Ident::new("mod", call_site_span)),
+                TokenTree::Ident(Ident::new(module_name, call_site_span)),
+                TokenTree::Group(Group::new(Delimiter::Brace, {
+                    let mut inner: Vec<TokenTree> = Vec::new();
+                    for i in 0..ids_length {
+                        if ids_groups[i] != Some(group_index) {
+                            continue;
+                        }
+                        inner.extend(ids_cfg_attributes[i].clone());
+                        inner.extend(use_vis.clone());
+                        inner.extend([
+                            TokenTree::Ident(Ident::new("use", call_site_span)),
+                            TokenTree::Ident(Ident::new("super", call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                            TokenTree::Ident(Ident::new(&enum_name, call_site_span)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                            TokenTree::Ident(ids_variants_idents[i].clone()),
+                            TokenTree::Ident(Ident::new("as", call_site_span)),
+                            TokenTree::Ident(groups_variants_idents[i].clone()),
+                            TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                        ]);
+                    }
+                    inner.into_iter().collect()
+                })),
+            ]);
         }
-        true
-    });
+    }
 
-    if !enum_tokens_iter.all(|token| {
-        if let TokenTree::Ident(ident) = token {
-            let ident_str = ident.to_string();
-            ident_str == "Ids" || ident_str == "enum" || ident_str == "pub"
-        } else if let TokenTree::Group(group) = token {
-            group.delimiter() == Delimiter::Brace || group.delimiter() == Delimiter::Parenthesis
-        } else {
-            return false;
-        }
-    }) {
-        let mut enum_tokens_iter = item_clone.into_iter().skip_while(|token| {
-            !matches!(token, proc_macro::TokenTree::Ident(ident) if ident.to_string() == "enum")
-        });
-        let first_token = enum_tokens_iter
-            .next()
-            .expect("Expected at least one token in the enum declaration");
-        let span = first_token.span();
+    tokens.extend(similar_ids_warnings);
+    tokens.extend(included_files);
+    tokens.into_iter().collect()
+}
 
-        return error(
-            b"Expected an enum formed with the token tree `enum Ids {{}}`.",
-            span,
-        );
-    }
+/// Singular of the enum name, like `Id` for `Ids` or `FormId` for `FormIds`,
+/// used to name the types generated next to the enum.
+///
+/// It's `None` for names that don't end in `s` after another character, like
+/// `Id`, whose types would collide with the ones of `Ids`.
+fn singular_enum_name(enum_name: &str) -> Option<&str> {
+    enum_name
+        .strip_suffix('s')
+        .filter(|singular| !singular.is_empty())
+}
 
-    let call_site_span = Span::call_site();
+/// Emit a `compile_error!` invocation with the given message.
+///
+/// The message is escaped by [`Literal::string`], so quotes, backslashes and
+/// line breaks are rendered verbatim in the diagnostic.
+fn error(message: &str, span: Span) -> TokenStream {
+    let mut error_message = Literal::string(message);
+    error_message.set_span(span);
+    // the diagnostic spans the whole `compile_error!` call, including its group
+    let mut group = Group::new(
+        Delimiter::Brace,
+        TokenStream::from(TokenTree::Literal(error_message)),
+    );
+    group.set_span(span);
 
-    let mut ids: Vec<String> = Vec::new();
-    let mut ids_variants_idents = Vec::new();
+    let punct = |char, spacing| {
+        let mut punct = Punct::new(char, spacing);
+        punct.set_span(span);
+        TokenTree::Punct(punct)
+    };
 
-    for token in attr {
-        if let TokenTree::Literal(literal) = token {
-            let literal_str = literal.to_string();
-            let maybe_value = value_from_literal_str(&literal_str);
-            if let Err(err) = maybe_value {
-                let span = literal.span();
-                return error(err, span);
-            }
-            let value = maybe_value.unwrap().to_string();
+    let mut stream = TokenStream::new();
+    stream.extend([
+        punct(':', Spacing::Joint),
+        punct(':', Spacing::Joint),
+        TokenTree::Ident(Ident::new("core", span)),
+        punct(':', Spacing::Joint),
+        punct(':', Spacing::Joint),
+        TokenTree::Ident(Ident::new("compile_error", span)),
+        punct('!', Spacing::Alone),
+        TokenTree::Group(group),
+    ]);
 
-            if value.is_empty() {
-                let span = literal.span();
-                return error(b"String literals in the attribute cannot be empty.", span);
-            }
+    stream
+}
 
-            if ids.contains(&value) {
-                let span = literal.span();
-                return error(b"Duplicated string literal found.", span);
-            }
+/// Fallback arm of the matches of `FromStr`, like `_ => Err(UnknownId),`.
+#[cfg(feature = "from-str")]
+fn unknown_id_arm(unknown_id_name: &str, span: Span) -> [TokenTree; 16] {
+    [
+        TokenTree::Ident(Ident::new("_", span)),
+        TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+        TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("core", span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("result", span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("Result", span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("Err", span)),
+        TokenTree::Group(Group::new(
+            Delimiter::Parenthesis,
+            TokenStream::from(TokenTree::Ident(Ident::new(unknown_id_name, span))),
+        )),
+    ]
+}
 
-            let maybe_pascal = pascal_case::to_pascal_case(&value);
-            if let Err(err) = maybe_pascal {
-                let span = literal.span();
-                return error(err, span);
-            }
-            let pascal = maybe_pascal.unwrap();
-            let ident = Ident::new(&pascal, call_site_span);
-            ids_variants_idents.push(ident);
-            ids.push(value);
-        } else if let TokenTree::Punct(punct) = token {
-            if punct.as_char() != ',' {
-                let span = punct.span();
-                return error(
-                    b"Expected a comma between string literals in the attribute.",
-                    span,
-                );
-            }
-        } else {
-            let span = token.span();
+/// Body of `FromStr::from_str` with the `phf` feature, which computes the slot
+/// of the string in the perfect hash map of the identifiers built while expanding
+/// the macro, comparing it only against the identifier of that slot.
+#[cfg(feature = "from-str")]
+fn phf_from_str_body(
+    profiles: &[(TokenStream, Vec<String>, Vec<usize>)],
+    ids_variants_idents: &[Ident],
+    ids_cfg_attributes: &[TokenStream],
+    unknown_id_name: &str,
+    span: Span,
+) -> TokenStream {
+    let mut inner: Vec<TokenTree> = Vec::new();
+    let mut arms: Vec<TokenTree> = Vec::new();
+    for (cfg, profile_ids, _) in profiles {
+        let Some(table) = phf::table(profile_ids) else {
             return error(
-                b"Expected only string literals and commas in the attribute.",
+                "Failed to build the perfect hash map of the identifiers with the `phf` feature.",
                 span,
             );
+        };
+
+        let mut displacements: Vec<TokenTree> = Vec::with_capacity(table.displacements.len() * 2);
+        for (d1, d2) in &table.displacements {
+            displacements.extend([
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Literal(Literal::u64_unsuffixed(*d1)),
+                        TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                        TokenTree::Literal(Literal::u64_unsuffixed(*d2)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+                TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+            ]);
         }
-    }
+        // with a single bucket the modulo is always zero
+        let bucket_index: TokenStream = if table.displacements.len() == 1 {
+            TokenStream::from(TokenTree::Literal(Literal::usize_unsuffixed(0)))
+        } else {
+            [
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Group(Group::new(
+                            Delimiter::Parenthesis,
+                            [
+                                TokenTree::Ident(Ident::new("hash", span)),
+                                TokenTree::Punct(Punct::new('>', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                                TokenTree::Literal(Literal::usize_unsuffixed(32)),
+                            ]
+                            .into_iter()
+                            .collect(),
+                        )),
+                        TokenTree::Punct(Punct::new('%', Spacing::Alone)),
+                        TokenTree::Literal(Literal::usize_unsuffixed(table.displacements.len())),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+                TokenTree::Ident(Ident::new("as", span)),
+                TokenTree::Ident(Ident::new("usize", span)),
+            ]
+            .into_iter()
+            .collect()
+        };
 
-    let ids_length = ids.len();
+        let mut block: Vec<TokenTree> = Vec::with_capacity(128);
+        block.extend([
+            TokenTree::Ident(Ident::new("const", span)),
+            TokenTree::Ident(Ident::new("DISPLACEMENTS", span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+            TokenTree::Punct(Punct::new('&', Spacing::Alone)),
+            TokenTree::Group(Group::new(
+                Delimiter::Bracket,
+                [TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Ident(Ident::new("u64", span)),
+                        TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("u64", span)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                ))]
+                .into_iter()
+                .collect(),
+            )),
+            TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+            TokenTree::Punct(Punct::new('&', Spacing::Alone)),
+            TokenTree::Group(Group::new(
+                Delimiter::Bracket,
+                displacements.into_iter().collect(),
+            )),
+            TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+        ]);
+        block.extend([
+            TokenTree::Ident(Ident::new("let", span)),
+            TokenTree::Ident(Ident::new("mut", span)),
+            TokenTree::Ident(Ident::new("hash", span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("u64", span)),
+            TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+            TokenTree::Literal(Literal::u64_unsuffixed(table.key)),
+            TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("for", span)),
+            TokenTree::Ident(Ident::new("byte", span)),
+            TokenTree::Ident(Ident::new("in", span)),
+            TokenTree::Ident(Ident::new("s", span)),
+            TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("bytes", span)),
+            TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+            TokenTree::Group(Group::new(
+                Delimiter::Brace,
+                [
+                    TokenTree::Ident(Ident::new("hash", span)),
+                    TokenTree::Punct(Punct::new('^', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("u64", span)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("from", span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        [TokenTree::Ident(Ident::new("byte", span))]
+                            .into_iter()
+                            .collect(),
+                    )),
+                    TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("hash", span)),
+                    TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("hash", span)),
+                    TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("wrapping_mul", span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        [TokenTree::Literal(Literal::u64_unsuffixed(phf::FNV_PRIME))]
+                            .into_iter()
+                            .collect(),
+                    )),
+                    TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+            TokenTree::Ident(Ident::new("hash", span)),
+            TokenTree::Punct(Punct::new('^', Spacing::Joint)),
+            TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("hash", span)),
+            TokenTree::Punct(Punct::new('>', Spacing::Joint)),
+            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+            TokenTree::Literal(Literal::usize_unsuffixed(33)),
+            TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("hash", span)),
+            TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("hash", span)),
+            TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("wrapping_mul", span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Parenthesis,
+                [TokenTree::Literal(Literal::u64_unsuffixed(
+                    phf::MIX_MULTIPLIER,
+                ))]
+                .into_iter()
+                .collect(),
+            )),
+            TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("hash", span)),
+            TokenTree::Punct(Punct::new('^', Spacing::Joint)),
+            TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("hash", span)),
+            TokenTree::Punct(Punct::new('>', Spacing::Joint)),
+            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+            TokenTree::Literal(Literal::usize_unsuffixed(33)),
+            TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("let", span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Parenthesis,
+                [
+                    TokenTree::Ident(Ident::new("d1", span)),
+                    TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("d2", span)),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+            TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("DISPLACEMENTS", span)),
+            TokenTree::Group(Group::new(Delimiter::Bracket, bucket_index)),
+            TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("d2", span)),
+            TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("wrapping_add", span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Parenthesis,
+                [
+                    TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        [
+                            TokenTree::Ident(Ident::new("hash", span)),
+                            TokenTree::Punct(Punct::new('&', Spacing::Alone)),
+                            TokenTree::Literal(Literal::u64_unsuffixed(0xffff_ffff)),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                    TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("wrapping_mul", span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        [TokenTree::Ident(Ident::new("d1", span))]
+                            .into_iter()
+                            .collect(),
+                    )),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+            TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("wrapping_add", span)),
+            TokenTree::Group(Group::new(
+                Delimiter::Parenthesis,
+                [
+                    TokenTree::Ident(Ident::new("hash", span)),
+                    TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("wrapping_mul", span)),
+                    TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        [TokenTree::Literal(Literal::u64_unsuffixed(
+                            phf::GOLDEN_RATIO,
+                        ))]
+                        .into_iter()
+                        .collect(),
+                    )),
+                    TokenTree::Punct(Punct::new('>', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                    TokenTree::Literal(Literal::usize_unsuffixed(32)),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+            TokenTree::Punct(Punct::new('%', Spacing::Alone)),
+            TokenTree::Literal(Literal::usize_unsuffixed(table.slots.len())),
+        ]);
+        inner.extend(cfg.clone());
+        inner.extend([
+            TokenTree::Ident(Ident::new("let", span)),
+            TokenTree::Ident(Ident::new("slot", span)),
+            TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+            // a single identifier is always in the first slot
+            if table.slots.len() == 1 {
+                TokenTree::Literal(Literal::u64_unsuffixed(0))
+            } else {
+                TokenTree::Group(Group::new(Delimiter::Brace, block.into_iter().collect()))
+            },
+            TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+        ]);
 
-    if ids_length == 0 {
-        return error(
-            b"Expected at least one string literal in the attribute.",
-            call_site_span,
-        );
+        // 3 if s == "preview" => Ok(Self::Preview),
+        let mut ids_by_slot: Vec<usize> = (0..profile_ids.len()).collect();
+        ids_by_slot.sort_by_key(|i| table.slots[*i]);
+        for i in ids_by_slot {
+            arms.extend(cfg.clone());
+            arms.extend(ids_cfg_attributes[i].clone());
+            arms.extend([
+                TokenTree::Literal(Literal::u64_unsuffixed(table.slots[i])),
+                TokenTree::Ident(Ident::new("if", span)),
+                TokenTree::Ident(Ident::new("s", span)),
+                TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                TokenTree::Literal(Literal::string(&profile_ids[i])),
+                TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("core", span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("result", span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("Result", span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new("Ok", span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [
+                        TokenTree::Ident(Ident::new("Self", span)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Ident(ids_variants_idents[i].clone()),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )),
+                TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+            ]);
+        }
     }
+    arms.extend(unknown_id_arm(unknown_id_name, span));
+    inner.extend([
+        TokenTree::Ident(Ident::new("match", span)),
+        TokenTree::Ident(Ident::new("slot", span)),
+        TokenTree::Group(Group::new(Delimiter::Brace, arms.into_iter().collect())),
+    ]);
+    inner.into_iter().collect()
+}
 
-    // remove the last token and add the implementation
-    let mut tokens: Vec<TokenTree> = item.into_iter().collect();
-    tokens.pop();
+/// `#[must_use]` attribute for the accessors of the enum, which are pure, so
+/// discarding their result is always a mistake.
+fn must_use_attribute(span: Span) -> [TokenTree; 2] {
+    [
+        TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+        TokenTree::Group(Group::new(
+            Delimiter::Bracket,
+            TokenStream::from(TokenTree::Ident(Ident::new("must_use", span))),
+        )),
+    ]
+}
 
-    // enum declaration
-    let group = Group::new(Delimiter::Brace, {
-        let mut inner = TokenStream::new();
-        for i in 0..ids_length {
-            let ident = &ids_variants_idents[i];
-            let id = &ids[i];
-            inner.extend([
+/// Constant using a deprecated unit struct, to emit the note as a warning
+/// pointing to the span, because procedural macros can't emit warnings on
+/// stable Rust.
+///
+/// ```rust,ignore
+/// const _: () = {
+///     #[deprecated(note = "...")]
+///     struct SimilarIdentifiers;
+///     let _ = SimilarIdentifiers;
+/// };
+/// ```
+fn similar_id_warning(note: &str, span: Span) -> [TokenTree; 7] {
+    [
+        TokenTree::Ident(Ident::new("const", span)),
+        TokenTree::Ident(Ident::new("_", span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+        TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+        TokenTree::Group(Group::new(
+            Delimiter::Brace,
+            [
                 TokenTree::Punct(Punct::new('#', Spacing::Alone)),
                 TokenTree::Group(Group::new(
                     Delimiter::Bracket,
                     [
-                        TokenTree::Ident(Ident::new("doc", call_site_span)),
-                        TokenTree::Punct(Punct::new('=', Spacing::Alone)),
-                        TokenTree::Literal(Literal::string(&format!("{id:?}"))),
+                        TokenTree::Ident(Ident::new("deprecated", span)),
+                        TokenTree::Group(Group::new(
+                            Delimiter::Parenthesis,
+                            [
+                                TokenTree::Ident(Ident::new("note", span)),
+                                TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                                TokenTree::Literal(Literal::string(note)),
+                            ]
+                            .into_iter()
+                            .collect(),
+                        )),
                     ]
                     .into_iter()
                     .collect(),
                 )),
-                TokenTree::Ident(ident.clone()),
-                TokenTree::Punct(Punct::new(',', Spacing::Alone)),
-            ]);
-        }
-        inner
-    });
-    tokens.push(TokenTree::Group(group));
+                TokenTree::Ident(Ident::new("struct", span)),
+                TokenTree::Ident(Ident::new("SimilarIdentifiers", span)),
+                TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("let", span)),
+                TokenTree::Ident(Ident::new("_", span)),
+                TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("SimilarIdentifiers", span)),
+                TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+            ]
+            .into_iter()
+            .collect(),
+        )),
+        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+    ]
+}
 
-    // as_str impl
-    tokens.extend([
-        TokenTree::Ident(Ident::new("impl", call_site_span)),
-        TokenTree::Ident(Ident::new("Ids", call_site_span)),
-    ]);
+/// Quote an identifier like its `Debug` representation, which only needs to
+/// be formatted when it contains characters that must be escaped.
+fn quoted(id: &str) -> String {
+    if id
+        .bytes()
+        .all(|byte| byte.is_ascii_graphic() && byte != b'"' && byte != b'\\')
+    {
+        let mut quoted = String::with_capacity(id.len() + 2);
+        quoted.push('"');
+        quoted.push_str(id);
+        quoted.push('"');
+        quoted
+    } else {
+        format!("{id:?}")
+    }
+}
 
-    let impl_group = Group::new(Delimiter::Brace, {
-        let mut inner = TokenStream::new();
+/// `const _: &[u8] = ::core::include_bytes!("path");` item to make Cargo track
+/// a file read while expanding the macro.
+fn include_bytes_item(path: &str, span: Span) -> [TokenTree; 15] {
+    [
+        TokenTree::Ident(Ident::new("const", span)),
+        TokenTree::Ident(Ident::new("_", span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Punct(Punct::new('&', Spacing::Alone)),
+        TokenTree::Group(Group::new(
+            Delimiter::Bracket,
+            TokenStream::from(TokenTree::Ident(Ident::new("u8", span))),
+        )),
+        TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("core", span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("include_bytes", span)),
+        TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+        TokenTree::Group(Group::new(
+            Delimiter::Parenthesis,
+            TokenStream::from(TokenTree::Literal(Literal::string(path))),
+        )),
+        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+    ]
+}
 
-        if let Some(vis) = vis {
-            inner.extend(vis);
+/// Same as [`error`], adding a help line to the message to guide users.
+fn error_with_help(message: &str, help: &str, span: Span) -> TokenStream {
+    error(&format!("{message}\nhelp: {help}"), span)
+}
+
+/// Parse an identifier of the attribute, either a string literal or a string
+/// literal with documentation like `("language-selector", doc = "...")`.
+///
+/// Returns `None` if the token is not an identifier.
+fn id_literal(token: &TokenTree) -> Option<Result<(Literal, Option<String>), TokenStream>> {
+    match token {
+        TokenTree::Literal(literal) => Some(Ok((literal.clone(), None))),
+        TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis => {
+            let mut group_iter = group.stream().into_iter();
+            let (
+                Some(TokenTree::Literal(literal)),
+                Some(TokenTree::Punct(comma)),
+                Some(TokenTree::Ident(ident)),
+                Some(TokenTree::Punct(eq)),
+                value,
+            ) = (
+                group_iter.next(),
+                group_iter.next(),
+                group_iter.next(),
+                group_iter.next(),
+                group_iter.next(),
+            )
+            else {
+                return Some(Err(error(
+                    "Expected a string literal followed by `doc = \"...\"` in the parentheses.",
+                    group.span(),
+                )));
+            };
+            if comma.as_char() != ','
+                || ident.to_string() != "doc"
+                || eq.as_char() != '='
+                || group_iter.any(
+                    |token| !matches!(&token, TokenTree::Punct(punct) if punct.as_char() == ','),
+                )
+            {
+                return Some(Err(error(
+                    "Expected a string literal followed by `doc = \"...\"` in the parentheses.",
+                    group.span(),
+                )));
+            }
+            Some(string_literal_value(value, ident.span()).map(|doc| (literal, Some(doc))))
         }
+        _ => None,
+    }
+}
 
-        inner.extend([
-            TokenTree::Ident(Ident::new("fn", call_site_span)),
-            TokenTree::Ident(Ident::new("as_str", call_site_span)),
+/// Parse the brackets of a `#[cfg(...)]` attribute written before an identifier,
+/// returning the predicate between its parentheses.
+///
+/// Returns `None` if the token is not a `cfg` attribute with a predicate.
+fn cfg_predicate(token: Option<TokenTree>) -> Option<TokenStream> {
+    let Some(TokenTree::Group(group)) = token else {
+        return None;
+    };
+    if group.delimiter() != Delimiter::Bracket {
+        return None;
+    }
+    let mut group_iter = group.stream().into_iter();
+    match (group_iter.next(), group_iter.next(), group_iter.next()) {
+        (Some(TokenTree::Ident(ident)), Some(TokenTree::Group(predicate)), None)
+            if ident.to_string() == "cfg"
+                && predicate.delimiter() == Delimiter::Parenthesis
+                && !predicate.stream().is_empty() =>
+        {
+            Some(predicate.stream())
+        }
+        _ => None,
+    }
+}
+
+/// Build the `#[cfg(...)]` attribute of the items of a conditionally compiled
+/// identifier, which is empty for the rest of identifiers.
+fn cfg_attribute(predicate: Option<&TokenStream>, span: Span) -> TokenStream {
+    let Some(predicate) = predicate else {
+        return TokenStream::new();
+    };
+    [
+        TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+        TokenTree::Group(Group::new(
+            Delimiter::Bracket,
+            [
+                TokenTree::Ident(Ident::new("cfg", span)),
+                TokenTree::Group(Group::new(Delimiter::Parenthesis, predicate.clone())),
+            ]
+            .into_iter()
+            .collect(),
+        )),
+    ]
+    .into_iter()
+    .collect()
+}
+
+/// Build the `#[cfg(...)]` attribute of an arm that is only compiled when none
+/// of the `skipped` predicates hold, and the `predicate` does if passed, like
+/// `#[cfg(all(not(feature = "admin"), feature = "beta"))]`.
+fn cfg_chain_attribute(
+    skipped: &[&TokenStream],
+    predicate: Option<&TokenStream>,
+    span: Span,
+) -> TokenStream {
+    let mut predicates: Vec<TokenTree> = Vec::with_capacity(skipped.len() * 3 + 1);
+    for skipped in skipped {
+        if !predicates.is_empty() {
+            predicates.push(TokenTree::Punct(Punct::new(',', Spacing::Alone)));
+        }
+        predicates.extend([
+            TokenTree::Ident(Ident::new("not", span)),
+            TokenTree::Group(Group::new(Delimiter::Parenthesis, (*skipped).clone())),
+        ]);
+    }
+    if let Some(predicate) = predicate {
+        if !predicates.is_empty() {
+            predicates.push(TokenTree::Punct(Punct::new(',', Spacing::Alone)));
+        }
+        predicates.extend(predicate.clone());
+    }
+    cfg_attribute(
+        Some(&TokenStream::from_iter([
+            TokenTree::Ident(Ident::new("all", span)),
             TokenTree::Group(Group::new(
                 Delimiter::Parenthesis,
-                [
-                    TokenTree::Punct(Punct::new('&', Spacing::Joint)),
-                    TokenTree::Ident(Ident::new("self", call_site_span)),
-                ]
-                .into_iter()
-                .collect(),
+                predicates.into_iter().collect(),
             )),
-            TokenTree::Punct(Punct::new('-', Spacing::Joint)),
-            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
-            TokenTree::Punct(Punct::new('&', Spacing::Joint)),
-            TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
-            TokenTree::Ident(Ident::new("static", call_site_span)),
-            TokenTree::Ident(Ident::new("str", call_site_span)),
+        ])),
+        span,
+    )
+}
+
+/// Build the position of an identifier after the `preceding` ones, counting
+/// the conditionally compiled ones with `cfg!`, like
+/// `1 + ::core::cfg!(feature = "admin") as usize`.
+fn position_expression<'a>(
+    preceding: impl Iterator<Item = Option<&'a TokenStream>>,
+    span: Span,
+) -> TokenStream {
+    let mut position = 0;
+    let mut terms: Vec<TokenTree> = Vec::new();
+    for predicate in preceding {
+        let Some(predicate) = predicate else {
+            position += 1;
+            continue;
+        };
+        if !terms.is_empty() {
+            terms.push(TokenTree::Punct(Punct::new('+', Spacing::Alone)));
+        }
+        terms.extend([
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("core", span)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("cfg", span)),
+            TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+            TokenTree::Group(Group::new(Delimiter::Parenthesis, predicate.clone())),
+            TokenTree::Ident(Ident::new("as", span)),
+            TokenTree::Ident(Ident::new("usize", span)),
         ]);
+    }
+    if terms.is_empty() {
+        return TokenStream::from(TokenTree::Literal(Literal::usize_unsuffixed(position)));
+    }
+    let mut expression: Vec<TokenTree> = Vec::with_capacity(terms.len() + 2);
+    if position > 0 {
+        expression.extend([
+            TokenTree::Literal(Literal::usize_unsuffixed(position)),
+            TokenTree::Punct(Punct::new('+', Spacing::Alone)),
+        ]);
+    }
+    expression.extend(terms);
+    expression.into_iter().collect()
+}
 
-        let group = Group::new(
-            Delimiter::Brace,
+/// Build the pattern and the guard of a match arm for a position built by
+/// [`position_expression`]. Positions counting conditionally compiled
+/// identifiers aren't literals, so they are bound and compared in the guard,
+/// like `position if position == 1 + ::core::cfg!(feature = "admin") as usize`.
+fn position_pattern(expression: TokenStream, span: Span) -> (TokenStream, TokenStream) {
+    let expression: Vec<TokenTree> = expression.into_iter().collect();
+    if expression.len() == 1 {
+        return (expression.into_iter().collect(), TokenStream::new());
+    }
+    let mut guard: Vec<TokenTree> = Vec::with_capacity(expression.len() + 4);
+    guard.extend([
+        TokenTree::Ident(Ident::new("if", span)),
+        TokenTree::Ident(Ident::new("position", span)),
+        TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+        TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+    ]);
+    guard.extend(expression);
+    (
+        TokenStream::from(TokenTree::Ident(Ident::new("position", span))),
+        guard.into_iter().collect(),
+    )
+}
+
+/// Build a block joining the selectors of `parts` with commas at compile time,
+/// used for `ALL_SELECTORS` when some identifiers are conditionally compiled,
+/// so the selectors can't be joined while expanding the macro.
+fn joined_selectors_block(parts: TokenStream, span: Span) -> TokenTree {
+    let mut inner: Vec<TokenTree> = Vec::with_capacity(128);
+    inner.extend([
+        TokenTree::Ident(Ident::new("const", span)),
+        TokenTree::Ident(Ident::new("PARTS", span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Punct(Punct::new('&', Spacing::Alone)),
+        TokenTree::Group(Group::new(
+            Delimiter::Bracket,
             [
-                TokenTree::Ident(Ident::new("match", call_site_span)),
-                TokenTree::Ident(Ident::new("self", call_site_span)),
-                TokenTree::Group(Group::new(Delimiter::Brace, {
-                    let mut inner = TokenStream::new();
-                    for i in 0..ids_length {
-                        let id = &ids[i];
-                        let ident = &ids_variants_idents[i];
-                        inner.extend([
-                            TokenTree::Ident(Ident::new("Self", call_site_span)),
-                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-                            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-                            TokenTree::Ident(ident.to_owned()),
-                            TokenTree::Punct(Punct::new('=', Spacing::Joint)),
-                            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
-                            TokenTree::Literal(Literal::string(id)),
-                            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
-                        ]);
-                    }
-                    inner
-                })),
+                TokenTree::Punct(Punct::new('&', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("str", span)),
             ]
             .into_iter()
             .collect(),
-        );
-        inner.extend([TokenTree::Group(group)]);
-
-        inner
-    });
-    tokens.push(TokenTree::Group(impl_group));
-
-    // Into<&'static str> impl
-    #[cfg(feature = "into-str")]
-    tokens.extend([
-        TokenTree::Ident(Ident::new("impl", call_site_span)),
-        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-        TokenTree::Ident(Ident::new("std", call_site_span)),
-        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-        TokenTree::Ident(Ident::new("convert", call_site_span)),
-        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-        TokenTree::Ident(Ident::new("Into", call_site_span)),
-        TokenTree::Punct(Punct::new('<', Spacing::Joint)),
-        TokenTree::Punct(Punct::new('&', Spacing::Joint)),
-        TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
-        TokenTree::Ident(Ident::new("static", call_site_span)),
-        TokenTree::Ident(Ident::new("str", call_site_span)),
-        TokenTree::Punct(Punct::new('>', Spacing::Alone)),
-        TokenTree::Ident(Ident::new("for", call_site_span)),
-        TokenTree::Ident(Ident::new("Ids", call_site_span)),
+        )),
+        TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+        TokenTree::Punct(Punct::new('&', Spacing::Alone)),
+        TokenTree::Group(Group::new(Delimiter::Bracket, parts)),
+        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+    ]);
+    inner.extend([
+        TokenTree::Ident(Ident::new("const", span)),
+        TokenTree::Ident(Ident::new("LENGTH", span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("usize", span)),
+        TokenTree::Punct(Punct::new('=', Spacing::Alone)),
         TokenTree::Group(Group::new(
             Delimiter::Brace,
             [
-                TokenTree::Ident(Ident::new("fn", call_site_span)),
-                TokenTree::Ident(Ident::new("into", call_site_span)),
+                TokenTree::Ident(Ident::new("let", span)),
+                TokenTree::Ident(Ident::new("mut", span)),
+                TokenTree::Ident(Ident::new("length", span)),
+                TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("PARTS", span)),
+                TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("len", span)),
+                TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+                TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("saturating_sub", span)),
                 TokenTree::Group(Group::new(
                     Delimiter::Parenthesis,
-                    TokenStream::from(TokenTree::Ident(Ident::new("self", call_site_span))),
+                    [TokenTree::Literal(Literal::usize_unsuffixed(1))]
+                        .into_iter()
+                        .collect(),
                 )),
-                TokenTree::Punct(Punct::new('-', Spacing::Joint)),
-                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
-                TokenTree::Punct(Punct::new('&', Spacing::Joint)),
-                TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
-                TokenTree::Ident(Ident::new("static", call_site_span)),
-                TokenTree::Ident(Ident::new("str", call_site_span)),
+                TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("let", span)),
+                TokenTree::Ident(Ident::new("mut", span)),
+                TokenTree::Ident(Ident::new("i", span)),
+                TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                TokenTree::Literal(Literal::usize_unsuffixed(0)),
+                TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("while", span)),
+                TokenTree::Ident(Ident::new("i", span)),
+                TokenTree::Punct(Punct::new('<', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("PARTS", span)),
+                TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("len", span)),
+                TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
                 TokenTree::Group(Group::new(
                     Delimiter::Brace,
                     [
-                        TokenTree::Ident(Ident::new("self", call_site_span)),
-                        TokenTree::Punct(Punct::new('.', Spacing::Joint)),
-                        TokenTree::Ident(Ident::new("as_str", call_site_span)),
+                        TokenTree::Ident(Ident::new("length", span)),
+                        TokenTree::Punct(Punct::new('+', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("PARTS", span)),
+                        TokenTree::Group(Group::new(
+                            Delimiter::Bracket,
+                            [TokenTree::Ident(Ident::new("i", span))]
+                                .into_iter()
+                                .collect(),
+                        )),
+                        TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("len", span)),
                         TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+                        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("i", span)),
+                        TokenTree::Punct(Punct::new('+', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                        TokenTree::Literal(Literal::usize_unsuffixed(1)),
+                        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
                     ]
                     .into_iter()
                     .collect(),
                 )),
+                TokenTree::Ident(Ident::new("length", span)),
             ]
             .into_iter()
             .collect(),
         )),
-    ]);
-
-    // leptos::prelude::IntoAttributeValue impl
-    #[cfg(feature = "into-attribute-value")]
-    tokens.extend([
-        TokenTree::Ident(Ident::new("impl", call_site_span)),
-        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-        TokenTree::Ident(Ident::new("leptos", call_site_span)),
-        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-        TokenTree::Ident(Ident::new("prelude", call_site_span)),
-        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-        TokenTree::Ident(Ident::new("IntoAttributeValue", call_site_span)),
-        TokenTree::Ident(Ident::new("for", call_site_span)),
-        TokenTree::Ident(Ident::new("Ids", call_site_span)),
+        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("const", span)),
+        TokenTree::Ident(Ident::new("BYTES", span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Group(Group::new(
+            Delimiter::Bracket,
+            [
+                TokenTree::Ident(Ident::new("u8", span)),
+                TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("LENGTH", span)),
+            ]
+            .into_iter()
+            .collect(),
+        )),
+        TokenTree::Punct(Punct::new('=', Spacing::Alone)),
         TokenTree::Group(Group::new(
             Delimiter::Brace,
             [
-                TokenTree::Ident(Ident::new("type", call_site_span)),
-                TokenTree::Ident(Ident::new("Output", call_site_span)),
+                TokenTree::Ident(Ident::new("let", span)),
+                TokenTree::Ident(Ident::new("mut", span)),
+                TokenTree::Ident(Ident::new("bytes", span)),
                 TokenTree::Punct(Punct::new('=', Spacing::Alone)),
-                TokenTree::Punct(Punct::new('&', Spacing::Joint)),
-                TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
-                TokenTree::Ident(Ident::new("static", call_site_span)),
-                TokenTree::Ident(Ident::new("str", call_site_span)),
-                TokenTree::Punct(Punct::new(';', Spacing::Joint)),
-                TokenTree::Ident(Ident::new("fn", call_site_span)),
-                TokenTree::Ident(Ident::new("into_attribute_value", call_site_span)),
                 TokenTree::Group(Group::new(
-                    Delimiter::Parenthesis,
-                    TokenStream::from(TokenTree::Ident(Ident::new("self", call_site_span))),
+                    Delimiter::Bracket,
+                    [
+                        TokenTree::Literal(Literal::usize_unsuffixed(0)),
+                        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("LENGTH", span)),
+                    ]
+                    .into_iter()
+                    .collect(),
                 )),
-                TokenTree::Punct(Punct::new('-', Spacing::Joint)),
-                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
-                TokenTree::Ident(Ident::new("Self", call_site_span)),
-                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-                TokenTree::Ident(Ident::new("Output", call_site_span)),
+                TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("let", span)),
+                TokenTree::Ident(Ident::new("mut", span)),
+                TokenTree::Ident(Ident::new("position", span)),
+                TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                TokenTree::Literal(Literal::usize_unsuffixed(0)),
+                TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("let", span)),
+                TokenTree::Ident(Ident::new("mut", span)),
+                TokenTree::Ident(Ident::new("i", span)),
+                TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                TokenTree::Literal(Literal::usize_unsuffixed(0)),
+                TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("while", span)),
+                TokenTree::Ident(Ident::new("i", span)),
+                TokenTree::Punct(Punct::new('<', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("PARTS", span)),
+                TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("len", span)),
+                TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
                 TokenTree::Group(Group::new(
                     Delimiter::Brace,
                     [
-                        TokenTree::Ident(Ident::new("self", call_site_span)),
-                        TokenTree::Punct(Punct::new('.', Spacing::Joint)),
-                        TokenTree::Ident(Ident::new("as_str", call_site_span)),
+                        TokenTree::Ident(Ident::new("if", span)),
+                        TokenTree::Ident(Ident::new("i", span)),
+                        TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                        TokenTree::Literal(Literal::usize_unsuffixed(0)),
+                        TokenTree::Group(Group::new(
+                            Delimiter::Brace,
+                            [
+                                TokenTree::Ident(Ident::new("bytes", span)),
+                                TokenTree::Group(Group::new(
+                                    Delimiter::Bracket,
+                                    [TokenTree::Ident(Ident::new("position", span))]
+                                        .into_iter()
+                                        .collect(),
+                                )),
+                                TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                                TokenTree::Literal(Literal::byte_character(b',')),
+                                TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("position", span)),
+                                TokenTree::Punct(Punct::new('+', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                                TokenTree::Literal(Literal::usize_unsuffixed(1)),
+                                TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                            ]
+                            .into_iter()
+                            .collect(),
+                        )),
+                        TokenTree::Ident(Ident::new("let", span)),
+                        TokenTree::Ident(Ident::new("part", span)),
+                        TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("PARTS", span)),
+                        TokenTree::Group(Group::new(
+                            Delimiter::Bracket,
+                            [TokenTree::Ident(Ident::new("i", span))]
+                                .into_iter()
+                                .collect(),
+                        )),
+                        TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("as_bytes", span)),
+                        TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+                        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("let", span)),
+                        TokenTree::Ident(Ident::new("mut", span)),
+                        TokenTree::Ident(Ident::new("j", span)),
+                        TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                        TokenTree::Literal(Literal::usize_unsuffixed(0)),
+                        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("while", span)),
+                        TokenTree::Ident(Ident::new("j", span)),
+                        TokenTree::Punct(Punct::new('<', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("part", span)),
+                        TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("len", span)),
                         TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+                        TokenTree::Group(Group::new(
+                            Delimiter::Brace,
+                            [
+                                TokenTree::Ident(Ident::new("bytes", span)),
+                                TokenTree::Group(Group::new(
+                                    Delimiter::Bracket,
+                                    [TokenTree::Ident(Ident::new("position", span))]
+                                        .into_iter()
+                                        .collect(),
+                                )),
+                                TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("part", span)),
+                                TokenTree::Group(Group::new(
+                                    Delimiter::Bracket,
+                                    [TokenTree::Ident(Ident::new("j", span))]
+                                        .into_iter()
+                                        .collect(),
+                                )),
+                                TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("position", span)),
+                                TokenTree::Punct(Punct::new('+', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                                TokenTree::Literal(Literal::usize_unsuffixed(1)),
+                                TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                                TokenTree::Ident(Ident::new("j", span)),
+                                TokenTree::Punct(Punct::new('+', Spacing::Joint)),
+                                TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                                TokenTree::Literal(Literal::usize_unsuffixed(1)),
+                                TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                            ]
+                            .into_iter()
+                            .collect(),
+                        )),
+                        TokenTree::Ident(Ident::new("i", span)),
+                        TokenTree::Punct(Punct::new('+', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+                        TokenTree::Literal(Literal::usize_unsuffixed(1)),
+                        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
                     ]
                     .into_iter()
                     .collect(),
                 )),
+                TokenTree::Ident(Ident::new("bytes", span)),
+            ]
+            .into_iter()
+            .collect(),
+        )),
+        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("match", span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("core", span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("str", span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("from_utf8", span)),
+        TokenTree::Group(Group::new(
+            Delimiter::Parenthesis,
+            [
+                TokenTree::Punct(Punct::new('&', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("BYTES", span)),
+            ]
+            .into_iter()
+            .collect(),
+        )),
+        TokenTree::Group(Group::new(
+            Delimiter::Brace,
+            [
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("core", span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("result", span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("Result", span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("Ok", span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [TokenTree::Ident(Ident::new("selectors", span))]
+                        .into_iter()
+                        .collect(),
+                )),
+                TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("selectors", span)),
+                TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("core", span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("result", span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("Result", span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("Err", span)),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    [TokenTree::Ident(Ident::new("_", span))]
+                        .into_iter()
+                        .collect(),
+                )),
+                TokenTree::Punct(Punct::new('=', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("core", span)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("unreachable", span)),
+                TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+                TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+                TokenTree::Punct(Punct::new(',', Spacing::Alone)),
             ]
             .into_iter()
             .collect(),
         )),
     ]);
+    TokenTree::Group(Group::new(Delimiter::Brace, inner.into_iter().collect()))
+}
 
-    tokens.into_iter().collect()
+/// Parse a boolean passed as value of an argument of the attribute.
+///
+/// Arguments without value are considered flags, so they are `true`.
+fn bool_value(token: Option<TokenTree>) -> Result<bool, TokenStream> {
+    match token {
+        None => Ok(true),
+        Some(TokenTree::Ident(ident)) if ident.to_string() == "true" => Ok(true),
+        Some(TokenTree::Ident(ident)) if ident.to_string() == "false" => Ok(false),
+        Some(token) => Err(error(
+            "Expected `true` or `false` as argument value.",
+            token.span(),
+        )),
+    }
 }
 
-fn error(message: &[u8], span: Span) -> TokenStream {
-    let mut error_message = Literal::string(&String::from_utf8_lossy(message));
-    error_message.set_span(span);
+/// Parse a string literal passed as value of an argument of the attribute.
+fn string_literal_value(
+    token: Option<TokenTree>,
+    argument_span: Span,
+) -> Result<String, TokenStream> {
+    let Some(TokenTree::Literal(literal)) = token else {
+        return Err(error(
+            "Expected a string literal as argument value.",
+            argument_span,
+        ));
+    };
+    let literal_str = literal.to_string();
+    match value_from_literal_str(&literal_str) {
+        Ok(value) => Ok(value.to_string()),
+        Err(err) => Err(error(err, literal.span())),
+    }
+}
 
-    let mut stream = TokenStream::new();
-    stream.extend([
-        TokenTree::Ident(Ident::new("compile_error", span)),
-        TokenTree::Punct({
-            let mut punct = Punct::new('!', Spacing::Alone);
-            punct.set_span(span);
-            punct
-        }),
+/// Parse a list of string literals like `["foo", "bar"]` passed as value of an
+/// argument of the attribute.
+fn string_literals_from_list(
+    token: Option<TokenTree>,
+    argument_span: Span,
+) -> Result<Vec<String>, TokenStream> {
+    let Some(TokenTree::Group(group)) = token else {
+        return Err(error(
+            "Expected a list of string literals as argument value.",
+            argument_span,
+        ));
+    };
+    if group.delimiter() != Delimiter::Bracket {
+        return Err(error(
+            "Expected a list of string literals as argument value.",
+            group.span(),
+        ));
+    }
+
+    let mut values = Vec::new();
+    for token in group.stream() {
+        if let TokenTree::Literal(literal) = token {
+            let literal_str = literal.to_string();
+            match value_from_literal_str(&literal_str) {
+                Ok(value) => values.push(value.to_string()),
+                Err(err) => return Err(error(err, literal.span())),
+            }
+        } else if !matches!(&token, TokenTree::Punct(punct) if punct.as_char() == ',') {
+            return Err(error(
+                "Expected only string literals and commas in the list.",
+                token.span(),
+            ));
+        }
+    }
+    Ok(values)
+}
+
+/// Short hash of an identifier emitted in release builds with `hashed_in_release`.
+///
+/// It's the 32 bits FNV-1a hash of the identifier in base 36, prefixed with a
+/// letter so it's also a valid HTML 4 id and CSS selector.
+fn short_hash(id: &str) -> String {
+    let mut hash: u32 = 0x811c_9dc5;
+    for byte in id.bytes() {
+        hash ^= u32::from(byte);
+        hash = hash.wrapping_mul(0x0100_0193);
+    }
+
+    let mut digits = Vec::new();
+    loop {
+        digits.push(char::from_digit(hash % 36, 36).unwrap());
+        hash /= 36;
+        if hash == 0 {
+            break;
+        }
+    }
+    std::iter::once('h')
+        .chain(digits.into_iter().rev())
+        .collect()
+}
+
+/// Attribute `#[cfg(debug_assertions)]`, or `#[cfg(not(debug_assertions))]` to
+/// select the items of release builds.
+fn debug_assertions_cfg(release: bool, span: Span) -> TokenStream {
+    let mut predicate = TokenStream::from(TokenTree::Ident(Ident::new("debug_assertions", span)));
+    if release {
+        predicate = [
+            TokenTree::Ident(Ident::new("not", span)),
+            TokenTree::Group(Group::new(Delimiter::Parenthesis, predicate)),
+        ]
+        .into_iter()
+        .collect();
+    }
+    [
+        TokenTree::Punct(Punct::new('#', Spacing::Alone)),
         TokenTree::Group(Group::new(
-            Delimiter::Brace,
-            TokenStream::from(TokenTree::Literal(error_message)),
+            Delimiter::Bracket,
+            [
+                TokenTree::Ident(Ident::new("cfg", span)),
+                TokenTree::Group(Group::new(Delimiter::Parenthesis, predicate)),
+            ]
+            .into_iter()
+            .collect(),
         )),
-    ]);
+    ]
+    .into_iter()
+    .collect()
+}
 
-    stream
+/// Span of a trait in the derives written below the macro, if any.
+#[cfg(any(feature = "debug", feature = "borrow-str"))]
+fn derived_trait_span(item: &TokenStream, name: &str) -> Option<Span> {
+    for token in item.clone() {
+        match token {
+            TokenTree::Ident(ident) if ident.to_string() == "enum" => break,
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Bracket => {
+                let mut attr_iter = group.stream().into_iter();
+                if let Some(TokenTree::Ident(ident)) = attr_iter.next()
+                    && ident.to_string() == "derive"
+                    && let Some(TokenTree::Group(derives)) = attr_iter.next()
+                {
+                    // the last segment of paths like `std::fmt::Debug` is also matched
+                    for derive in derives.stream() {
+                        if let TokenTree::Ident(ident) = derive
+                            && ident.to_string() == name
+                        {
+                            return Some(ident.span());
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    None
 }
 
-fn value_from_literal_str(literal_str: &str) -> Result<&str, &'static [u8]> {
-    if literal_str.starts_with("r#") {
-        Ok(&literal_str[2..literal_str.len() - 2])
-    } else if literal_str.starts_with("c\"") {
-        Ok(&literal_str[2..literal_str.len() - 1])
-    } else if literal_str.starts_with("cr#") {
-        Ok(&literal_str[3..literal_str.len() - 2])
-    } else if literal_str.starts_with('"') {
-        Ok(&literal_str[1..literal_str.len() - 1])
-    } else {
-        Err(b"Literal must be a string literal")
+/// Check if an identifier follows the strict rules of HTML 4 for ids.
+fn is_strict_html_id(id: &str) -> bool {
+    let mut chars = id.chars();
+    chars.next().is_some_and(|char| char.is_ascii_alphabetic())
+        && chars.all(|char| char.is_ascii_alphanumeric() || matches!(char, '-' | '_' | ':' | '.'))
+}
+
+/// Check if an identifier is a valid CSS identifier, which can be used in id
+/// selectors like `#language-selector` without escaping.
+fn is_css_identifier(id: &str) -> bool {
+    let is_name_start = |char: char| char.is_ascii_alphabetic() || char == '_' || !char.is_ascii();
+    let name = id.strip_prefix("--").or_else(|| {
+        let name = id.strip_prefix('-').unwrap_or(id);
+        name.starts_with(is_name_start).then_some(name)
+    });
+    name.is_some_and(|name| {
+        name.chars()
+            .all(|char| is_name_start(char) || char.is_ascii_digit() || char == '-')
+    })
+}
+
+/// Resolve a path of the `files` argument.
+///
+/// Relative paths are resolved from the directory of the file that invokes the
+/// macro, like `include_str!`, or from the manifest directory of the crate when
+/// the file is not available.
+fn ids_file_path(path: &str) -> std::path::PathBuf {
+    Span::call_site()
+        .local_file()
+        .and_then(|file| file.parent().map(std::path::Path::to_path_buf))
+        .or_else(|| std::env::var_os("CARGO_MANIFEST_DIR").map(std::path::PathBuf::from))
+        .unwrap_or_default()
+        .join(path)
+}
+
+/// Write the identifiers in declaration order as a JSON array of strings.
+///
+/// Relative paths are resolved from the `OUT_DIR` of the crate when it has a
+/// build script, or from its manifest directory otherwise.
+fn write_manifest(path: &str, ids: &[String]) -> std::io::Result<()> {
+    let base_dir = std::env::var_os("OUT_DIR")
+        .or_else(|| std::env::var_os("CARGO_MANIFEST_DIR"))
+        .map(std::path::PathBuf::from)
+        .unwrap_or_default();
+    let path = base_dir.join(path);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut json = String::from("[");
+    for (i, id) in ids.iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        json.push_str(&json_string(id));
+    }
+    json.push_str("]\n");
+    std::fs::write(path, json)
+}
+
+/// Append an identifier to a CSS selector, escaping the ASCII characters that
+/// are not allowed unescaped in CSS identifiers, like `.` or `:`.
+fn push_css_escaped(selector: &mut String, id: &str) {
+    for char in id.chars() {
+        if char.is_ascii() && !char.is_ascii_alphanumeric() && char != '-' && char != '_' {
+            selector.push('\\');
+        }
+        selector.push(char);
+    }
+}
+
+/// Serialize a string as a JSON string, escaping quotes, backslashes and
+/// control characters.
+fn json_string(value: &str) -> String {
+    use std::fmt::Write;
+
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for char in value.chars() {
+        match char {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            char if char.is_control() => {
+                let _ = write!(json, "\\u{:04x}", u32::from(char));
+            }
+            char => json.push(char),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// Value of a string literal as written in the source, like `"foo"` or
+/// `r#"foo"#`, with the escapes of non-raw literals decoded.
+fn value_from_literal_str(literal_str: &str) -> Result<Cow<'_, str>, &'static str> {
+    let unprefixed = literal_str.strip_prefix('c').unwrap_or(literal_str);
+    let (unprefixed, hashes, raw) = match unprefixed.strip_prefix('r') {
+        Some(raw) => {
+            let hashes = raw.len() - raw.trim_start_matches('#').len();
+            (&raw[hashes..], hashes, true)
+        }
+        None => (unprefixed, 0, false),
+    };
+    if unprefixed.len() < hashes + 2 || !unprefixed.starts_with('"') {
+        return Err("Literal must be a string literal");
+    }
+    let value = &unprefixed[1..unprefixed.len() - hashes - 1];
+    if raw || !value.contains('\\') {
+        return Ok(Cow::Borrowed(value));
+    }
+    unescape(value).map(Cow::Owned)
+}
+
+/// Decode the escapes of the content of a non-raw string literal.
+///
+/// The literal has already been validated by the lexer of the compiler, so
+/// only escapes that are not valid in strings, like bytes of C strings that
+/// are not ASCII, are errors.
+fn unescape(value: &str) -> Result<String, &'static str> {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    while let Some(char) = chars.next() {
+        if char != '\\' {
+            unescaped.push(char);
+            continue;
+        }
+        match chars.next() {
+            Some('"') => unescaped.push('"'),
+            Some('\'') => unescaped.push('\''),
+            Some('\\') => unescaped.push('\\'),
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('t') => unescaped.push('\t'),
+            Some('0') => unescaped.push('\0'),
+            Some('x') => {
+                let hex: String = chars.by_ref().take(2).collect();
+                match u8::from_str_radix(&hex, 16) {
+                    Ok(byte) if byte.is_ascii() => unescaped.push(char::from(byte)),
+                    _ => return Err("Only ASCII characters can be escaped with `\\x`."),
+                }
+            }
+            Some('u') => {
+                let hex: String = chars
+                    .by_ref()
+                    .skip_while(|char| *char == '{')
+                    .take_while(|char| *char != '}')
+                    .filter(|char| *char != '_')
+                    .collect();
+                match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                    Some(char) => unescaped.push(char),
+                    None => return Err("Invalid unicode escape in string literal."),
+                }
+            }
+            // line continuation, skipping the whitespace of the next line
+            Some('\n') => while chars.next_if(|char| char.is_whitespace()).is_some() {},
+            _ => return Err("Invalid escape in string literal."),
+        }
+    }
+    Ok(unescaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        is_css_identifier, json_string, push_css_escaped, quoted, short_hash,
+        value_from_literal_str,
+    };
+
+    #[test]
+    fn raw_string() {
+        assert_eq!(value_from_literal_str("r##\"foo\"##").unwrap(), "foo");
+        assert_eq!(value_from_literal_str("r\"foo\"").unwrap(), "foo");
+    }
+
+    #[test]
+    fn raw_c_string() {
+        assert_eq!(value_from_literal_str("cr#\"bar\"#").unwrap(), "bar");
+        assert_eq!(value_from_literal_str("c\"bar\"").unwrap(), "bar");
+    }
+
+    #[test]
+    fn string() {
+        assert_eq!(value_from_literal_str("\"baz\"").unwrap(), "baz");
+    }
+
+    #[test]
+    fn escaped_string() {
+        assert_eq!(value_from_literal_str(r#""a\"b""#).unwrap(), "a\"b");
+        assert_eq!(value_from_literal_str(r#""a\\b""#).unwrap(), "a\\b");
+        assert_eq!(value_from_literal_str(r#""a\nb\t""#).unwrap(), "a\nb\t");
+        assert_eq!(
+            value_from_literal_str(r#""\x41\u{f1}\u{1_F600}""#).unwrap(),
+            "A\u{f1}\u{1F600}"
+        );
+        assert_eq!(
+            value_from_literal_str("\"foo-\\\n    bar\"").unwrap(),
+            "foo-bar"
+        );
+        assert_eq!(value_from_literal_str(r#"c"a\"b""#).unwrap(), "a\"b");
+        assert!(value_from_literal_str(r#"c"\xff""#).is_err());
+    }
+
+    #[test]
+    fn raw_string_not_unescaped() {
+        assert_eq!(value_from_literal_str(r##"r#"a\"b"#"##).unwrap(), "a\\\"b");
+    }
+
+    #[test]
+    fn json_strings() {
+        assert_eq!(json_string("foo-bar"), "\"foo-bar\"");
+        assert_eq!(json_string("a\"b\\c"), "\"a\\\"b\\\\c\"");
+        assert_eq!(json_string("a\u{1}"), "\"a\\u0001\"");
+    }
+
+    #[test]
+    fn quoted_like_debug() {
+        for id in ["foo-bar", "it's", "a\"b\\c", "a\u{1}", "año", "a\u{301}"] {
+            assert_eq!(quoted(id), format!("{id:?}"));
+        }
+    }
+
+    #[test]
+    fn css_identifiers() {
+        for id in ["foo", "_foo", "-foo", "--foo", "--", "foo-1_bar", "año"] {
+            assert!(is_css_identifier(id), "{id:?} is a CSS identifier");
+        }
+        for id in ["", "-", "1st", "-1st", "nav.main", "user:avatar", "a#b"] {
+            assert!(!is_css_identifier(id), "{id:?} is not a CSS identifier");
+        }
+    }
+
+    #[test]
+    fn css_escaped_ids() {
+        let mut selector = String::new();
+        for id in ["foo-bar_baz", "a.b:c", "año"] {
+            push_css_escaped(&mut selector, id);
+            selector.push(',');
+        }
+        assert_eq!(selector, "foo-bar_baz,a\\.b\\:c,año,");
+    }
+
+    #[test]
+    fn short_hashes() {
+        assert_eq!(short_hash("foo"), "h1b5leqf");
+        assert_eq!(short_hash("language-selector"), "hwrd9k7");
+        assert_eq!(short_hash("preview"), "hksxopj");
+    }
+
+    #[test]
+    fn not_a_string() {
+        assert!(value_from_literal_str("b\"baz\"").is_err());
+        assert!(value_from_literal_str("42").is_err());
     }
 }
@@ -1,95 +1,438 @@
-#[cfg(not(feature = "convert-case"))]
-pub(crate) fn to_pascal_case(input: &str) -> Result<String, &'static [u8]> {
-    let mut pascal = String::with_capacity(input.len());
+use std::borrow::Cow;
+
+/// Convert an identifier to `PascalCase`.
+///
+/// This is the conversion used by the `leptos_unique_ids` macro to build the
+/// variant names of the `Ids` enum from their string literals.
+///
+/// Non-alphanumeric characters are treated as word boundaries and removed, the
+/// first character of each word is uppercased and a digit ends the current word.
+///
+/// # Errors
+///
+/// Returns an error if the input contains non-ASCII characters.
+///
+/// The input is borrowed when it's already in `PascalCase`.
+#[cfg(not(any(feature = "convert-case", feature = "unicode")))]
+pub fn to_pascal_case(input: &str) -> Result<Cow<'_, str>, &'static str> {
+    // Only allocate when a character of the input has to be changed.
+    let mut pascal: Option<String> = None;
     let mut at_word_boundary = true;
-    for char in input.chars() {
-        if !char.is_ascii() {
-            return Err(b"Input contains non-ASCII characters.");
+    for (i, char) in input.char_indices() {
+        let output = if !char.is_ascii() {
+            return Err("Input contains non-ASCII characters.");
         } else if char.is_ascii_alphanumeric() {
             if at_word_boundary {
-                pascal.push(char.to_ascii_uppercase());
                 at_word_boundary = false;
+                Some(char.to_ascii_uppercase())
             } else if char.is_ascii_uppercase() || char.is_ascii_lowercase() {
                 // If not at a word boundary and character is uppercase or lowercase,
                 // append it as is.
-                pascal.push(char);
+                Some(char)
             } else if char.is_ascii_digit() {
                 // If not at a word boundary and character is digit,
                 // append it as is and set at word boundary.
-                pascal.push(char);
                 at_word_boundary = true;
+                Some(char)
             } else {
                 // If not at a word boundary and character is not alphanumeric,
                 // set at word boundary.
                 at_word_boundary = true;
+                None
             }
         } else {
             // If non-alphanumeric character, set at a word boundary
             at_word_boundary = true;
+            None
+        };
+
+        match &mut pascal {
+            Some(pascal) => pascal.extend(output),
+            None if output != Some(char) => {
+                let mut new_pascal = String::with_capacity(input.len());
+                new_pascal.push_str(&input[..i]);
+                new_pascal.extend(output);
+                pascal = Some(new_pascal);
+            }
+            None => {}
+        }
+    }
+    Ok(pascal.map_or(Cow::Borrowed(input), Cow::Owned))
+}
+
+/// Convert an identifier to `PascalCase`, supporting Unicode characters.
+///
+/// Non-alphanumeric characters are treated as word boundaries and removed, the
+/// first character of each word is uppercased and a numeric character ends the
+/// current word.
+///
+/// # Errors
+///
+/// Returns an error if the result is not a valid Rust identifier.
+#[cfg(all(feature = "unicode", not(feature = "convert-case")))]
+pub fn to_pascal_case(input: &str) -> Result<Cow<'_, str>, &'static str> {
+    let mut pascal = String::with_capacity(input.len());
+    let mut at_word_boundary = true;
+    for char in input.chars() {
+        if !char.is_alphanumeric() {
+            at_word_boundary = true;
+        } else if at_word_boundary {
+            pascal.extend(char.to_uppercase());
+            at_word_boundary = false;
+        } else {
+            pascal.push(char);
+            at_word_boundary = char.is_numeric();
         }
     }
-    Ok(pascal)
+    check_unicode_identifier(&pascal)?;
+
+    if pascal == input {
+        Ok(Cow::Borrowed(input))
+    } else {
+        Ok(Cow::Owned(pascal))
+    }
 }
 
+/// Convert an identifier to `PascalCase` using [`convert_case`].
+///
+/// # Errors
+///
+/// Returns an error if the input contains non-ASCII characters, or if the result
+/// is not a valid Rust identifier when the `unicode` feature is enabled.
 #[cfg(feature = "convert-case")]
-pub(crate) fn to_pascal_case(input: &str) -> Result<String, &'static [u8]> {
+pub fn to_pascal_case(input: &str) -> Result<Cow<'_, str>, &'static str> {
+    #[cfg(not(feature = "unicode"))]
     if !input.is_ascii() {
-        return Err(b"Input contains non-ASCII characters.");
+        return Err("Input contains non-ASCII characters.");
+    }
+    let pascal = convert_case::Casing::to_case(&input, convert_case::Case::Pascal);
+    #[cfg(feature = "unicode")]
+    check_unicode_identifier(&pascal)?;
+    if pascal == input {
+        Ok(Cow::Borrowed(input))
+    } else {
+        Ok(Cow::Owned(pascal))
+    }
+}
+
+/// Check that a non-empty `PascalCase` result is a valid Rust identifier.
+#[cfg(feature = "unicode")]
+fn check_unicode_identifier(pascal: &str) -> Result<(), &'static str> {
+    let mut chars = pascal.chars();
+    if let Some(first) = chars.next()
+        && (!unicode_ident::is_xid_start(first) || !chars.all(unicode_ident::is_xid_continue))
+    {
+        return Err("Input can't be converted to a valid Rust identifier.");
+    }
+    Ok(())
+}
+
+/// Convert an identifier to `PascalCase`, uppercasing the words that are acronyms.
+///
+/// Words are the runs of ASCII alphanumeric characters delimited by other ASCII
+/// characters, so `"svg-url"` is converted to `SVGURL` when both `"svg"` and
+/// `"url"` are acronyms. The acronyms are matched ignoring ASCII case and words
+/// that are not acronyms are converted with [`to_pascal_case`].
+///
+/// # Errors
+///
+/// Returns an error if the input contains non-ASCII characters.
+pub fn to_pascal_case_with_acronyms<'a, S: AsRef<str>>(
+    input: &'a str,
+    acronyms: &[S],
+) -> Result<Cow<'a, str>, &'static str> {
+    if acronyms.is_empty() {
+        return to_pascal_case(input);
+    }
+
+    let mut pascal = String::with_capacity(input.len());
+    for word in input.split(|char: char| char.is_ascii() && !char.is_ascii_alphanumeric()) {
+        if acronyms
+            .iter()
+            .any(|acronym| acronym.as_ref().eq_ignore_ascii_case(word))
+        {
+            pascal.push_str(&word.to_ascii_uppercase());
+        } else {
+            pascal.push_str(&to_pascal_case(word)?);
+        }
+    }
+    Ok(Cow::Owned(pascal))
+}
+
+/// Convert a `PascalCase` identifier, like a variant name, to `kebab-case`.
+///
+/// It's internal to the crate, used to convert the emitted identifiers with the
+/// `id_case` argument, as procedural macro crates can only export macros.
+///
+/// Words are lowercased and joined with hyphens. Non-alphanumeric characters,
+/// lowercase characters or digits followed by uppercase ones and the last
+/// uppercase character of an acronym followed by a lowercase one are treated as
+/// word boundaries, so `"FooBar"` is converted to `foo-bar`, `"Foo5Bar"` to
+/// `foo5-bar`, `"foo5bar"` to `foo5bar` and `"HTMLParser"` to `html-parser`.
+///
+/// # Round trips
+///
+/// It's the inverse of [`to_pascal_case`] for names without consecutive
+/// uppercase characters: `to_pascal_case(&to_kebab_case(name))` returns `name`.
+/// The words of acronyms are merged instead, so `"HTMLParser"` gets back as
+/// `HtmlParser` and `"AB"` as `Ab`.
+///
+/// In the other direction, `to_kebab_case(&to_pascal_case(id)?)` returns `id`
+/// when it's made of lowercase words that start with a letter joined by single
+/// hyphens, whose digits are only followed by other digits, like `h1-title`,
+/// and its name has no consecutive uppercase characters. Other ids get back in
+/// that form, like `"foo5bar"` as `foo5-bar`, `"foo_bar"` as `foo-bar` and
+/// `"a-b"` as `ab`.
+#[must_use]
+pub fn to_kebab_case(input: &str) -> String {
+    let mut kebab = String::with_capacity(input.len() + 4);
+    for (i, word) in split_words(input).iter().enumerate() {
+        if i > 0 {
+            kebab.push('-');
+        }
+        kebab.push_str(&word.to_lowercase());
+    }
+    kebab
+}
+
+/// Split an identifier in words at the boundaries described in [`to_kebab_case`].
+fn split_words(input: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut word_start = None;
+    let mut chars = input.char_indices().peekable();
+    let mut previous: Option<char> = None;
+    while let Some((i, char)) = chars.next() {
+        if !char.is_alphanumeric() {
+            if let Some(start) = word_start.take() {
+                words.push(&input[start..i]);
+            }
+            previous = None;
+            continue;
+        }
+        if let Some(start) = word_start
+            && char.is_uppercase()
+            && previous.is_some_and(|previous| {
+                previous.is_lowercase()
+                    || previous.is_numeric()
+                    || previous.is_uppercase()
+                        && chars.peek().is_some_and(|(_, next)| next.is_lowercase())
+            })
+        {
+            words.push(&input[start..i]);
+            word_start = Some(i);
+        } else if word_start.is_none() {
+            word_start = Some(i);
+        }
+        previous = Some(char);
+    }
+    if let Some(start) = word_start {
+        words.push(&input[start..]);
     }
-    Ok(convert_case::Casing::to_case(
-        &input.to_string(),
-        convert_case::Case::Pascal,
-    ))
+    words
 }
 
 #[cfg(test)]
 mod tests {
-    use super::to_pascal_case;
+    use super::{to_kebab_case, to_pascal_case, to_pascal_case_with_acronyms};
+    use std::borrow::Cow;
 
     #[test]
     fn basic() {
-        assert_eq!(to_pascal_case("foo"), Ok("Foo".to_string()));
+        assert_eq!(to_pascal_case("foo"), Ok("Foo".into()));
     }
 
     #[test]
     fn empty() {
-        assert_eq!(to_pascal_case(""), Ok("".to_string()));
+        assert_eq!(to_pascal_case(""), Ok("".into()));
     }
 
     #[test]
     fn hyphen() {
-        assert_eq!(to_pascal_case("foo-bar-baz"), Ok("FooBarBaz".to_string()));
+        assert_eq!(to_pascal_case("foo-bar-baz"), Ok("FooBarBaz".into()));
     }
 
     #[test]
     fn underscore() {
-        assert_eq!(to_pascal_case("foo_bar_baz"), Ok("FooBarBaz".to_string()));
+        assert_eq!(to_pascal_case("foo_bar_baz"), Ok("FooBarBaz".into()));
     }
 
     #[test]
+    #[cfg(not(feature = "unicode"))]
     fn special_non_ascii_characters() {
         let result = to_pascal_case("foo-bár");
-        let err_message = b"Input contains non-ASCII characters." as &[u8];
+        let err_message = "Input contains non-ASCII characters.";
         assert_eq!(result, Err(err_message));
     }
 
     #[test]
     fn lower_followed_by_upper() {
-        assert_eq!(to_pascal_case("fooBar"), Ok("FooBar".to_string()));
+        assert_eq!(to_pascal_case("fooBar"), Ok("FooBar".into()));
     }
 
     #[test]
     fn digit_followed_by_upper() {
-        assert_eq!(to_pascal_case("foo5Bar"), Ok("Foo5Bar".to_string()));
+        assert_eq!(to_pascal_case("foo5Bar"), Ok("Foo5Bar".into()));
     }
 
     #[test]
     fn upper_followed_by_digit() {
-        assert_eq!(to_pascal_case("FoO5bar"), Ok("FoO5Bar".to_string()));
+        assert_eq!(to_pascal_case("FoO5bar"), Ok("FoO5Bar".into()));
     }
 
     #[test]
     fn digit_followed_by_lower() {
-        assert_eq!(to_pascal_case("foo5bar"), Ok("Foo5Bar".to_string()));
+        assert_eq!(to_pascal_case("foo5bar"), Ok("Foo5Bar".into()));
+    }
+
+    #[test]
+    fn already_pascal_case_is_borrowed() {
+        assert!(matches!(to_pascal_case("App"), Ok(Cow::Borrowed("App"))));
+        assert!(matches!(
+            to_pascal_case("Foo5Bar"),
+            Ok(Cow::Borrowed("Foo5Bar"))
+        ));
+        assert!(matches!(to_pascal_case("foo"), Ok(Cow::Owned(_))));
+    }
+
+    #[test]
+    fn acronyms() {
+        let acronyms = ["html", "svg", "url"];
+        assert_eq!(
+            to_pascal_case_with_acronyms("html-parser", &acronyms),
+            Ok("HTMLParser".into())
+        );
+        assert_eq!(
+            to_pascal_case_with_acronyms("svg-url", &acronyms),
+            Ok("SVGURL".into())
+        );
+        assert_eq!(
+            to_pascal_case_with_acronyms("preview_svg-button", &acronyms),
+            Ok("PreviewSVGButton".into())
+        );
+        assert_eq!(
+            to_pascal_case_with_acronyms("download-url", &acronyms),
+            Ok("DownloadURL".into())
+        );
+    }
+
+    #[test]
+    fn acronyms_match_whole_words() {
+        let acronyms = ["url"];
+        assert_eq!(
+            to_pascal_case_with_acronyms("urls-list", &acronyms),
+            Ok("UrlsList".into())
+        );
+        assert_eq!(
+            to_pascal_case_with_acronyms("curl", &acronyms),
+            Ok("Curl".into())
+        );
+    }
+
+    #[test]
+    fn acronyms_ignore_case() {
+        assert_eq!(
+            to_pascal_case_with_acronyms("Html-parser", &["HTML"]),
+            Ok("HTMLParser".into())
+        );
+    }
+
+    #[test]
+    fn without_acronyms() {
+        let acronyms: [&str; 0] = [];
+        assert_eq!(
+            to_pascal_case_with_acronyms("html-parser", &acronyms),
+            Ok("HtmlParser".into())
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "unicode"))]
+    fn acronyms_non_ascii_characters() {
+        let result = to_pascal_case_with_acronyms("svg-bár", &["svg"]);
+        let err_message = "Input contains non-ASCII characters.";
+        assert_eq!(result, Err(err_message));
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn unicode_accented() {
+        assert_eq!(to_pascal_case("preço-total"), Ok("PreçoTotal".into()));
+        assert_eq!(to_pascal_case("ñandú"), Ok("Ñandú".into()));
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn unicode_non_latin() {
+        assert_eq!(to_pascal_case("привет-мир"), Ok("ПриветМир".into()));
+        assert_eq!(to_pascal_case("γεια_σου"), Ok("ΓειαΣου".into()));
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn unicode_invalid_identifier() {
+        let err_message = "Input can't be converted to a valid Rust identifier.";
+        assert_eq!(to_pascal_case("5-foo"), Err(err_message));
+        assert_eq!(to_pascal_case("½-foo"), Err(err_message));
+    }
+
+    #[test]
+    fn kebab_basic() {
+        assert_eq!(to_kebab_case("Foo"), "foo");
+    }
+
+    #[test]
+    fn kebab_empty() {
+        assert_eq!(to_kebab_case(""), "");
+    }
+
+    #[test]
+    fn kebab_words() {
+        assert_eq!(to_kebab_case("FooBarBaz"), "foo-bar-baz");
+        assert_eq!(to_kebab_case("foo_bar_baz"), "foo-bar-baz");
+        assert_eq!(to_kebab_case("-foo__bar-"), "foo-bar");
+    }
+
+    #[test]
+    fn kebab_lower_followed_by_upper() {
+        assert_eq!(to_kebab_case("fooBar"), "foo-bar");
+    }
+
+    #[test]
+    fn kebab_digit_followed_by_upper() {
+        assert_eq!(to_kebab_case("Foo5Bar"), "foo5-bar");
+    }
+
+    #[test]
+    fn kebab_upper_followed_by_digit() {
+        assert_eq!(to_kebab_case("FoO5Bar"), "fo-o5-bar");
+    }
+
+    #[test]
+    fn kebab_digit_followed_by_lower() {
+        assert_eq!(to_kebab_case("foo5bar"), "foo5bar");
+    }
+
+    #[test]
+    fn kebab_acronyms() {
+        assert_eq!(to_kebab_case("HTMLParser"), "html-parser");
+        assert_eq!(to_kebab_case("DownloadURL"), "download-url");
+    }
+
+    #[test]
+    fn kebab_round_trips() {
+        for id in ["language-selector", "h1-title", "foo-a-bar"] {
+            let name = to_pascal_case(id).unwrap();
+            assert_eq!(to_kebab_case(&name), id);
+            assert_eq!(to_pascal_case(&to_kebab_case(&name)).unwrap(), name);
+        }
+        // ids that are not in the form returned by `to_kebab_case`
+        for (id, kebab) in [
+            ("foo5bar", "foo5-bar"),
+            ("foo_bar", "foo-bar"),
+            ("a-b", "ab"),
+            ("preview-2", "preview2"),
+        ] {
+            assert_eq!(to_kebab_case(&to_pascal_case(id).unwrap()), kebab);
+        }
     }
 }
@@ -0,0 +1,183 @@
+/// Perfect hash map of the identifiers, built while expanding the macro with
+/// the `phf` feature to look up strings in `FromStr` without comparing them
+/// against every identifier.
+///
+/// It follows the hash and displace algorithm of the generator of the `phf`
+/// crate: the identifiers are split in buckets by their hash, and the buckets
+/// are placed from the largest one searching the displacements that move all
+/// their identifiers to free slots of the table.
+pub struct Table {
+    /// Initial state of the hash, which changes until a map is found.
+    pub key: u64,
+    /// Displacements of each bucket.
+    pub displacements: Vec<(u64, u64)>,
+    /// Slot of each identifier in the table, in the order of the identifiers.
+    pub slots: Vec<u64>,
+}
+
+/// Offset basis of the FNV-1a hash, which is mixed with the seed of the key.
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// Prime multiplied by the state of the FNV-1a hash after each byte.
+pub const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// Multiplier of the finalizer of `MurmurHash3`, which spreads the bits of the
+/// state, because the low bits of FNV-1a only depend on the low bits of the key.
+pub const MIX_MULTIPLIER: u64 = 0xff51_afd7_ed55_8ccd;
+
+/// Multiplier to build the second displacement factor from the hash.
+pub const GOLDEN_RATIO: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// Identifiers per bucket, like the default of the `phf` generator.
+const LAMBDA: usize = 5;
+
+/// Keys tried before giving up, which is never reached in practice.
+const MAX_SEEDS: u64 = 1024;
+
+/// Hash of an identifier with the key of the table. The code generated for
+/// `FromStr` computes the same for the looked up string.
+pub fn hash(id: &str, key: u64) -> u64 {
+    let mut hash = key;
+    for byte in id.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(MIX_MULTIPLIER);
+    hash ^ (hash >> 33)
+}
+
+/// Bucket of a hash in a table with `buckets` buckets.
+pub fn bucket(hash: u64, buckets: u64) -> u64 {
+    (hash >> 32) % buckets
+}
+
+/// Slot of a hash in a table of `len` slots with the displacements of its bucket.
+pub fn slot(hash: u64, (d1, d2): (u64, u64), len: u64) -> u64 {
+    d2.wrapping_add((hash & 0xffff_ffff).wrapping_mul(d1))
+        .wrapping_add(hash.wrapping_mul(GOLDEN_RATIO) >> 32)
+        % len
+}
+
+/// Build the perfect hash map of the identifiers, which must not be empty.
+///
+/// Returns `None` if no map is found with any of the keys tried.
+pub fn table(ids: &[String]) -> Option<Table> {
+    let len = ids.len();
+    let buckets_len = len.div_ceil(LAMBDA);
+    (0..MAX_SEEDS).find_map(|seed| {
+        let key = FNV_OFFSET_BASIS ^ seed.wrapping_mul(GOLDEN_RATIO);
+        try_table(ids, key, buckets_len)
+    })
+}
+
+fn try_table(ids: &[String], key: u64, buckets_len: usize) -> Option<Table> {
+    let len = ids.len() as u64;
+    let hashes: Vec<u64> = ids.iter().map(|id| hash(id, key)).collect();
+
+    let mut buckets: Vec<Vec<usize>> = vec![Vec::new(); buckets_len];
+    for (i, hash) in hashes.iter().enumerate() {
+        buckets[index(bucket(*hash, buckets_len as u64))].push(i);
+    }
+    let mut buckets_order: Vec<usize> = (0..buckets_len).collect();
+    buckets_order.sort_by_key(|i| std::cmp::Reverse(buckets[*i].len()));
+
+    let mut displacements = vec![(0, 0); buckets_len];
+    let mut slots = vec![0; ids.len()];
+    let mut taken = vec![false; ids.len()];
+    // slots used by the current bucket, stamped with the attempt to not clear
+    // them after each displacement that doesn't fit
+    let mut tried: Vec<u64> = vec![0; ids.len()];
+    let mut attempt = 0;
+    for bucket in buckets_order {
+        if buckets[bucket].is_empty() {
+            break;
+        }
+        let mut found = None;
+        'displacements: for d1 in 0..len {
+            for d2 in 0..len {
+                attempt += 1;
+                let fits = buckets[bucket].iter().all(|i| {
+                    let slot = index(slot(hashes[*i], (d1, d2), len));
+                    let free = !taken[slot] && tried[slot] != attempt;
+                    tried[slot] = attempt;
+                    free
+                });
+                if fits {
+                    found = Some((d1, d2));
+                    break 'displacements;
+                }
+            }
+        }
+        let displacement = found?;
+        for i in &buckets[bucket] {
+            let slot = slot(hashes[*i], displacement, len);
+            slots[*i] = slot;
+            taken[index(slot)] = true;
+        }
+        displacements[bucket] = displacement;
+    }
+
+    Some(Table {
+        key,
+        displacements,
+        slots,
+    })
+}
+
+/// Convert a bucket or a slot, which are lower than the number of identifiers,
+/// to an index.
+fn index(value: u64) -> usize {
+    usize::try_from(value).expect("buckets and slots are lower than the number of identifiers")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bucket, hash, slot, table};
+
+    fn assert_perfect(ids: &[String]) {
+        let table = table(ids).unwrap();
+        let len = ids.len() as u64;
+        let mut slots: Vec<u64> = Vec::with_capacity(ids.len());
+        for (id, expected) in ids.iter().zip(&table.slots) {
+            let hash = hash(id, table.key);
+            let bucket = bucket(hash, table.displacements.len() as u64);
+            let displacements = table.displacements[super::index(bucket)];
+            let slot = slot(hash, displacements, len);
+            assert_eq!(slot, *expected, "{id}");
+            slots.push(slot);
+        }
+        slots.sort_unstable();
+        assert_eq!(slots, (0..len).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn single() {
+        assert_perfect(&["preview".to_string()]);
+    }
+
+    #[test]
+    fn few() {
+        assert_perfect(&[
+            "language-selector".to_string(),
+            "preview".to_string(),
+            "preview-download-svg-button".to_string(),
+            "preview-upload-svg-button".to_string(),
+        ]);
+    }
+
+    #[test]
+    fn many() {
+        let ids: Vec<String> = (0..5000).map(|i| format!("id-{i}")).collect();
+        assert_perfect(&ids);
+    }
+
+    #[test]
+    fn deterministic() {
+        let ids: Vec<String> = (0..100).map(|i| format!("button-{i}")).collect();
+        let (first, second) = (table(&ids).unwrap(), table(&ids).unwrap());
+        assert_eq!(first.key, second.key);
+        assert_eq!(first.displacements, second.displacements);
+        assert_eq!(first.slots, second.slots);
+    }
+}
@@ -0,0 +1,65 @@
+/// Convert an identifier to `SCREAMING_SNAKE_CASE`.
+///
+/// This is the conversion used by the `leptos_unique_ids` macro to build the
+/// names of the constants generated with the `constants` feature.
+///
+/// Non-alphanumeric characters and lowercase characters followed by uppercase
+/// ones are treated as word boundaries, so `"language-selector"` and
+/// `"languageSelector"` are both converted to `LANGUAGE_SELECTOR`.
+pub fn to_screaming_snake_case(input: &str) -> String {
+    let mut screaming = String::with_capacity(input.len() + 4);
+    let mut at_word_boundary = false;
+    let mut previous_is_lowercase = false;
+    for char in input.chars() {
+        if !char.is_alphanumeric() {
+            at_word_boundary = true;
+            previous_is_lowercase = false;
+            continue;
+        }
+        if !screaming.is_empty()
+            && (at_word_boundary || previous_is_lowercase && char.is_uppercase())
+        {
+            screaming.push('_');
+        }
+        at_word_boundary = false;
+        previous_is_lowercase = char.is_lowercase();
+        screaming.extend(char.to_uppercase());
+    }
+    screaming
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_screaming_snake_case;
+
+    #[test]
+    fn basic() {
+        assert_eq!(to_screaming_snake_case("foo"), "FOO");
+    }
+
+    #[test]
+    fn hyphen() {
+        assert_eq!(to_screaming_snake_case("foo-bar-baz"), "FOO_BAR_BAZ");
+    }
+
+    #[test]
+    fn underscore() {
+        assert_eq!(to_screaming_snake_case("foo_bar_baz"), "FOO_BAR_BAZ");
+    }
+
+    #[test]
+    fn consecutive_separators() {
+        assert_eq!(to_screaming_snake_case("-foo--bar-"), "FOO_BAR");
+    }
+
+    #[test]
+    fn lower_followed_by_upper() {
+        assert_eq!(to_screaming_snake_case("fooBar"), "FOO_BAR");
+    }
+
+    #[test]
+    fn digits() {
+        assert_eq!(to_screaming_snake_case("foo5bar"), "FOO5BAR");
+        assert_eq!(to_screaming_snake_case("h1-title"), "H1_TITLE");
+    }
+}
@@ -0,0 +1,81 @@
+//! Benchmark of the expansion of the `#[leptos_unique_ids()]` attribute macro
+//! for enums of 10, 100 and 1000 identifiers.
+//!
+//! Proc macros can only be expanded by the compiler, so a crate is generated
+//! for each size in the temporary directory of the target and checked with
+//! `cargo check`. The time to check an enum without identifiers is reported
+//! as the baseline of the overhead of Cargo and the compiler.
+//!
+//! Run with `cargo bench -p tests --bench expansion`.
+
+use std::{
+    fmt::Write as _,
+    fs,
+    path::Path,
+    process::Command,
+    time::{Duration, Instant},
+};
+
+const SIZES: [usize; 3] = [10, 100, 1000];
+const ITERATIONS: u32 = 10;
+
+fn main() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("expansion");
+    fs::create_dir_all(dir.join("src")).unwrap();
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    fs::write(
+        dir.join("Cargo.toml"),
+        format!(
+            r#"[package]
+name = "expansion"
+version = "0.1.0"
+edition = "2024"
+publish = false
+
+[workspace]
+
+[dependencies]
+leptos-unique-ids = {{ path = {:?}, default-features = false }}
+"#,
+            crate_dir.display()
+        ),
+    )
+    .unwrap();
+
+    // the first check builds the proc macro crate
+    check(&dir, 0);
+    eprintln!(
+        "expansion: 0 identifiers in {:?} (baseline)",
+        check(&dir, 0)
+    );
+
+    for size in SIZES {
+        let elapsed = check(&dir, size);
+        eprintln!("expansion: {size} identifiers in {elapsed:?}");
+    }
+}
+
+/// Generate an enum with `size` identifiers and return the minimum time to
+/// check it of all the iterations.
+fn check(dir: &Path, size: usize) -> Duration {
+    let mut source = String::from("#[leptos_unique_ids::leptos_unique_ids(\n");
+    for i in 0..size {
+        writeln!(source, "    \"section-{i}-button\",").unwrap();
+    }
+    source.push_str(")]\npub enum Ids {}\n");
+
+    let mut min = Duration::MAX;
+    for i in 0..ITERATIONS {
+        // the source changes in each iteration to force cargo to check it again
+        fs::write(dir.join("src/lib.rs"), format!("{source}// {i}\n")).unwrap();
+        let start = Instant::now();
+        let status = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".into()))
+            .args(["check", "--quiet", "--offline", "--manifest-path"])
+            .arg(dir.join("Cargo.toml"))
+            .status()
+            .unwrap();
+        min = min.min(start.elapsed());
+        assert!(status.success(), "Failed to check the generated crate.");
+    }
+    min
+}
@@ -0,0 +1,99 @@
+//! Benchmark of the `FromStr` implementation generated by the
+//! `#[leptos_unique_ids()]` attribute macro for a large enum.
+//!
+//! Run with `cargo bench -p tests --bench from_str`.
+
+use std::{hint::black_box, time::Instant};
+
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids(
+    "app",
+    "nav",
+    "main",
+    "footer",
+    "header",
+    "sidebar",
+    "top-bar",
+    "bottom-bar",
+    "menu-button",
+    "menu-dropdown",
+    "language-selector",
+    "language-dropdown",
+    "theme-selector",
+    "theme-dropdown",
+    "search-input",
+    "search-button",
+    "search-results",
+    "preview",
+    "preview-panel",
+    "preview-download-svg-button",
+    "preview-download-png-button",
+    "preview-upload-svg-button",
+    "preview-upload-png-button",
+    "preview-copy-svg-button",
+    "preview-copy-png-button",
+    "editor",
+    "editor-panel",
+    "editor-toolbar",
+    "editor-undo-button",
+    "editor-redo-button",
+    "editor-format-button",
+    "editor-settings-button",
+    "settings-modal",
+    "settings-close-button",
+    "settings-save-button",
+    "settings-reset-button",
+    "login-form",
+    "login-username",
+    "login-password",
+    "login-submit",
+    "signup-form",
+    "signup-username",
+    "signup-password",
+    "signup-submit",
+    "user-avatar",
+    "user-menu",
+    "user-logout",
+    "notifications",
+    "notifications-list",
+    "notifications-clear",
+    "toast",
+    "toast-close",
+    "help-modal",
+    "help-close-button",
+    "about-modal",
+    "about-close-button",
+    "share-button",
+    "share-modal",
+    "share-link-input",
+    "share-copy-button",
+    "export-button",
+    "export-modal",
+    "import-button",
+    "import-modal"
+)]
+enum Ids {}
+
+const ITERATIONS: u32 = 100_000;
+
+fn main() {
+    let inputs = Ids::iter()
+        .map(|id| id.as_str())
+        .chain(["unknown", "preview-download-jpg-button", ""])
+        .collect::<Vec<_>>();
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        for input in &inputs {
+            black_box(black_box(*input).parse::<Ids>().is_ok());
+        }
+    }
+    let elapsed = start.elapsed();
+
+    let parses = ITERATIONS * u32::try_from(inputs.len()).unwrap();
+    eprintln!(
+        "from_str: {parses} parses in {elapsed:?} ({:?} per parse)",
+        elapsed / parses
+    );
+}
@@ -0,0 +1,282 @@
+use leptos_unique_ids::leptos_unique_ids;
+#[allow(clippy::enum_variant_names)]
+/// Identifiers of the application.
+pub enum Ids {
+    ///"language-selector"
+    LanguageSelector,
+    ///"preview"
+    ///Preview of the rendered document.
+    Preview,
+}
+#[automatically_derived]
+#[allow(clippy::enum_variant_names)]
+impl ::core::clone::Clone for Ids {
+    #[inline]
+    fn clone(&self) -> Ids {
+        *self
+    }
+}
+#[automatically_derived]
+#[allow(clippy::enum_variant_names)]
+impl ::core::marker::Copy for Ids {}
+#[automatically_derived]
+#[allow(clippy::enum_variant_names)]
+impl ::core::marker::StructuralPartialEq for Ids {}
+#[automatically_derived]
+#[allow(clippy::enum_variant_names)]
+impl ::core::cmp::PartialEq for Ids {
+    #[inline]
+    fn eq(&self, other: &Ids) -> bool {
+        let __self_discr = ::core::intrinsics::discriminant_value(self);
+        let __arg1_discr = ::core::intrinsics::discriminant_value(other);
+        __self_discr == __arg1_discr
+    }
+}
+#[automatically_derived]
+#[allow(clippy::enum_variant_names)]
+impl ::core::cmp::Eq for Ids {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_receiver_is_total_eq(&self) -> () {}
+}
+impl Ids {
+    #[must_use]
+    #[allow(dead_code)]
+    fn as_str(&self) -> &'static str {
+        match *self {
+            Self::LanguageSelector => "language-selector",
+            Self::Preview => "preview",
+        }
+    }
+    #[must_use]
+    pub const fn as_bytes(&self) -> &'static [u8] {
+        match *self {
+            Self::LanguageSelector => b"language-selector",
+            Self::Preview => b"preview",
+        }
+    }
+    #[must_use]
+    pub fn local_name(&self) -> &'static str {
+        match *self {
+            Self::LanguageSelector => "language-selector",
+            Self::Preview => "preview",
+        }
+    }
+    #[must_use]
+    pub fn as_data_attr(self, name: &'static str) -> impl ::leptos::attr::Attribute {
+        if true {
+            if !(!name.is_empty()
+                && name
+                    .chars()
+                    .all(|char| {
+                        char.is_ascii_alphanumeric() || char == '-' || char == '_'
+                    }))
+            {
+                {
+                    ::core::panicking::panic_fmt(
+                        format_args!("Invalid data attribute name {0:?}", name),
+                    );
+                }
+            }
+        }
+        ::leptos::attr::custom::custom_attribute(
+            ::std::borrow::Cow::<
+                'static,
+                str,
+            >::Owned(
+                ::alloc::__export::must_use({
+                    ::alloc::fmt::format(format_args!("data-{0}", name))
+                }),
+            ),
+            self.as_str(),
+        )
+    }
+    pub fn attr(&self) -> (&'static str, &'static str) {
+        ("id", self.as_str())
+    }
+    pub const ALL: &'static [Self] = &[Self::LanguageSelector, Self::Preview];
+    pub const PAIRS: &'static [(&'static str, Self)] = &[
+        ("language-selector", Self::LanguageSelector),
+        ("preview", Self::Preview),
+    ];
+    pub const SORTED_NAMES: &'static [(&'static str, Self)] = &[
+        ("language-selector", Self::LanguageSelector),
+        ("preview", Self::Preview),
+    ];
+    pub const ALL_SELECTORS: &'static str = "#language-selector,#preview";
+    pub fn from_str_sorted(name: &str) -> ::core::option::Option<Self> {
+        match Self::SORTED_NAMES.binary_search_by(|(id, _)| (*id).cmp(name)) {
+            ::core::result::Result::Ok(0) => {
+                ::core::option::Option::Some(Self::LanguageSelector)
+            }
+            ::core::result::Result::Ok(1) => ::core::option::Option::Some(Self::Preview),
+            _ => ::core::option::Option::None,
+        }
+    }
+    pub fn contains(name: &str) -> bool {
+        match name {
+            "language-selector" | "preview" => true,
+            _ => false,
+        }
+    }
+    pub const fn variants_count() -> usize {
+        2
+    }
+    #[must_use]
+    pub const fn as_array() -> [&'static str; 2] {
+        ["language-selector", "preview"]
+    }
+    pub const fn all() -> &'static [Self] {
+        Self::ALL
+    }
+    pub fn iter() -> impl ::core::iter::Iterator<Item = Self> {
+        (0..Self::ALL.len()).filter_map(Self::from_index)
+    }
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        match *self {
+            Self::LanguageSelector => 0,
+            Self::Preview => 1,
+        }
+    }
+    pub const fn from_index(index: usize) -> ::core::option::Option<Self> {
+        match index {
+            0 => ::core::option::Option::Some(Self::LanguageSelector),
+            1 => ::core::option::Option::Some(Self::Preview),
+            _ => ::core::option::Option::None,
+        }
+    }
+    pub const fn next(&self) -> Self {
+        match *self {
+            Self::LanguageSelector => Self::Preview,
+            Self::Preview => Self::LanguageSelector,
+        }
+    }
+    pub const fn prev(&self) -> Self {
+        match *self {
+            Self::LanguageSelector => Self::Preview,
+            Self::Preview => Self::LanguageSelector,
+        }
+    }
+    pub fn write_to<W: ::core::fmt::Write>(&self, w: &mut W) -> ::core::fmt::Result {
+        w.write_str(self.as_str())
+    }
+    #[must_use]
+    pub fn matches_selector(&self, sel: &str) -> bool {
+        let id = self.as_str().as_bytes();
+        let sel = sel.as_bytes();
+        sel == id || sel.strip_prefix(b"#") == ::core::option::Option::Some(id)
+    }
+    pub fn assert_all_unique() {
+        for (i, variant) in Self::ALL.iter().enumerate() {
+            let id = variant.as_str();
+            for other in &Self::ALL[i + 1..] {
+                if !(other.as_str() != id) {
+                    {
+                        ::core::panicking::panic_fmt(
+                            format_args!(
+                                "Identifier {0:?} is used by more than one variant.", id
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+impl ::core::convert::AsRef<str> for Ids {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+impl ::core::cmp::PartialEq<str> for Ids {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+impl ::core::cmp::PartialEq<Ids> for str {
+    fn eq(&self, other: &Ids) -> bool {
+        other.as_str() == self
+    }
+}
+impl ::core::cmp::PartialEq<&str> for Ids {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+impl ::core::cmp::PartialEq<Ids> for &str {
+    fn eq(&self, other: &Ids) -> bool {
+        other.as_str() == *self
+    }
+}
+impl ::core::cmp::PartialEq<&&str> for Ids {
+    fn eq(&self, other: &&&str) -> bool {
+        self.as_str() == **other
+    }
+}
+impl ::core::cmp::PartialEq<Ids> for &&str {
+    fn eq(&self, other: &Ids) -> bool {
+        other.as_str() == **self
+    }
+}
+///Error returned when a position is out of range of the variants of `Ids`.
+pub struct IdIndexOutOfRange(pub usize);
+#[automatically_derived]
+impl ::core::fmt::Debug for IdIndexOutOfRange {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "IdIndexOutOfRange",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for IdIndexOutOfRange {
+    #[inline]
+    fn clone(&self) -> IdIndexOutOfRange {
+        let _: ::core::clone::AssertParamIsClone<usize>;
+        *self
+    }
+}
+#[automatically_derived]
+impl ::core::marker::Copy for IdIndexOutOfRange {}
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for IdIndexOutOfRange {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for IdIndexOutOfRange {
+    #[inline]
+    fn eq(&self, other: &IdIndexOutOfRange) -> bool {
+        self.0 == other.0
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for IdIndexOutOfRange {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_receiver_is_total_eq(&self) -> () {
+        let _: ::core::cmp::AssertParamIsEq<usize>;
+    }
+}
+impl ::core::fmt::Display for IdIndexOutOfRange {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_fmt(format_args!("position {0} is out of range", self.0))
+    }
+}
+impl ::core::error::Error for IdIndexOutOfRange {}
+impl ::core::convert::TryFrom<usize> for Ids {
+    type Error = IdIndexOutOfRange;
+    fn try_from(value: usize) -> ::core::result::Result<Self, Self::Error> {
+        Self::from_index(value).ok_or(IdIndexOutOfRange(value))
+    }
+}
+impl ::leptos::prelude::IntoAttributeValue for Ids {
+    type Output = &'static str;
+    fn into_attribute_value(self) -> Self::Output {
+        self.as_str()
+    }
+}
+fn main() {}
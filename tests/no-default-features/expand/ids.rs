@@ -0,0 +1,11 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+/// Identifiers of the application.
+#[leptos_unique_ids(
+    "language-selector",
+    ("preview", doc = "Preview of the rendered document."),
+)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Ids {}
+
+fn main() {}
@@ -0,0 +1,11 @@
+//! Snapshots of the code generated by `#[leptos_unique_ids()]` attribute macro
+//! with only the `into-attribute-value` feature enabled.
+//!
+//! Requires `cargo-expand`. Update the snapshots after changing the generated
+//! code with
+//! `MACROTEST=overwrite cargo test --manifest-path tests/no-default-features/Cargo.toml expand`.
+
+#[test]
+fn expand() {
+    macrotest::expand("expand/*.rs");
+}
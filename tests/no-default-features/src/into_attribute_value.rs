@@ -0,0 +1,22 @@
+//! Tests for `#[leptos_unique_ids()]` attribute macro with only the
+//! `into-attribute-value` feature enabled.
+
+use leptos::prelude::*;
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("main-section", "language-selector")]
+pub enum Ids {}
+
+#[test]
+fn view_id() {
+    let html = view! {
+        <div id=Ids::MainSection>
+            <select id=Ids::LanguageSelector></select>
+        </div>
+    }
+    .to_html();
+    assert_eq!(
+        html,
+        "<div id=\"main-section\"><select id=\"language-selector\"></select></div>"
+    );
+}
@@ -0,0 +1,6 @@
+//! Run with `cargo test --manifest-path tests/no-default-features/Cargo.toml`.
+
+#[cfg(test)]
+mod expand;
+#[cfg(test)]
+mod into_attribute_value;
@@ -0,0 +1,53 @@
+//! Run with `cargo test --manifest-path tests/no-std/Cargo.toml`, as this
+//! crate is not a member of the workspace.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{borrow::Cow, string::String};
+
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("language-selector", "preview")]
+pub enum Ids {}
+
+#[must_use]
+pub fn as_str(id: &Ids) -> &'static str {
+    id.as_str()
+}
+
+#[must_use]
+pub fn as_ref(id: &Ids) -> &str {
+    id.as_ref()
+}
+
+#[must_use]
+pub fn into_str(id: Ids) -> &'static str {
+    id.into()
+}
+
+#[must_use]
+pub fn into_string(id: Ids) -> String {
+    id.into()
+}
+
+#[must_use]
+pub fn into_cow(id: Ids) -> Cow<'static, str> {
+    id.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Ids, as_ref, as_str, into_cow, into_str, into_string};
+
+    #[test]
+    fn conversions() {
+        assert_eq!(as_str(&Ids::LanguageSelector), "language-selector");
+        assert_eq!(as_ref(&Ids::Preview), "preview");
+        assert_eq!(into_str(Ids::Preview), "preview");
+        assert_eq!(into_string(Ids::LanguageSelector), "language-selector");
+        assert_eq!(into_cow(Ids::Preview), "preview");
+        Ids::assert_all_unique();
+    }
+}
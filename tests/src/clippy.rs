@@ -0,0 +1,22 @@
+//! Tests for the lints of clippy allowed in enums generated by
+//! `#[leptos_unique_ids()]` attribute macro, checked running clippy.
+
+#![deny(clippy::enum_variant_names)]
+
+use leptos_unique_ids::leptos_unique_ids;
+
+// private enums are checked by the lint even with `avoid-breaking-exported-api`
+#[leptos_unique_ids(
+    "preview-download-svg-button",
+    "preview-upload-svg-button",
+    "preview-copy-svg-button"
+)]
+enum Ids {}
+
+#[test]
+fn enum_variant_names_allowed() {
+    assert_eq!(
+        Ids::PreviewUploadSvgButton.as_str(),
+        "preview-upload-svg-button"
+    );
+}
@@ -0,0 +1,15 @@
+//! Tests for the `env` argument of `#[leptos_unique_ids()]` attribute macro.
+
+use leptos_unique_ids::leptos_unique_ids;
+
+// set in the `[env]` table of the Cargo configuration of the workspace
+#[leptos_unique_ids("footer", env = "LEPTOS_UNIQUE_IDS_TEST_IDS")]
+enum Ids {}
+
+#[test]
+fn ids_read_from_env() {
+    assert_eq!(Ids::variants_count(), 3);
+    assert_eq!(Ids::Footer.as_str(), "footer");
+    assert_eq!(Ids::LanguageSelector.as_str(), "language-selector");
+    assert_eq!(Ids::Preview.as_str(), "preview");
+}
@@ -0,0 +1,11 @@
+//! Snapshots of the code generated by `#[leptos_unique_ids()]` attribute macro
+//! with the features enabled in the `tests` crate. They are skipped with its
+//! `phf` feature, which changes the generated `FromStr` implementations.
+//!
+//! Requires `cargo-expand`. Update the snapshots after changing the generated
+//! code with `MACROTEST=overwrite cargo test -p tests expand`.
+
+#[test]
+fn expand() {
+    macrotest::expand("expand/*.rs");
+}
@@ -0,0 +1,22 @@
+//! Browser tests for `Ids::get_element` of the `web-sys` feature.
+
+use leptos_unique_ids::leptos_unique_ids;
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[leptos_unique_ids("inserted-element", "missing-element")]
+enum Ids {}
+
+#[wasm_bindgen_test]
+fn get_element() {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let element = document.create_element("span").unwrap();
+    element.set_id(Ids::InsertedElement.as_str());
+    document.body().unwrap().append_child(&element).unwrap();
+
+    let found = Ids::InsertedElement.get_element().unwrap();
+    assert_eq!(found.id(), "inserted-element");
+    assert_eq!(found.tag_name(), "SPAN");
+    assert!(Ids::MissingElement.get_element().is_none());
+}
@@ -0,0 +1,51 @@
+//! Tests for the identifiers emitted by `#[leptos_unique_ids()]` attribute macro
+//! with `hashed_in_release`, run in debug and release profiles.
+
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("language-selector", "preview", hashed_in_release)]
+enum Ids {}
+
+#[cfg(debug_assertions)]
+#[test]
+fn readable_in_debug() {
+    assert_eq!(Ids::LanguageSelector.as_str(), "language-selector");
+    assert_eq!(Ids::Preview.as_str(), "preview");
+    assert_eq!(ids::LANGUAGE_SELECTOR, "language-selector");
+    assert!(Ids::contains("preview"));
+    assert!(!Ids::contains("hksxopj"));
+    assert!(matches!("preview".parse::<Ids>(), Ok(Ids::Preview)));
+    assert!("hksxopj".parse::<Ids>().is_err());
+}
+
+#[cfg(not(debug_assertions))]
+#[test]
+fn hashed_in_release() {
+    assert_eq!(Ids::LanguageSelector.as_str(), "hwrd9k7");
+    assert_eq!(Ids::Preview.as_str(), "hksxopj");
+    assert_eq!(ids::LANGUAGE_SELECTOR, "hwrd9k7");
+    assert!(Ids::contains("hksxopj"));
+    assert!(!Ids::contains("preview"));
+    assert!(matches!("hksxopj".parse::<Ids>(), Ok(Ids::Preview)));
+    assert!("preview".parse::<Ids>().is_err());
+}
+
+#[test]
+fn consistent_with_as_str() {
+    for variant in Ids::ALL {
+        let id = variant.as_str();
+        assert!(Ids::contains(id));
+        assert_eq!(
+            id.parse::<Ids>().map(|parsed| parsed.index()),
+            Ok(variant.index())
+        );
+        assert_eq!(
+            Ids::from_str_sorted(id).map(|found| found.index()),
+            Some(variant.index())
+        );
+    }
+    assert_eq!(Ids::SORTED_NAMES.len(), 2);
+    assert!(Ids::SORTED_NAMES.is_sorted_by_key(|(id, _)| *id));
+    assert_eq!(Ids::LanguageSelector.local_name(), "language-selector");
+    Ids::assert_all_unique();
+}
@@ -6,6 +6,14 @@ fn fail() {
     t.compile_fail("ui/fail/*.rs");
 }
 
+// identifiers only rejected when the `unicode` feature is disabled
+#[cfg(not(feature = "unicode"))]
+#[test]
+fn fail_ascii() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("ui/fail/ascii/*.rs");
+}
+
 #[test]
 fn pass() {
     let t = trybuild::TestCases::new();
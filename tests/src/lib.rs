@@ -1,4 +1,20 @@
-#[cfg(test)]
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod clippy;
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod env;
+#[cfg(all(test, not(target_arch = "wasm32"), not(feature = "phf")))]
+mod expand;
+#[cfg(all(test, target_arch = "wasm32"))]
+mod get_element;
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod hashed_in_release;
+#[cfg(all(test, not(target_arch = "wasm32")))]
 mod leptos_unique_ids;
-#[cfg(test)]
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod manifest;
+#[cfg(all(test, not(target_arch = "wasm32")))]
 mod meta;
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod phf;
+#[cfg(all(test, target_arch = "wasm32"))]
+mod runtime_check;
@@ -0,0 +1,16 @@
+//! Tests for the `manifest` argument of `#[leptos_unique_ids()]` attribute macro.
+
+use leptos_unique_ids::leptos_unique_ids;
+
+// the tests crate has no build script, so the path is relative to its manifest
+#[leptos_unique_ids("foo", "bar-baz", manifest = "../target/leptos-unique-ids/ids.json")]
+#[allow(dead_code)]
+enum Ids {}
+
+#[test]
+fn manifest_written() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../target/leptos-unique-ids/ids.json");
+    let content = std::fs::read_to_string(path).unwrap();
+    assert_eq!(content, "[\"foo\",\"bar-baz\"]\n");
+}
@@ -0,0 +1,67 @@
+//! Tests for the `FromStr` implementation generated by `#[leptos_unique_ids()]`
+//! attribute macro for a large enum, checked against a linear search of the
+//! identifiers. Run them with the perfect hash map of the `phf` feature with
+//! `cargo test -p tests --features phf`.
+
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("footer", files = ["phf/ids.txt"])]
+enum Ids {}
+
+#[leptos_unique_ids(
+    "language-selector",
+    "preview",
+    #[cfg(any())]
+    "admin-panel",
+    hashed_in_release,
+)]
+enum HashedIds {}
+
+fn linear_search(s: &str) -> Option<usize> {
+    Ids::ALL.iter().position(|id| id.as_str() == s)
+}
+
+#[test]
+fn declared_identifiers() {
+    assert_eq!(Ids::variants_count(), 601);
+    for variant in Ids::ALL {
+        let id = variant.as_str();
+        assert_eq!(
+            id.parse::<Ids>().ok().map(|parsed| parsed.index()),
+            linear_search(id)
+        );
+        assert_eq!(
+            Ids::try_from(id).map(|parsed| parsed.index()),
+            Ok(variant.index())
+        );
+    }
+}
+
+#[test]
+fn unknown_identifiers() {
+    for variant in Ids::ALL {
+        let id = variant.as_str();
+        for unknown in [
+            format!("{id}-"),
+            format!("x{id}"),
+            id[..id.len() - 1].to_string(),
+            id.to_uppercase(),
+        ] {
+            assert_eq!(linear_search(&unknown), None);
+            assert!(unknown.parse::<Ids>().is_err(), "{unknown}");
+        }
+    }
+    assert!("".parse::<Ids>().is_err());
+}
+
+#[test]
+fn hashed_in_release() {
+    for variant in HashedIds::ALL {
+        let id = variant.as_str();
+        assert_eq!(
+            id.parse::<HashedIds>().map(|parsed| parsed.index()),
+            Ok(variant.index())
+        );
+    }
+    assert!("admin-panel".parse::<HashedIds>().is_err());
+}
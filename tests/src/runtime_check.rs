@@ -0,0 +1,26 @@
+//! Browser tests for `Ids::assert_unique_in_dom` of the `runtime-check` feature.
+
+use leptos::prelude::document;
+use leptos_unique_ids::leptos_unique_ids;
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[leptos_unique_ids("unique", "duplicated")]
+enum Ids {}
+
+fn append_element_with_id(id: Ids) {
+    let document = document();
+    let element = document.create_element("div").unwrap();
+    element.set_id(id.as_str());
+    document.body().unwrap().append_child(&element).unwrap();
+}
+
+#[wasm_bindgen_test]
+#[should_panic(expected = "Identifier \"duplicated\" is used by 2 elements in the DOM.")]
+fn duplicated_id() {
+    append_element_with_id(Ids::Unique);
+    append_element_with_id(Ids::Duplicated);
+    append_element_with_id(Ids::Duplicated);
+    Ids::assert_unique_in_dom();
+}
@@ -0,0 +1,6 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", "b\"ár")]
+pub enum Ids {}
+
+fn main() {}
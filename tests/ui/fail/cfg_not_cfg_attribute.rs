@@ -0,0 +1,6 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", #[doc = "bar"] "bar")]
+pub enum Ids {}
+
+fn main() {}
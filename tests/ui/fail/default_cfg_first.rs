@@ -0,0 +1,6 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids(#[cfg(debug_assertions)] "foo", "bar", default)]
+pub enum Ids {}
+
+fn main() {}
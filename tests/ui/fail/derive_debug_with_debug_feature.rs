@@ -0,0 +1,7 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("language-selector")]
+#[derive(Clone, Debug)]
+pub enum Ids {}
+
+fn main() {}
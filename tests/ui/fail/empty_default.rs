@@ -1,6 +1,6 @@
 use leptos_unique_ids::leptos_unique_ids;
 
-#[leptos_unique_ids]
+#[leptos_unique_ids(default)]
 pub enum Ids {}
 
 fn main() {}
@@ -0,0 +1,6 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids(repr = "u16")]
+pub enum Ids {}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+// The types of `Id` would collide with the ones of `Ids`
+#[leptos_unique_ids("foo", "bar")]
+pub enum Id {}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo")]
+pub enum Ids<T> {} // Generics are not allowed
+
+fn main() {}
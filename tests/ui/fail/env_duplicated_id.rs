@@ -0,0 +1,6 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("preview", env = "LEPTOS_UNIQUE_IDS_TEST_IDS")]
+pub enum Ids {}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", env = "LEPTOS_UNIQUE_IDS_UNSET")]
+pub enum Ids {}
+
+fn main() {}
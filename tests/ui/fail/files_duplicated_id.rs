@@ -0,0 +1,6 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("footer", files = ["files/core.txt", "files/admin.txt"])]
+pub enum Ids {}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids(files = ["files/core.txt"], "preview")]
+pub enum Ids {}
+
+fn main() {}
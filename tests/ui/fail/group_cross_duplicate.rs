@@ -0,0 +1,9 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids(
+    group "nav" { "language-selector", "logo" },
+    group "footer" { "links", "logo" },
+)]
+pub enum Ids {}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("LanguageSelector", "language-selector", id_case = "kebab")]
+pub enum Ids {}
+
+fn main() {}
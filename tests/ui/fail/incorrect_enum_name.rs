@@ -1,6 +0,0 @@
-use leptos_unique_ids::leptos_unique_ids;
-
-#[leptos_unique_ids]
-pub enum Foo {} // This should be called `Ids` instead
-
-fn main() {}
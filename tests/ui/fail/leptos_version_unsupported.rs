@@ -0,0 +1,6 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", leptos_version = "0.5")]
+pub enum Ids {}
+
+fn main() {}
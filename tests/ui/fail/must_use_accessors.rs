@@ -0,0 +1,12 @@
+#![deny(unused_must_use)]
+
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo")]
+pub enum Ids {}
+
+fn main() {
+    Ids::Foo.as_str();
+    Ids::Foo.local_name();
+    Ids::Foo.index();
+}
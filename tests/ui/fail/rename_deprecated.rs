@@ -0,0 +1,11 @@
+#![deny(deprecated)]
+
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("settings-panel", rename("preferences-panel" => "settings-panel"))]
+pub enum Ids {}
+
+fn main() {
+    let _ = Ids::SettingsPanel.as_str();
+    let _ = Ids::PreferencesPanel.as_str();
+}
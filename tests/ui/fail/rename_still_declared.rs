@@ -0,0 +1,10 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids(
+    "settings-panel",
+    "preferences-panel",
+    rename("preferences-panel" => "settings-panel"),
+)]
+pub enum Ids {}
+
+fn main() {}
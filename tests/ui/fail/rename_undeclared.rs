@@ -0,0 +1,6 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("settings-panel", rename("preferences-panel" => "settings"))]
+pub enum Ids {}
+
+fn main() {}
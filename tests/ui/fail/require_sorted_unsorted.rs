@@ -0,0 +1,6 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("footer", "preview", "language-selector", require_sorted)]
+pub enum Ids {}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", "root", reserved = ["root", "app"])]
+pub enum Ids {}
+
+fn main() {}
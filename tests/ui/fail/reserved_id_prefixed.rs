@@ -0,0 +1,6 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", "root", prefix = "app-", reserved = ["app-root"])]
+pub enum Ids {}
+
+fn main() {}
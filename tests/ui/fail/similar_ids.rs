@@ -0,0 +1,8 @@
+#![deny(deprecated)]
+
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("user-name", "language-selector", "username")]
+pub enum Ids {}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", "nav.main", strict_css)]
+pub enum Ids {}
+
+fn main() {}
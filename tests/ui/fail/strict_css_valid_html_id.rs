@@ -0,0 +1,7 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+// valid HTML 4 id, but the colon must be escaped in CSS selectors
+#[leptos_unique_ids("user:avatar", strict_html, strict_css)]
+pub enum Ids {}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", "1st-item", strict_html)]
+pub enum Ids {}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", "bar", "foo")]
+pub struct Ids;
+
+fn main() {}
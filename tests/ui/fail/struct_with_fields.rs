@@ -0,0 +1,8 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo")]
+pub struct Ids {
+    foo: &'static str,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+// variants are located at their string literals, so diagnostics and IDE
+// navigation point to them instead of the whole attribute
+#[leptos_unique_ids("language-selector", "preview")]
+pub enum Ids {}
+
+fn main() {
+    match Ids::Preview {
+        Ids::Preview => {}
+    }
+}
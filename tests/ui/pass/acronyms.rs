@@ -0,0 +1,20 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids(
+    "html-parser",
+    "svg-url",
+    "preview-download-svg-button",
+    "language-selector",
+    acronyms = ["html", "svg", "url"],
+)]
+pub enum Ids {}
+
+fn main() {
+    assert_eq!(Ids::HTMLParser.as_str(), "html-parser");
+    assert_eq!(Ids::SVGURL.as_str(), "svg-url");
+    assert_eq!(
+        Ids::PreviewDownloadSVGButton.as_str(),
+        "preview-download-svg-button"
+    );
+    assert_eq!(Ids::LanguageSelector.as_str(), "language-selector");
+}
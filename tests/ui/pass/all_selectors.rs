@@ -0,0 +1,28 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("language-selector", "preview", "download-button")]
+pub enum Ids {}
+
+mod sorted {
+    use leptos_unique_ids::leptos_unique_ids;
+
+    #[leptos_unique_ids("language-selector", "preview", "download-button", order = "sorted")]
+    pub enum Ids {}
+}
+
+mod escaped {
+    use leptos_unique_ids::leptos_unique_ids;
+
+    #[leptos_unique_ids("main.content", "nav:links")]
+    pub enum Ids {}
+}
+
+fn main() {
+    const SELECTORS: &str = Ids::ALL_SELECTORS;
+    assert_eq!(SELECTORS, "#language-selector,#preview,#download-button");
+    assert_eq!(
+        sorted::Ids::ALL_SELECTORS,
+        "#download-button,#language-selector,#preview"
+    );
+    assert_eq!(escaped::Ids::ALL_SELECTORS, "#main\\.content,#nav\\:links");
+}
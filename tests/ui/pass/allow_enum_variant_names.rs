@@ -0,0 +1,19 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("preview-download-button", "preview-upload-button")]
+pub enum Ids {}
+
+mod other {
+    use leptos_unique_ids::leptos_unique_ids;
+
+    #[leptos_unique_ids("preview-copy-button", allow_enum_variant_names = false)]
+    pub enum Ids {}
+}
+
+fn main() {
+    assert_eq!(Ids::PreviewUploadButton.as_str(), "preview-upload-button");
+    assert_eq!(
+        other::Ids::PreviewCopyButton.as_str(),
+        "preview-copy-button"
+    );
+}
@@ -0,0 +1,41 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("language-selector", "preview", "download-button")]
+pub enum Ids {}
+
+mod sorted {
+    use leptos_unique_ids::leptos_unique_ids;
+
+    #[leptos_unique_ids("language-selector", "preview", "download-button", order = "sorted")]
+    pub enum Ids {}
+}
+
+mod empty {
+    use leptos_unique_ids::leptos_unique_ids;
+
+    #[leptos_unique_ids()]
+    pub enum Ids {}
+}
+
+const A: [&str; 3] = Ids::as_array();
+const LENGTHS: [usize; 3] = {
+    let mut lengths = [0; 3];
+    let mut i = 0;
+    while i < A.len() {
+        lengths[i] = A[i].len();
+        i += 1;
+    }
+    lengths
+};
+
+fn main() {
+    assert_eq!(A, ["language-selector", "preview", "download-button"]);
+    assert_eq!(LENGTHS, [17, 7, 15]);
+    assert_eq!(
+        sorted::Ids::as_array(),
+        ["download-button", "language-selector", "preview"]
+    );
+
+    let empty: [&str; 0] = empty::Ids::as_array();
+    assert!(empty.is_empty());
+}
@@ -0,0 +1,19 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("language-selector", "preview")]
+pub enum Ids {}
+
+#[leptos_unique_ids("root", prefix = "nav-")]
+pub enum NavIds {}
+
+const PREVIEW: &[u8] = Ids::Preview.as_bytes();
+
+fn main() {
+    assert_eq!(PREVIEW, b"preview");
+    assert_eq!(Ids::LanguageSelector.as_bytes(), b"language-selector");
+    assert_eq!(NavIds::Root.as_bytes(), b"nav-root");
+
+    for id in Ids::ALL {
+        assert_eq!(id.as_bytes(), id.as_str().as_bytes());
+    }
+}
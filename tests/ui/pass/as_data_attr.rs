@@ -0,0 +1,16 @@
+use leptos::prelude::*;
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("main-section", "language-selector")]
+pub enum Ids {}
+
+fn main() {
+    let _view = view! {
+        <div id=Ids::MainSection data-section=Ids::MainSection>
+            <select
+                id=Ids::LanguageSelector
+                {..Ids::LanguageSelector.as_data_attr("selector")}
+            ></select>
+        </div>
+    };
+}
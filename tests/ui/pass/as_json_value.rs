@@ -0,0 +1,12 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", "bar-baz")]
+pub enum Ids {}
+
+fn main() {
+    assert_eq!(
+        Ids::Foo.as_json_value(),
+        serde_json::Value::String("foo".to_string())
+    );
+    assert_eq!(Ids::BarBaz.as_json_value(), "bar-baz");
+}
@@ -0,0 +1,14 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", "bar-baz")]
+pub enum Ids {}
+
+fn id_length(id: impl AsRef<str>) -> usize {
+    id.as_ref().len()
+}
+
+fn main() {
+    assert_eq!(id_length(Ids::Foo), 3);
+    assert_eq!(id_length(Ids::BarBaz), 7);
+    assert_eq!(Ids::BarBaz.as_ref(), "bar-baz");
+}
@@ -0,0 +1,28 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("language-selector", "preview")]
+pub enum Ids {}
+
+#[leptos_unique_ids("login-form", prefix = "app-")]
+pub enum FormIds {}
+
+fn assert_copy<T: Copy>(value: T) -> (T, T) {
+    (value, value)
+}
+
+fn main() {
+    assert_eq!(
+        format!("{}", Ids::LanguageSelector.as_selector()),
+        "#language-selector"
+    );
+    assert_eq!(Ids::Preview.as_selector().to_string(), "#preview");
+    assert_eq!(
+        format!("{} > p", FormIdSelector::clone(&FormIds::LoginForm.as_selector())),
+        "#app-login-form > p"
+    );
+
+    // the wrapper is `Copy` even if the enum isn't
+    let (first, second) = assert_copy(Ids::Preview.as_selector());
+    assert_eq!(first, second);
+    assert_ne!(first, Ids::LanguageSelector.as_selector());
+}
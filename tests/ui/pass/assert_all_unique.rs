@@ -0,0 +1,12 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", "bar-baz", prefix = "app-")]
+pub enum Ids {}
+
+#[leptos_unique_ids()]
+pub enum EmptyIds {}
+
+fn main() {
+    Ids::assert_all_unique();
+    EmptyIds::assert_all_unique();
+}
@@ -0,0 +1,15 @@
+use leptos::attr::custom::custom_attribute;
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", "bar-baz")]
+pub enum Ids {}
+
+fn main() {
+    assert_eq!(Ids::Foo.attr(), ("id", "foo"));
+
+    let (name, value) = Ids::BarBaz.attr();
+    assert_eq!(name, "id");
+    assert_eq!(value, "bar-baz");
+
+    let _attribute = custom_attribute(name, value);
+}
@@ -0,0 +1,20 @@
+use leptos::attr::custom::custom_attribute;
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", "bar-baz")]
+pub enum Ids {}
+
+fn main() {
+    assert_eq!(
+        Ids::Foo.attrs_with_testid(),
+        [("id", "foo"), ("data-testid", "foo")]
+    );
+
+    let [(id_name, id_value), (testid_name, testid_value)] = Ids::BarBaz.attrs_with_testid();
+    assert_eq!((id_name, id_value), ("id", "bar-baz"));
+    assert_eq!((testid_name, testid_value), ("data-testid", "bar-baz"));
+
+    let _attributes = Ids::BarBaz
+        .attrs_with_testid()
+        .map(|(name, value)| custom_attribute(name, value));
+}
@@ -0,0 +1,21 @@
+use std::collections::{HashMap, HashSet};
+
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("language-selector", "preview")]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Ids {}
+
+fn main() {
+    let mut labels = HashMap::new();
+    labels.insert(Ids::LanguageSelector, "Language");
+    labels.insert(Ids::Preview, "Preview");
+
+    assert_eq!(labels.get("language-selector"), Some(&"Language"));
+    assert_eq!(labels.get("preview"), Some(&"Preview"));
+    assert_eq!(labels.get(&Ids::Preview), Some(&"Preview"));
+    assert!(labels.get("unknown").is_none());
+
+    let ids: HashSet<Ids> = Ids::ALL.iter().copied().collect();
+    assert!(ids.contains("preview"));
+}
@@ -0,0 +1,61 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids(
+    "language-selector",
+    #[cfg(all())]
+    "admin-panel",
+    #[cfg(any())]
+    "beta-banner",
+    "preview",
+)]
+pub enum Ids {}
+
+// compiled with and without debug assertions, like in release builds
+#[leptos_unique_ids(
+    #[cfg(debug_assertions)]
+    "debug-panel",
+    "preview",
+    group "nav" { "logo", #[cfg(not(debug_assertions))] "release-notes" },
+    order = "sorted",
+)]
+pub enum ProfileIds {}
+
+fn main() {
+    assert_eq!(Ids::AdminPanel.as_str(), "admin-panel");
+    assert_eq!(Ids::ALL.len(), 3);
+    assert_eq!(Ids::variants_count(), 3);
+    assert_eq!(Ids::as_array(), ["language-selector", "admin-panel", "preview"]);
+    assert_eq!(Ids::ALL_SELECTORS, "#language-selector,#admin-panel,#preview");
+    assert!(Ids::contains("admin-panel"));
+    assert!(!Ids::contains("beta-banner"));
+    assert!("beta-banner".parse::<Ids>().is_err());
+    assert_eq!(Ids::Preview.index(), 2);
+    assert!(matches!(Ids::from_index(1), Some(Ids::AdminPanel)));
+    assert!(matches!(Ids::from_index(2), Some(Ids::Preview)));
+    assert!(Ids::from_index(3).is_none());
+    assert!(matches!(Ids::from_str_sorted("preview"), Some(Ids::Preview)));
+    assert!(matches!(Ids::AdminPanel.next(), Ids::Preview));
+    assert!(matches!(Ids::Preview.prev(), Ids::AdminPanel));
+    assert!(matches!(Ids::Preview.next(), Ids::LanguageSelector));
+
+    assert_eq!(ProfileIds::variants_count(), 3);
+    for (index, id) in ProfileIds::iter().enumerate() {
+        assert_eq!(id.index(), index);
+        assert!(matches!(ProfileIds::from_index(index), Some(other) if other == id.as_str()));
+        assert!(matches!(ProfileIds::from_str_sorted(id.as_str()), Some(other) if other == id.as_str()));
+        assert_eq!(id.next().prev().as_str(), id.as_str());
+    }
+    assert_eq!(profile_ids::LOGO, "logo");
+    assert_eq!(nav::Logo.as_str(), "logo");
+
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(ProfileIds::DebugPanel.as_str(), "debug-panel");
+        assert_eq!(ProfileIds::ALL_SELECTORS, "#debug-panel,#logo,#preview");
+    }
+    #[cfg(not(debug_assertions))]
+    {
+        assert_eq!(nav::ReleaseNotes.as_str(), "release-notes");
+        assert_eq!(ProfileIds::ALL_SELECTORS, "#logo,#preview,#release-notes");
+    }
+}
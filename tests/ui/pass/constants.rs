@@ -0,0 +1,13 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("language-selector", "preview_svg-button", "h1")]
+pub enum Ids {}
+
+const SELECTOR: &str = ids::LANGUAGE_SELECTOR;
+
+fn main() {
+    assert_eq!(SELECTOR, "language-selector");
+    assert_eq!(ids::PREVIEW_SVG_BUTTON, "preview_svg-button");
+    assert_eq!(ids::H1, "h1");
+    assert_eq!(ids::H1, Ids::H1.as_str());
+}
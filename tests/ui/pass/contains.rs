@@ -0,0 +1,22 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", "bar-baz")]
+pub enum Ids {}
+
+mod empty {
+    use leptos_unique_ids::leptos_unique_ids;
+
+    #[leptos_unique_ids()]
+    pub enum Ids {}
+}
+
+fn main() {
+    assert!(Ids::contains("foo"));
+    assert!(Ids::contains("bar-baz"));
+    assert!(!Ids::contains("BarBaz"));
+    assert!(!Ids::contains("unknown"));
+    assert!(!Ids::contains(""));
+
+    assert!(!empty::Ids::contains("foo"));
+    assert!(!empty::Ids::contains(""));
+}
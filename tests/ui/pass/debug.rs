@@ -0,0 +1,21 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("language-selector", "preview")]
+pub enum Ids {}
+
+#[leptos_unique_ids()]
+pub enum EmptyIds {}
+
+fn main() {
+    assert_eq!(
+        format!("{:?}", Ids::LanguageSelector),
+        "LanguageSelector(\"language-selector\")"
+    );
+    assert_eq!(format!("{:?}", Ids::Preview), "Preview(\"preview\")");
+    assert_eq!(
+        format!("{:#?}", Ids::Preview),
+        "Preview(\n    \"preview\",\n)"
+    );
+
+    let _ = EmptyIds::ALL;
+}
@@ -0,0 +1,15 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", "bar", "baz", default, order = "sorted")]
+pub enum Ids {}
+
+#[derive(Default)]
+struct Component {
+    id: Ids,
+}
+
+fn main() {
+    // the first declared variant, regardless of `order`
+    assert_eq!(Ids::default().as_str(), "foo");
+    assert_eq!(Component::default().id.as_str(), "foo");
+}
@@ -0,0 +1,20 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("language-selector", "preview")]
+pub enum Ids {}
+
+fn takes_str(value: &str) -> usize {
+    value.len()
+}
+
+fn main() {
+    assert!(Ids::LanguageSelector.starts_with("language"));
+    assert_eq!(Ids::Preview.len(), 7);
+    assert_eq!(&Ids::LanguageSelector[..8], "language");
+    assert_eq!(Ids::LanguageSelector.split('-').count(), 2);
+    assert_eq!(takes_str(&Ids::Preview), 7);
+
+    // methods of the enum take precedence over the `str` ones
+    assert!(Ids::contains("preview"));
+    assert!(Ids::Preview.as_str().contains("view"));
+}
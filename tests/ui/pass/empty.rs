@@ -0,0 +1,39 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids()]
+pub enum Ids {}
+
+mod without_parentheses {
+    use leptos_unique_ids::leptos_unique_ids;
+
+    #[leptos_unique_ids]
+    pub enum Ids {}
+}
+
+mod trailing_comma {
+    use leptos_unique_ids::leptos_unique_ids;
+
+    #[leptos_unique_ids("foo", "bar")]
+    pub enum Ids {}
+}
+
+#[allow(dead_code)]
+fn as_str(id: &Ids) -> &'static str {
+    // the generated methods typecheck without variants
+    let _ = id.next().index();
+    id.as_str()
+}
+
+fn main() {
+    assert!(Ids::ALL.is_empty());
+    assert!(Ids::all().is_empty());
+    assert!(Ids::PAIRS.is_empty());
+    assert!(Ids::SORTED_NAMES.is_empty());
+    assert!(Ids::ALL_SELECTORS.is_empty());
+    assert!(Ids::from_index(0).is_none());
+    assert!(Ids::from_str_sorted("foo").is_none());
+    assert!(without_parentheses::Ids::ALL.is_empty());
+
+    assert_eq!(trailing_comma::Ids::Bar.as_str(), "bar");
+    assert_eq!(trailing_comma::Ids::ALL.len(), 2);
+}
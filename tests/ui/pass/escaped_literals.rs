@@ -0,0 +1,14 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("say-\"hi\"", "back\\slash", "\x61scii", r#"raw-"quote""#)]
+pub enum Ids {}
+
+fn main() {
+    assert_eq!(Ids::SayHi.as_str(), "say-\"hi\"");
+    assert_eq!(Ids::BackSlash.as_str(), "back\\slash");
+    assert_eq!(Ids::Ascii.as_str(), "ascii");
+    assert_eq!(Ids::RawQuote.as_str(), "raw-\"quote\"");
+
+    assert!(matches!("say-\"hi\"".parse::<Ids>(), Ok(Ids::SayHi)));
+    assert_eq!(ids::SAY_HI, "say-\"hi\"");
+}
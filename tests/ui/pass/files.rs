@@ -0,0 +1,13 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("footer", files = ["files/core.txt", "files/admin.txt"])]
+pub enum Ids {}
+
+fn main() {
+    assert_eq!(Ids::variants_count(), 5);
+    assert_eq!(Ids::Footer.as_str(), "footer");
+    assert_eq!(Ids::LanguageSelector.as_str(), "language-selector");
+    assert_eq!(Ids::Preview.as_str(), "preview");
+    assert_eq!(Ids::UsersTable.as_str(), "users-table");
+    assert_eq!(Ids::UserSettings.as_str(), "user-settings");
+}
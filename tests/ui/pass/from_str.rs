@@ -0,0 +1,37 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids(
+    "foo",
+    "bar",
+    "baz",
+    "qux-a",
+    "qux-b",
+    "preview",
+    "a",
+    "language-selector"
+)]
+pub enum Ids {}
+
+mod empty {
+    use leptos_unique_ids::leptos_unique_ids;
+
+    #[leptos_unique_ids()]
+    pub enum Ids {}
+}
+
+fn main() {
+    for id in Ids::iter() {
+        assert_eq!(id.as_str().parse::<Ids>().unwrap().as_str(), id.as_str());
+    }
+
+    // same length as declared identifiers
+    for unknown in ["fox", "qux-c", "b", "previes"] {
+        assert!(unknown.parse::<Ids>().is_err());
+    }
+    // different length than any declared identifier
+    for unknown in ["", "fo", "foo-bar", "language-selectors"] {
+        assert!(unknown.parse::<Ids>().is_err());
+    }
+
+    assert!("foo".parse::<empty::Ids>().is_err());
+}
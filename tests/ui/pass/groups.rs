@@ -0,0 +1,19 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids(
+    "preview",
+    group "nav" { "language-selector", "logo" },
+    group "main-footer" prefixed { "logo", ("links", doc = "Footer links.") },
+)]
+pub enum Ids {}
+
+fn main() {
+    assert_eq!(nav::LanguageSelector.as_str(), "language-selector");
+    assert_eq!(nav::Logo.as_str(), "logo");
+    assert_eq!(main_footer::Logo.as_str(), "main-footer-logo");
+    assert_eq!(main_footer::Links.as_str(), "main-footer-links");
+
+    assert!(matches!(nav::Logo, Ids::Logo));
+    assert!(matches!(main_footer::Logo, Ids::MainFooterLogo));
+    assert_eq!(Ids::variants_count(), 5);
+}
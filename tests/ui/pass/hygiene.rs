@@ -0,0 +1,40 @@
+//! The generated code doesn't depend on the names in scope where the macro is
+//! used, so local items can shadow the crates and prelude items it references.
+
+#![allow(dead_code)]
+
+mod shadowed {
+    use leptos_unique_ids::leptos_unique_ids;
+
+    mod core {}
+    mod leptos {}
+    mod std {}
+
+    struct Option;
+    struct Result;
+    struct Some;
+    struct None;
+    struct Ok;
+    struct Err;
+
+    #[leptos_unique_ids("foo", "bar-baz")]
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub enum Ids {}
+}
+
+use ::leptos::prelude::IntoAttributeValue;
+use shadowed::Ids;
+
+fn main() {
+    let id: &'static str = Ids::Foo.into();
+    assert_eq!(id, "foo");
+    assert_eq!(Ids::BarBaz.as_ref(), "bar-baz");
+    assert_eq!(Ids::BarBaz.into_attribute_value(), "bar-baz");
+
+    assert!(matches!(Ids::from_index(1), Some(Ids::BarBaz)));
+    assert!(Ids::from_index(2).is_none());
+    assert!(matches!("foo".parse::<Ids>(), Ok(Ids::Foo)));
+    assert!("baz".parse::<Ids>().is_err());
+    assert!(matches!(Ids::try_from(0_usize), Ok(Ids::Foo)));
+    assert!(Ids::Foo.matches_selector("#foo"));
+}
@@ -0,0 +1,40 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+mod kebab {
+    use leptos_unique_ids::leptos_unique_ids;
+
+    #[leptos_unique_ids("LanguageSelector", "preview_panel", "HTMLParser", id_case = "kebab")]
+    pub enum Ids {}
+}
+
+mod snake {
+    use leptos_unique_ids::leptos_unique_ids;
+
+    #[leptos_unique_ids("LanguageSelector", "preview-panel", id_case = "snake")]
+    pub enum Ids {}
+}
+
+mod camel {
+    use leptos_unique_ids::leptos_unique_ids;
+
+    #[leptos_unique_ids("language-selector", "preview_panel", id_case = "camel")]
+    pub enum Ids {}
+}
+
+#[leptos_unique_ids("LanguageSelector", allow_unused = ["LanguageSelector"], id_case = "kebab")]
+pub enum Ids {}
+
+fn main() {
+    assert_eq!(kebab::Ids::LanguageSelector.as_str(), "language-selector");
+    assert_eq!(kebab::Ids::PreviewPanel.as_str(), "preview-panel");
+    assert_eq!(kebab::Ids::HtmlParser.as_str(), "html-parser");
+    assert_eq!(kebab::Ids::PAIRS[0].0, "language-selector");
+
+    assert_eq!(snake::Ids::LanguageSelector.as_str(), "language_selector");
+    assert_eq!(snake::Ids::PreviewPanel.as_str(), "preview_panel");
+
+    assert_eq!(camel::Ids::LanguageSelector.as_str(), "languageSelector");
+    assert_eq!(camel::Ids::PreviewPanel.as_str(), "previewPanel");
+
+    assert_eq!(Ids::LanguageSelector.as_str(), "language-selector");
+}
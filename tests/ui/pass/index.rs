@@ -0,0 +1,29 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", "bar", "baz")]
+pub enum Ids {}
+
+mod sorted {
+    use leptos_unique_ids::leptos_unique_ids;
+
+    #[leptos_unique_ids("foo", "bar", "baz", order = "sorted")]
+    pub enum Ids {}
+}
+
+const BAR_INDEX: usize = Ids::Bar.index();
+
+fn main() {
+    assert_eq!(BAR_INDEX, 1);
+
+    for (i, id) in Ids::ALL.iter().enumerate() {
+        assert_eq!(id.index(), i);
+        assert_eq!(Ids::from_index(i).unwrap().as_str(), id.as_str());
+    }
+    assert!(Ids::from_index(Ids::ALL.len()).is_none());
+
+    for (i, id) in sorted::Ids::ALL.iter().enumerate() {
+        assert_eq!(id.index(), i);
+        assert_eq!(sorted::Ids::from_index(i).unwrap().as_str(), id.as_str());
+    }
+    assert_eq!(sorted::Ids::Bar.index(), 0);
+}
@@ -0,0 +1,45 @@
+// `::leptos` paths of the generated code resolve to the stub below
+extern crate self as leptos;
+
+use leptos_unique_ids::leptos_unique_ids;
+
+pub mod prelude {
+    /// Stub of the `Oco` type of Leptos.
+    pub enum Oco<'a, T: ?Sized + 'a> {
+        Borrowed(&'a T),
+    }
+
+    /// Stub of the `IntoAttributeValue` trait of Leptos 0.7.
+    pub trait IntoAttributeValue {
+        type Output;
+        fn into_attribute_value(self) -> Self::Output;
+    }
+}
+
+pub mod attr {
+    /// Stub of the `Attribute` trait of Leptos 0.7.
+    pub trait Attribute {}
+
+    pub mod custom {
+        /// Stub of the custom attributes of Leptos 0.7.
+        pub struct CustomAttr<K, V>(pub K, pub V);
+
+        impl<K, V> super::Attribute for CustomAttr<K, V> {}
+
+        pub fn custom_attribute<K, V>(key: K, value: V) -> CustomAttr<K, V> {
+            CustomAttr(key, value)
+        }
+    }
+}
+
+#[leptos_unique_ids("foo", "bar-baz")]
+pub enum Ids {}
+
+fn main() {
+    let prelude::Oco::Borrowed(id) = prelude::Oco::<'static, str>::from(Ids::Foo);
+    assert_eq!(id, "foo");
+
+    let oco: prelude::Oco<'static, str> = Ids::BarBaz.into();
+    let prelude::Oco::Borrowed(id) = oco;
+    assert_eq!(id, "bar-baz");
+}
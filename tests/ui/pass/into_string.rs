@@ -0,0 +1,15 @@
+use std::borrow::Cow;
+
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", "bar-baz")]
+pub enum Ids {}
+
+fn main() {
+    let id: String = Ids::Foo.into();
+    assert_eq!(id, "foo");
+    assert_eq!(String::from(Ids::BarBaz), "bar-baz".to_string());
+
+    let id: Cow<'static, str> = Ids::BarBaz.into();
+    assert!(matches!(id, Cow::Borrowed("bar-baz")));
+}
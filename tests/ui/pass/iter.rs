@@ -0,0 +1,30 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", "bar", "baz")]
+pub enum Ids {}
+
+mod sorted {
+    use leptos_unique_ids::leptos_unique_ids;
+
+    #[leptos_unique_ids("foo", "bar", "baz", order = "sorted")]
+    pub enum Ids {}
+}
+
+mod empty {
+    use leptos_unique_ids::leptos_unique_ids;
+
+    #[leptos_unique_ids()]
+    pub enum Ids {}
+}
+
+fn main() {
+    let ids = Ids::iter().map(|id| id.as_str()).collect::<Vec<_>>();
+    assert_eq!(ids, ["foo", "bar", "baz"]);
+
+    let ids = sorted::Ids::iter()
+        .map(|id| id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, ["bar", "baz", "foo"]);
+
+    assert_eq!(empty::Ids::iter().count(), 0);
+}
@@ -0,0 +1,26 @@
+// `::leptos` paths of the generated code resolve to the stub below
+extern crate self as leptos;
+
+use leptos_unique_ids::leptos_unique_ids;
+
+/// Stub of the attribute of Leptos 0.6.
+pub enum Attribute {
+    String(std::borrow::Cow<'static, str>),
+}
+
+/// Stub of the `IntoAttribute` trait of Leptos 0.6.
+pub trait IntoAttribute {
+    fn into_attribute(self) -> Attribute;
+    fn into_attribute_boxed(self: Box<Self>) -> Attribute;
+}
+
+#[leptos_unique_ids("foo", "bar-baz", leptos_version = "0.6")]
+pub enum Ids {}
+
+fn main() {
+    let Attribute::String(value) = Ids::Foo.into_attribute();
+    assert_eq!(value, "foo");
+
+    let Attribute::String(value) = Box::new(Ids::BarBaz).into_attribute_boxed();
+    assert_eq!(value, "bar-baz");
+}
@@ -0,0 +1,45 @@
+// `::leptos` paths of the generated code resolve to the stub below
+extern crate self as leptos;
+
+use leptos_unique_ids::leptos_unique_ids;
+
+pub mod prelude {
+    /// Stub of the `IntoAttributeValue` trait of Leptos 0.7.
+    pub trait IntoAttributeValue {
+        type Output;
+        fn into_attribute_value(self) -> Self::Output;
+    }
+
+    /// Stub of the `Oco` type of Leptos 0.7.
+    pub enum Oco<'a, T: ?Sized + 'a> {
+        Borrowed(&'a T),
+    }
+}
+
+pub mod attr {
+    /// Stub of the `Attribute` trait of Leptos 0.7.
+    pub trait Attribute {}
+
+    pub mod custom {
+        /// Stub of the custom attributes of Leptos 0.7.
+        pub struct CustomAttr<K, V>(pub K, pub V);
+
+        impl<K, V> super::Attribute for CustomAttr<K, V> {}
+
+        pub fn custom_attribute<K, V>(key: K, value: V) -> CustomAttr<K, V> {
+            CustomAttr(key, value)
+        }
+    }
+}
+
+#[leptos_unique_ids("foo", "bar-baz", leptos_version = "0.7")]
+pub enum Ids {}
+
+fn main() {
+    use prelude::IntoAttributeValue;
+
+    assert_eq!(Ids::Foo.into_attribute_value(), "foo");
+    assert_eq!(Ids::BarBaz.into_attribute_value(), "bar-baz");
+
+    let _attribute = Ids::Foo.as_data_attr("section");
+}
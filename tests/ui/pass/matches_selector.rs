@@ -0,0 +1,13 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("language-selector", "preview", prefix = "app-")]
+pub enum Ids {}
+
+fn main() {
+    assert!(Ids::LanguageSelector.matches_selector("#app-language-selector"));
+    assert!(Ids::LanguageSelector.matches_selector("app-language-selector"));
+    assert!(!Ids::LanguageSelector.matches_selector("#app-preview"));
+    assert!(!Ids::LanguageSelector.matches_selector("language-selector"));
+    assert!(!Ids::LanguageSelector.matches_selector("##app-language-selector"));
+    assert!(!Ids::Preview.matches_selector("#"));
+}
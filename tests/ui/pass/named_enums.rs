@@ -0,0 +1,28 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("login-form", "login-button")]
+pub enum FormIds {}
+
+#[leptos_unique_ids("main-nav", "login-button")]
+pub(crate) enum NavIds {}
+
+#[leptos_unique_ids("language-selector")]
+pub enum Ids {}
+
+fn main() {
+    assert_eq!(FormIds::LoginForm.as_str(), "login-form");
+    assert_eq!(FormIds::LoginButton.as_str(), "login-button");
+    assert_eq!(NavIds::MainNav.as_str(), "main-nav");
+    assert_eq!(NavIds::LoginButton.as_str(), "login-button");
+    assert_eq!(Ids::LanguageSelector.as_str(), "language-selector");
+
+    assert_eq!(FormIds::ALL.len(), 2);
+    assert!(matches!("main-nav".parse::<NavIds>(), Ok(NavIds::MainNav)));
+    assert!(matches!("main-nav".parse::<FormIds>(), Err(UnknownFormId)));
+    assert!(matches!("login-form".parse::<NavIds>(), Err(UnknownNavId)));
+    assert!(matches!("login-form".parse::<Ids>(), Err(UnknownId)));
+
+    assert_eq!(form_ids::LOGIN_FORM, "login-form");
+    assert_eq!(nav_ids::MAIN_NAV, "main-nav");
+    assert_eq!(ids::LANGUAGE_SELECTOR, "language-selector");
+}
@@ -0,0 +1,26 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("login-form", "login-button")]
+pub enum FormIdentifiers {}
+
+#[leptos_unique_ids("login-form")]
+pub enum Ids {}
+
+fn main() {
+    // the types are named after the singular of the enum name
+    assert!(matches!(
+        "main-nav".parse::<FormIdentifiers>(),
+        Err(UnknownFormIdentifier)
+    ));
+    assert!(matches!(
+        FormIdentifiers::try_from(2),
+        Err(FormIdentifierIndexOutOfRange(2))
+    ));
+    let selector: FormIdentifierSelector = FormIdentifiers::LoginForm.as_selector();
+    assert_eq!(selector.to_string(), "#login-form");
+
+    assert!(matches!("main-nav".parse::<Ids>(), Err(UnknownId)));
+    assert!(matches!(Ids::try_from(1), Err(IdIndexOutOfRange(1))));
+    let selector: IdSelector = Ids::LoginForm.as_selector();
+    assert_eq!(selector.to_string(), "#login-form");
+}
@@ -0,0 +1,41 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", "bar", "baz")]
+pub enum Ids {}
+
+mod single {
+    use leptos_unique_ids::leptos_unique_ids;
+
+    #[leptos_unique_ids("qux")]
+    pub enum Ids {}
+}
+
+mod sorted {
+    use leptos_unique_ids::leptos_unique_ids;
+
+    #[leptos_unique_ids("foo", "bar", "baz", order = "sorted")]
+    pub enum Ids {}
+}
+
+const AFTER_FOO: Ids = Ids::Foo.next();
+
+fn main() {
+    // first
+    assert_eq!(AFTER_FOO.as_str(), "bar");
+    assert_eq!(Ids::Foo.prev().as_str(), "baz");
+
+    // middle
+    assert_eq!(Ids::Bar.next().as_str(), "baz");
+    assert_eq!(Ids::Bar.prev().as_str(), "foo");
+
+    // last
+    assert_eq!(Ids::Baz.next().as_str(), "foo");
+    assert_eq!(Ids::Baz.prev().as_str(), "bar");
+
+    // single variant
+    assert_eq!(single::Ids::Qux.next().as_str(), "qux");
+    assert_eq!(single::Ids::Qux.prev().as_str(), "qux");
+
+    // declaration order is used regardless of `order`
+    assert_eq!(sorted::Ids::Foo.next().as_str(), "bar");
+}
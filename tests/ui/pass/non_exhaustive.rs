@@ -0,0 +1,28 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", "bar", "baz", non_exhaustive)]
+pub enum Ids {}
+
+mod other {
+    use leptos_unique_ids::leptos_unique_ids;
+
+    #[leptos_unique_ids("qux", non_exhaustive = true)]
+    pub enum Ids {}
+}
+
+fn is_foo(id: Ids) -> bool {
+    #[allow(unreachable_patterns)]
+    match id {
+        Ids::Foo => true,
+        Ids::Bar | Ids::Baz => false,
+        // downstream crates must handle future identifiers
+        _ => false,
+    }
+}
+
+fn main() {
+    assert!(is_foo(Ids::Foo));
+    assert!(!is_foo(Ids::Baz));
+    assert_eq!(Ids::Bar.as_str(), "bar");
+    assert_eq!(other::Ids::Qux.as_str(), "qux");
+}
@@ -0,0 +1,35 @@
+use std::collections::BTreeMap;
+
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("preview", "language-selector", "footer", ord)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Ids {}
+
+#[leptos_unique_ids("preview", "language-selector", "footer", order = "sorted", ord)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SortedIds {}
+
+fn main() {
+    let mut ids = vec![Ids::Footer, Ids::Preview, Ids::LanguageSelector];
+    ids.sort();
+    assert!(ids == [Ids::Preview, Ids::LanguageSelector, Ids::Footer]);
+
+    let map = BTreeMap::from([(Ids::Footer, 2), (Ids::Preview, 0)]);
+    assert!(map.keys().copied().eq([Ids::Preview, Ids::Footer]));
+
+    let mut sorted_ids = vec![
+        SortedIds::Preview,
+        SortedIds::LanguageSelector,
+        SortedIds::Footer,
+    ];
+    sorted_ids.sort();
+    assert!(
+        sorted_ids
+            == [
+                SortedIds::Footer,
+                SortedIds::LanguageSelector,
+                SortedIds::Preview,
+            ]
+    );
+}
@@ -0,0 +1,43 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+mod declaration {
+    use super::leptos_unique_ids;
+
+    #[leptos_unique_ids("cherry", "apple", "banana")]
+    pub enum Ids {}
+}
+
+mod sorted {
+    use super::leptos_unique_ids;
+
+    #[leptos_unique_ids("cherry", "apple", "banana", order = "sorted")]
+    pub enum Ids {}
+}
+
+fn main() {
+    let all = declaration::Ids::ALL
+        .iter()
+        .map(declaration::Ids::as_str)
+        .collect::<Vec<_>>();
+    assert_eq!(all, ["cherry", "apple", "banana"]);
+
+    let all = sorted::Ids::all()
+        .iter()
+        .map(sorted::Ids::as_str)
+        .collect::<Vec<_>>();
+    assert_eq!(all, ["apple", "banana", "cherry"]);
+
+    let pairs = sorted::Ids::PAIRS
+        .iter()
+        .map(|(id, variant)| {
+            assert_eq!(*id, variant.as_str());
+            *id
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(pairs, ["apple", "banana", "cherry"]);
+
+    // discriminants keep the declaration order
+    assert_eq!(sorted::Ids::Cherry as usize, 0);
+    assert_eq!(sorted::Ids::Apple as usize, 1);
+    assert_eq!(sorted::Ids::Banana as usize, 2);
+}
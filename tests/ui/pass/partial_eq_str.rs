@@ -0,0 +1,32 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("language-selector", "preview")]
+#[derive(PartialEq)]
+pub enum Ids {}
+
+fn main() {
+    let id: &str = "preview";
+
+    // Ids and &str
+    assert!(Ids::Preview == "preview");
+    assert!("preview" == Ids::Preview);
+    assert!(Ids::Preview != "language-selector");
+    assert!("language-selector" != Ids::Preview);
+
+    // Ids and &&str
+    assert!(Ids::Preview == &id);
+    assert!(&id == Ids::Preview);
+
+    // Ids and str
+    assert!(Ids::Preview == *id);
+    assert!(*id == Ids::Preview);
+
+    // &Ids and &str, through the impls between Ids and str
+    assert!(&Ids::Preview == id);
+    assert!(id == &Ids::Preview);
+
+    // Ids is still compared with Ids
+    assert!(Ids::Preview == Ids::Preview);
+    assert_eq!(Ids::LanguageSelector, "language-selector");
+    assert_ne!(Ids::LanguageSelector, Ids::Preview);
+}
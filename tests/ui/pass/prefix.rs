@@ -0,0 +1,34 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", "bar-baz", prefix = "app-")]
+pub enum Ids {}
+
+mod unprefixed {
+    use leptos_unique_ids::leptos_unique_ids;
+
+    #[leptos_unique_ids("foo", "bar-baz")]
+    pub enum Ids {}
+}
+
+mod id_case {
+    use leptos_unique_ids::leptos_unique_ids;
+
+    #[leptos_unique_ids("BarBaz", prefix = "app_", id_case = "snake")]
+    pub enum Ids {}
+}
+
+fn main() {
+    assert_eq!(Ids::Foo.as_str(), "app-foo");
+    assert_eq!(Ids::Foo.local_name(), "foo");
+    assert_eq!(Ids::BarBaz.as_str(), "app-bar-baz");
+    assert_eq!(Ids::BarBaz.local_name(), "bar-baz");
+    assert!(Ids::contains("app-foo"));
+    assert!(!Ids::contains("foo"));
+
+    for id in unprefixed::Ids::iter() {
+        assert_eq!(id.local_name(), id.as_str());
+    }
+
+    assert_eq!(id_case::Ids::BarBaz.as_str(), "app_bar_baz");
+    assert_eq!(id_case::Ids::BarBaz.local_name(), "bar_baz");
+}
@@ -0,0 +1,17 @@
+#![allow(deprecated)]
+
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids(
+    "settings-panel",
+    "logo",
+    rename("preferences-panel" => "settings-panel"),
+    rename("brand" => "logo"),
+)]
+pub enum Ids {}
+
+fn main() {
+    assert_eq!(Ids::PreferencesPanel.as_str(), "settings-panel");
+    assert_eq!(Ids::Brand.index(), Ids::Logo.index());
+    assert_eq!(Ids::ALL.len(), 2);
+}
@@ -0,0 +1,20 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", "bar", "baz", repr = "u16", order = "sorted")]
+pub enum Ids {}
+
+const BAZ: u16 = Ids::Baz.as_u16();
+
+fn main() {
+    // discriminants follow the declaration order, regardless of `order`
+    assert_eq!(Ids::Foo as u16, 0);
+    assert_eq!(Ids::Bar as u16, 1);
+    assert_eq!(Ids::Baz as u16, 2);
+    assert_eq!(BAZ, 2);
+    assert_eq!(std::mem::size_of::<Ids>(), 2);
+
+    for id in Ids::ALL {
+        assert_eq!(Ids::from_u16(id.as_u16()).unwrap().as_str(), id.as_str());
+    }
+    assert!(Ids::from_u16(3).is_none());
+}
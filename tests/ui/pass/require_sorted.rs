@@ -0,0 +1,21 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("footer", "language-selector", "preview", require_sorted)]
+pub enum Ids {}
+
+// uppercase letters are sorted before lowercase ones
+#[leptos_unique_ids("Preview", "footer", require_sorted = true)]
+pub enum CaseIds {}
+
+#[leptos_unique_ids("preview", "footer", require_sorted = false)]
+pub enum UnsortedIds {}
+
+#[leptos_unique_ids(require_sorted)]
+pub enum EmptyIds {}
+
+fn main() {
+    assert_eq!(Ids::ALL.len(), 3);
+    assert_eq!(CaseIds::ALL.len(), 2);
+    assert_eq!(UnsortedIds::Preview.as_str(), "preview");
+    assert!(EmptyIds::ALL.is_empty());
+}
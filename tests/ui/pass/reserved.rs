@@ -0,0 +1,13 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("language-selector", "app-header", reserved = ["root", "app"])]
+pub enum Ids {}
+
+// reserved identifiers are compared after prefixing the string literals
+#[leptos_unique_ids("root", prefix = "nav-", reserved = ["root"])]
+pub enum NavIds {}
+
+fn main() {
+    assert_eq!(Ids::AppHeader.as_str(), "app-header");
+    assert_eq!(NavIds::Root.as_str(), "nav-root");
+}
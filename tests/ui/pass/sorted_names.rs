@@ -0,0 +1,33 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("cherry", "apple", "banana-split", "Banana-bread", "banana")]
+pub enum Ids {}
+
+fn main() {
+    let names = Ids::SORTED_NAMES
+        .iter()
+        .map(|(id, variant)| {
+            assert_eq!(*id, variant.as_str());
+            *id
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        ["Banana-bread", "apple", "banana", "banana-split", "cherry"]
+    );
+    assert!(names.is_sorted());
+
+    // declaration order is kept in `ALL`
+    assert_eq!(Ids::ALL[0].as_str(), "cherry");
+
+    for (id, _) in Ids::PAIRS {
+        let sorted = Ids::from_str_sorted(id).map(|variant| variant.as_str());
+        let linear = id.parse::<Ids>().ok().map(|variant| variant.as_str());
+        assert_eq!(sorted, Some(*id));
+        assert_eq!(sorted, linear);
+    }
+    for id in ["", "banan", "bananas", "zebra", "Cherry"] {
+        assert!(Ids::from_str_sorted(id).is_none());
+        assert!(id.parse::<Ids>().is_err());
+    }
+}
@@ -0,0 +1,25 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids(
+    "language-selector",
+    "_private",
+    "-webkit-input",
+    "user_avatar-1",
+    strict_css
+)]
+pub enum Ids {}
+
+mod other {
+    use leptos_unique_ids::leptos_unique_ids;
+
+    #[leptos_unique_ids("nav.main", strict_css = false)]
+    pub enum Ids {}
+}
+
+fn main() {
+    assert_eq!(Ids::LanguageSelector.as_str(), "language-selector");
+    assert_eq!(Ids::Private.as_str(), "_private");
+    assert_eq!(Ids::WebkitInput.as_str(), "-webkit-input");
+    assert_eq!(Ids::UserAvatar1.as_str(), "user_avatar-1");
+    assert_eq!(other::Ids::NavMain.as_str(), "nav.main");
+}
@@ -0,0 +1,18 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("language-selector", "nav.main", "user:avatar_1", strict_html)]
+pub enum Ids {}
+
+mod other {
+    use leptos_unique_ids::leptos_unique_ids;
+
+    #[leptos_unique_ids("_private", strict_html = false)]
+    pub enum Ids {}
+}
+
+fn main() {
+    assert_eq!(Ids::LanguageSelector.as_str(), "language-selector");
+    assert_eq!(Ids::NavMain.as_str(), "nav.main");
+    assert_eq!(Ids::UserAvatar1.as_str(), "user:avatar_1");
+    assert_eq!(other::Ids::Private.as_str(), "_private");
+}
@@ -0,0 +1,10 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("language-selector", ("preview", doc = "Preview panel."), prefix = "app-")]
+#[derive(Debug, Clone, Copy)]
+pub struct Ids;
+
+fn main() {
+    assert_eq!(Ids::LANGUAGE_SELECTOR, "app-language-selector");
+    assert_eq!(Ids::PREVIEW, "app-preview");
+}
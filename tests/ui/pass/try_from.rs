@@ -0,0 +1,12 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", "bar-baz")]
+pub enum Ids {}
+
+fn main() {
+    assert!(matches!(Ids::try_from("foo"), Ok(Ids::Foo)));
+    assert!(matches!(Ids::try_from("bar-baz"), Ok(Ids::BarBaz)));
+
+    let result: Result<Ids, _> = "BarBaz".try_into();
+    assert!(matches!(result, Err(UnknownId)));
+}
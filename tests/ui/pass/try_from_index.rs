@@ -0,0 +1,35 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", "bar", "baz", order = "sorted")]
+pub enum Ids {}
+
+mod repr {
+    use leptos_unique_ids::leptos_unique_ids;
+
+    #[leptos_unique_ids("foo", "bar", "baz", repr = "u16", order = "sorted")]
+    pub enum Ids {}
+}
+
+fn main() {
+    // positions in `Ids::ALL`, like `Ids::from_index`
+    assert_eq!(Ids::try_from(0).unwrap().as_str(), "bar");
+    assert_eq!(Ids::try_from(2).unwrap().as_str(), "foo");
+
+    let err = Ids::try_from(3).unwrap_err();
+    assert_eq!(err, IdIndexOutOfRange(3));
+    assert_eq!(err.to_string(), "position 3 is out of range");
+
+    // discriminants with `repr = "u16"`, like `Ids::from_u16`
+    assert_eq!(repr::Ids::try_from(0_u16).unwrap().as_str(), "foo");
+    assert_eq!(repr::Ids::try_from(2_u16).unwrap().as_str(), "baz");
+    assert_eq!(
+        repr::Ids::try_from(3_u16).unwrap_err(),
+        repr::IdIndexOutOfRange(3)
+    );
+    for id in repr::Ids::ALL {
+        assert_eq!(
+            repr::Ids::try_from(id.as_u16()).unwrap().as_str(),
+            id.as_str()
+        );
+    }
+}
@@ -0,0 +1,21 @@
+use std::error::Error;
+
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", "bar-baz")]
+pub enum Ids {}
+
+fn main() {
+    assert!(matches!("foo".parse::<Ids>(), Ok(Ids::Foo)));
+    assert!(matches!("bar-baz".parse::<Ids>(), Ok(Ids::BarBaz)));
+
+    let Err(err) = "BarBaz".parse::<Ids>() else {
+        panic!("Expected an error parsing an unknown identifier");
+    };
+    let _: UnknownId = err;
+    assert_eq!(err, UnknownId);
+    assert_eq!(err.to_string(), "unknown identifier");
+
+    let boxed: Box<dyn Error> = Box::new(err);
+    assert!(boxed.downcast_ref::<UnknownId>().is_some());
+}
@@ -0,0 +1,15 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+/// Identifiers of the application.
+#[leptos_unique_ids("foo", "bar")]
+#[derive(Clone, PartialEq)]
+#[doc = "Every element with an `id` attribute must use one of them."]
+#[repr(u32)]
+pub enum Ids {}
+
+fn main() {
+    let id = Ids::Bar.clone();
+    assert!(id == Ids::Bar);
+    assert_eq!(id.as_str(), "bar");
+    assert_eq!(std::mem::size_of::<Ids>(), 4);
+}
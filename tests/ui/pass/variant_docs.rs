@@ -0,0 +1,14 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids(
+    ("language-selector", doc = "Dropdown of the top bar to switch the language."),
+    "preview",
+    (r#"footer"#, doc = r#"Footer of the "main" layout."#,),
+)]
+pub enum Ids {}
+
+fn main() {
+    assert_eq!(Ids::LanguageSelector.as_str(), "language-selector");
+    assert_eq!(Ids::Preview.as_str(), "preview");
+    assert_eq!(Ids::Footer.as_str(), "footer");
+}
@@ -0,0 +1,19 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", "bar", "baz")]
+pub enum Ids {}
+
+mod empty {
+    use leptos_unique_ids::leptos_unique_ids;
+
+    #[leptos_unique_ids()]
+    pub enum Ids {}
+}
+
+const COUNT: usize = Ids::variants_count();
+
+fn main() {
+    assert_eq!(COUNT, 3);
+    assert_eq!(Ids::variants_count(), Ids::ALL.len());
+    assert_eq!(empty::Ids::variants_count(), 0);
+}
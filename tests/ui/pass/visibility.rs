@@ -0,0 +1,61 @@
+mod app {
+    pub mod components {
+        use leptos_unique_ids::leptos_unique_ids;
+
+        pub mod public {
+            use super::leptos_unique_ids;
+
+            #[leptos_unique_ids("public")]
+            pub enum Ids {}
+        }
+
+        pub mod krate {
+            use super::leptos_unique_ids;
+
+            #[leptos_unique_ids("krate")]
+            pub(crate) enum Ids {}
+        }
+
+        pub mod parent {
+            use super::leptos_unique_ids;
+
+            #[leptos_unique_ids("parent")]
+            pub(super) enum Ids {}
+        }
+
+        pub mod path {
+            use super::leptos_unique_ids;
+
+            #[leptos_unique_ids("path")]
+            pub(in crate::app) enum Ids {}
+        }
+
+        pub mod private {
+            use super::leptos_unique_ids;
+
+            #[leptos_unique_ids("private")]
+            #[allow(dead_code)]
+            enum Ids {}
+
+            pub fn as_str() -> &'static str {
+                Ids::Private.as_str()
+            }
+        }
+
+        pub fn parent_as_str() -> &'static str {
+            parent::Ids::Parent.as_str()
+        }
+    }
+
+    pub fn path_as_str() -> &'static str {
+        components::path::Ids::Path.as_str()
+    }
+}
+
+fn main() {
+    assert_eq!(app::components::public::Ids::Public.as_str(), "public");
+    assert_eq!(app::components::krate::Ids::Krate.as_str(), "krate");
+    assert_eq!(app::components::parent_as_str(), "parent");
+    assert_eq!(app::path_as_str(), "path");
+    assert_eq!(app::components::private::as_str(), "private");
+}
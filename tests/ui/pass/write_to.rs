@@ -0,0 +1,13 @@
+use leptos_unique_ids::leptos_unique_ids;
+
+#[leptos_unique_ids("foo", "bar-baz", "qux", prefix = "app-")]
+pub enum Ids {}
+
+fn main() {
+    let mut buffer = String::from("ids:");
+    for id in Ids::ALL {
+        buffer.push(' ');
+        id.write_to(&mut buffer).unwrap();
+    }
+    assert_eq!(buffer, "ids: app-foo app-bar-baz app-qux");
+}